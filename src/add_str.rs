@@ -0,0 +1,117 @@
+use std::{error::Error, fmt};
+
+use crate::{ApplyDuration, ApplyError, Duration, EomPolicy, ParseDurationError};
+
+/// Error returned by [`AddIso8601::add_iso8601`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum AddIso8601Error {
+    /// The string isn't a valid duration.
+    Parse(ParseDurationError),
+    /// The duration couldn't be added to the datetime.
+    Apply(ApplyError),
+}
+
+impl fmt::Display for AddIso8601Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddIso8601Error::Parse(error) => write!(f, "invalid duration: {}", error),
+            AddIso8601Error::Apply(error) => write!(f, "can't add duration: {}", error),
+        }
+    }
+}
+
+impl Error for AddIso8601Error {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AddIso8601Error::Parse(error) => Some(error),
+            AddIso8601Error::Apply(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseDurationError> for AddIso8601Error {
+    fn from(error: ParseDurationError) -> Self {
+        AddIso8601Error::Parse(error)
+    }
+}
+
+impl From<ApplyError> for AddIso8601Error {
+    fn from(error: ApplyError) -> Self {
+        AddIso8601Error::Apply(error)
+    }
+}
+
+/// Add a duration given as an ISO8601 string, for the common case of
+/// applying a user-supplied offset.
+///
+/// It's implemented for every [`ApplyDuration`] type, so for
+/// `time::OffsetDateTime` with the `time_03` feature and
+/// `chrono::DateTime` with the `chrono` feature.
+///
+/// ```rust
+/// # #[cfg(feature = "time_03")]
+/// # {
+/// use iso8601_duration::AddIso8601;
+/// use time::macros::datetime;
+///
+/// let start = datetime!(2024-01-31 09:00:00 UTC);
+/// assert_eq!(start.add_iso8601("P1MT1H"), Ok(datetime!(2024-02-29 10:00:00 UTC)));
+/// assert_eq!(
+///     start.add_iso8601("P1Q").unwrap_err().to_string(),
+///     "invalid duration: unknown designator 'Q' in the component at position 1"
+/// );
+/// # }
+/// ```
+pub trait AddIso8601: Sized {
+    /// Parse `input` with [`Duration::parse`] and add it, clamping the day
+    /// to the end of shorter months
+    fn add_iso8601(&self, input: &str) -> Result<Self, AddIso8601Error> {
+        self.add_iso8601_with(input, EomPolicy::Clamp)
+    }
+
+    /// Parse `input` with [`Duration::parse`] and add it, resolving days
+    /// past the end of a month with `policy`
+    fn add_iso8601_with(&self, input: &str, policy: EomPolicy) -> Result<Self, AddIso8601Error>;
+}
+
+impl<T: ApplyDuration> AddIso8601 for T {
+    fn add_iso8601_with(&self, input: &str, policy: EomPolicy) -> Result<Self, AddIso8601Error> {
+        let duration = Duration::parse(input)?;
+
+        Ok(self.apply(&duration, policy)?)
+    }
+}
+
+#[cfg(all(test, feature = "time_03"))]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn add_iso8601() {
+        let start = datetime!(2023-01-31 00:00:00 UTC);
+
+        assert_eq!(
+            start.add_iso8601("-P1D"),
+            Ok(datetime!(2023-01-30 00:00:00 UTC))
+        );
+        assert_eq!(
+            start.add_iso8601_with("P1M", EomPolicy::Overflow),
+            Ok(datetime!(2023-03-03 00:00:00 UTC))
+        );
+        assert_eq!(
+            start.add_iso8601_with("P1M", EomPolicy::Reject),
+            Err(AddIso8601Error::Apply(ApplyError::DayOutOfMonth))
+        );
+        assert!(matches!(
+            start.add_iso8601("1D"),
+            Err(AddIso8601Error::Parse(_))
+        ));
+        assert!(start
+            .add_iso8601("P100000Y")
+            .unwrap_err()
+            .source()
+            .is_some());
+    }
+}
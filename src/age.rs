@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+
+use time::{Date, Month};
+
+use crate::apply::days_in_month;
+use crate::Duration;
+
+/// When a birthday falls on a day missing from the target month, which
+/// mostly concerns people born on February 29.
+///
+/// The rule differs between jurisdictions, and only matters on the days
+/// around the end of the month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeapDayRule {
+    /// The anniversary is on the last day of the month, so someone born on
+    /// February 29 ages on February 28 in common years, as in New Zealand.
+    #[default]
+    LastDay,
+    /// The anniversary is on the following day, so someone born on
+    /// February 29 ages on March 1 in common years, as in England and
+    /// Wales or Hong Kong.
+    NextDay,
+}
+
+impl Duration {
+    /// Return the age on `today` of someone born on `birth`, in years,
+    /// months and days, or `None` when `today` is before `birth`.
+    ///
+    /// Years and months are counted in whole anniversaries of `birth`,
+    /// which move according to `rule` when the day is missing from the
+    /// month, then days are counted from the last monthly anniversary.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, LeapDayRule};
+    /// use time::macros::date;
+    ///
+    /// let birth = date!(2004-02-29);
+    /// let age = |today, rule| Duration::age(birth, today, rule).unwrap().to_string();
+    ///
+    /// assert_eq!(age(date!(2022-02-28), LeapDayRule::LastDay), "P18Y");
+    /// assert_eq!(age(date!(2022-02-28), LeapDayRule::NextDay), "P17Y11M30D");
+    /// assert_eq!(age(date!(2022-03-01), LeapDayRule::NextDay), "P18Y");
+    /// ```
+    pub fn age(birth: Date, today: Date, rule: LeapDayRule) -> Option<Duration> {
+        if today < birth {
+            return None;
+        }
+
+        let months_of = |date: Date| date.year() as i64 * 12 + date.month() as i64 - 1;
+        let mut months = months_of(today) - months_of(birth);
+        while months > 0 && anniversary(birth, months, rule)? > today {
+            months -= 1;
+        }
+
+        let days = (today - anniversary(birth, months, rule)?).whole_days();
+        Some(Duration::new(
+            (months / 12) as f32,
+            (months % 12) as f32,
+            days as f32,
+            0.,
+            0.,
+            0.,
+        ))
+    }
+}
+
+/// Return the anniversary of `birth` after `months` months.
+fn anniversary(birth: Date, months: i64, rule: LeapDayRule) -> Option<Date> {
+    let month_0_based = birth.month() as i64 - 1 + months;
+    let year = birth
+        .year()
+        .checked_add(i32::try_from(month_0_based.div_euclid(12)).ok()?)?;
+    let month = Month::try_from(month_0_based.rem_euclid(12) as u8 + 1).ok()?;
+
+    let length = days_in_month(year, month as u32) as u8;
+    match rule {
+        _ if birth.day() <= length => Date::from_calendar_date(year, month, birth.day()).ok(),
+        LeapDayRule::LastDay => Date::from_calendar_date(year, month, length).ok(),
+        LeapDayRule::NextDay => Date::from_calendar_date(year, month, length)
+            .ok()?
+            .next_day(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn age() {
+        let age = |birth, today, rule| Duration::age(birth, today, rule).map(|d| d.to_string());
+
+        let birth = date!(1990 - 05 - 15);
+        assert_eq!(age(birth, birth, LeapDayRule::LastDay).unwrap(), "PT0S");
+        assert_eq!(
+            age(birth, date!(2008 - 05 - 14), LeapDayRule::LastDay).unwrap(),
+            "P17Y11M29D"
+        );
+        assert_eq!(
+            age(birth, date!(2008 - 05 - 15), LeapDayRule::LastDay).unwrap(),
+            "P18Y"
+        );
+        assert_eq!(
+            age(birth, date!(1990 - 05 - 14), LeapDayRule::LastDay),
+            None
+        );
+
+        // monthly anniversaries follow the rule as well
+        let birth = date!(2023 - 01 - 31);
+        assert_eq!(
+            age(birth, date!(2023 - 02 - 28), LeapDayRule::LastDay).unwrap(),
+            "P1M"
+        );
+        assert_eq!(
+            age(birth, date!(2023 - 02 - 28), LeapDayRule::NextDay).unwrap(),
+            "P28D"
+        );
+        assert_eq!(
+            age(birth, date!(2023 - 03 - 01), LeapDayRule::NextDay).unwrap(),
+            "P1M"
+        );
+        assert_eq!(
+            age(birth, date!(2023 - 03 - 31), LeapDayRule::NextDay).unwrap(),
+            "P2M"
+        );
+
+        // leap years keep the actual birthday
+        let birth = date!(2000 - 02 - 29);
+        for rule in [LeapDayRule::LastDay, LeapDayRule::NextDay] {
+            assert_eq!(
+                age(birth, date!(2024 - 02 - 28), rule).unwrap(),
+                "P23Y11M30D"
+            );
+            assert_eq!(age(birth, date!(2024 - 02 - 29), rule).unwrap(), "P24Y");
+        }
+    }
+}
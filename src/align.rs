@@ -0,0 +1,245 @@
+use time::{macros::date, OffsetDateTime, Time, Weekday};
+
+use crate::Duration;
+
+/// The day weeks start on, for week-aligned durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WeekStart {
+    /// ISO 8601 weeks, starting on Monday
+    #[default]
+    Monday,
+    /// US weeks, starting on Sunday
+    Sunday,
+}
+
+impl WeekStart {
+    /// Return the start of the week containing `datetime`, at midnight in
+    /// its offset
+    ///
+    /// ```rust
+    /// use iso8601_duration::WeekStart;
+    /// use time::macros::datetime;
+    ///
+    /// // a Wednesday
+    /// let datetime = datetime!(2023-03-15 10:20 UTC);
+    /// assert_eq!(WeekStart::Monday.start_of_week(datetime), datetime!(2023-03-13 00:00 UTC));
+    /// assert_eq!(WeekStart::Sunday.start_of_week(datetime), datetime!(2023-03-12 00:00 UTC));
+    /// ```
+    pub fn start_of_week(self, datetime: OffsetDateTime) -> OffsetDateTime {
+        let days = match self {
+            WeekStart::Monday => datetime.weekday().number_days_from_monday(),
+            WeekStart::Sunday => datetime.weekday().number_days_from_sunday(),
+        };
+        let date = datetime
+            .date()
+            .saturating_sub(time::Duration::days(days as i64));
+
+        datetime.replace_date(date).replace_time(Time::MIDNIGHT)
+    }
+
+    /// Return the first day of the week
+    pub fn weekday(self) -> Weekday {
+        match self {
+            WeekStart::Monday => Weekday::Monday,
+            WeekStart::Sunday => Weekday::Sunday,
+        }
+    }
+}
+
+/// How [`Duration::step_from`] moves a datetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Stepping {
+    /// Add the duration as `+` does
+    #[default]
+    Exact,
+    /// Land durations of whole weeks on the start of an ISO week, so `P1W`
+    /// goes to Monday midnight of the next ISO week. Other durations are
+    /// added as `+` does.
+    IsoWeek,
+}
+
+impl Duration {
+    /// Move `datetime` forward by the duration, following `stepping`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, Stepping};
+    /// use time::macros::datetime;
+    ///
+    /// let week: Duration = "P1W".parse().unwrap();
+    /// // a Thursday, in ISO week 1 of 2026
+    /// let datetime = datetime!(2026-01-01 15:30 UTC);
+    ///
+    /// let next = week.step_from(datetime, Stepping::IsoWeek);
+    /// assert_eq!(next, datetime!(2026-01-05 00:00 UTC));
+    /// assert_eq!(next.iso_week(), 2);
+    ///
+    /// assert_eq!(week.step_from(datetime, Stepping::Exact), datetime!(2026-01-08 15:30 UTC));
+    /// ```
+    pub fn step_from(&self, datetime: OffsetDateTime, stepping: Stepping) -> OffsetDateTime {
+        match stepping {
+            Stepping::IsoWeek if self.is_whole_weeks() => {
+                WeekStart::Monday.start_of_week(datetime) + *self
+            }
+            _ => datetime + *self,
+        }
+    }
+
+    /// Return `true` if the duration is a whole number of weeks
+    fn is_whole_weeks(&self) -> bool {
+        self.year == 0.
+            && self.month == 0.
+            && self.hour == 0.
+            && self.minute == 0.
+            && self.second == 0.
+            && self.day > 0.
+            && self.day % 7. == 0.
+    }
+
+    /// Return the datetime periods of the duration are aligned on.
+    fn alignment_anchor(&self, datetime: OffsetDateTime, week_start: WeekStart) -> OffsetDateTime {
+        let midnight = datetime.replace_time(Time::MIDNIGHT);
+        if self.year == 0. && self.month == 0. && self.day == 0. {
+            return midnight;
+        }
+
+        // 2001 starts on a Monday
+        let anchor = midnight.replace_date(date!(2001 - 01 - 01));
+        if self.is_whole_weeks() {
+            week_start.start_of_week(anchor)
+        } else {
+            anchor
+        }
+    }
+
+    /// Return the start of the period of the duration containing `datetime`.
+    ///
+    /// Durations of whole weeks are aligned on `week_start`, durations
+    /// without date components on midnight, and other durations on the
+    /// start of 2001, a Monday, so `P3M` is aligned on quarters. Periods
+    /// follow each other as in [`bucket_of`](Duration::bucket_of).
+    ///
+    /// This method will return `None` if the duration is empty.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, WeekStart};
+    /// use time::macros::datetime;
+    ///
+    /// let fortnight: Duration = "P2W".parse().unwrap();
+    /// let datetime = datetime!(2023-03-15 10:20 UTC);
+    /// assert_eq!(
+    ///     fortnight.align_down(datetime, WeekStart::Monday),
+    ///     Some(datetime!(2023-03-13 00:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     fortnight.align_down(datetime, WeekStart::Sunday),
+    ///     Some(datetime!(2023-03-12 00:00 UTC))
+    /// );
+    ///
+    /// let quarter: Duration = "P3M".parse().unwrap();
+    /// assert_eq!(
+    ///     quarter.align_down(datetime, WeekStart::Monday),
+    ///     Some(datetime!(2023-01-01 00:00 UTC))
+    /// );
+    /// ```
+    pub fn align_down(
+        &self,
+        datetime: OffsetDateTime,
+        week_start: WeekStart,
+    ) -> Option<OffsetDateTime> {
+        self.bucket_of(datetime, self.alignment_anchor(datetime, week_start))
+            .map(|(start, _)| start)
+    }
+
+    /// Return the first period boundary of the duration at or after
+    /// `datetime`, see [`align_down`](Duration::align_down)
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, WeekStart};
+    /// use time::macros::datetime;
+    ///
+    /// let week: Duration = "P1W".parse().unwrap();
+    /// assert_eq!(
+    ///     week.align_up(datetime!(2023-03-15 10:20 UTC), WeekStart::Sunday),
+    ///     Some(datetime!(2023-03-19 00:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     week.align_up(datetime!(2023-03-19 00:00 UTC), WeekStart::Sunday),
+    ///     Some(datetime!(2023-03-19 00:00 UTC))
+    /// );
+    /// ```
+    pub fn align_up(
+        &self,
+        datetime: OffsetDateTime,
+        week_start: WeekStart,
+    ) -> Option<OffsetDateTime> {
+        let (start, end) = self.bucket_of(datetime, self.alignment_anchor(datetime, week_start))?;
+        Some(if start == datetime { start } else { end })
+    }
+
+    /// Return the bucket of the duration containing `datetime`, aligned as
+    /// in [`align_down`](Duration::align_down)
+    pub fn aligned_bucket_of(
+        &self,
+        datetime: OffsetDateTime,
+        week_start: WeekStart,
+    ) -> Option<(OffsetDateTime, OffsetDateTime)> {
+        self.bucket_of(datetime, self.alignment_anchor(datetime, week_start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn align_on_weeks() {
+        let week: Duration = "P1W".parse().unwrap();
+        for week_start in [WeekStart::Monday, WeekStart::Sunday].iter().copied() {
+            for datetime in [
+                datetime!(1999-12-31 23:59 UTC),
+                datetime!(2001-01-01 00:00 UTC),
+                datetime!(2023-03-15 10:20 +02:00),
+            ]
+            .iter()
+            .copied()
+            {
+                let start = week.align_down(datetime, week_start).unwrap();
+                assert_eq!(start, week_start.start_of_week(datetime));
+                assert_eq!(start.weekday(), week_start.weekday());
+                assert_eq!(
+                    week.aligned_bucket_of(datetime, week_start),
+                    Some((start, start + week))
+                );
+            }
+        }
+
+        let fortnight: Duration = "P2W".parse().unwrap();
+        let monday = datetime!(2020-12-28 00:00 +01:00);
+        let next = fortnight.step_from(monday, Stepping::IsoWeek);
+        assert_eq!(next, datetime!(2021-01-11 00:00 +01:00));
+        assert_eq!(next.to_iso_week_date(), (2021, 2, Weekday::Monday));
+        assert_eq!(
+            fortnight.step_from(datetime!(2021-01-03 23:59 +01:00), Stepping::IsoWeek),
+            next
+        );
+        assert_eq!(
+            Duration::hours(6).step_from(datetime!(2021-01-02 23:59 +01:00), Stepping::IsoWeek),
+            datetime!(2021-01-03 05:59 +01:00)
+        );
+
+        let hours: Duration = "PT6H".parse().unwrap();
+        assert_eq!(
+            hours.align_down(datetime!(2023-03-15 10:20 UTC), WeekStart::Sunday),
+            Some(datetime!(2023-03-15 06:00 UTC))
+        );
+        assert_eq!(
+            hours.align_up(datetime!(2023-03-15 10:20 UTC), WeekStart::Sunday),
+            Some(datetime!(2023-03-15 12:00 UTC))
+        );
+        assert_eq!(
+            Duration::ZERO.align_down(datetime!(2023-03-15 10:20 UTC), WeekStart::Monday),
+            None
+        );
+    }
+}
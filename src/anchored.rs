@@ -0,0 +1,454 @@
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    time::Duration as StdDuration,
+};
+
+use time::OffsetDateTime;
+
+use crate::{CalendarPolicy, Duration};
+
+/// A `Duration` together with the datetime it starts at.
+///
+/// Knowing where the duration starts gives it an exact length, so unlike
+/// `Duration`, anchored durations can always be compared: `P1M` is longer
+/// than `P30D` when anchored in January, and shorter when anchored in
+/// February. Comparison and equality only look at that exact length.
+///
+/// ```rust
+/// use iso8601_duration::{AnchoredDuration, Duration};
+/// use time::macros::datetime;
+///
+/// let month: Duration = "P1M".parse().unwrap();
+/// let days: Duration = "P30D".parse().unwrap();
+///
+/// let january = datetime!(2023-01-01 00:00:00 UTC);
+/// assert!(AnchoredDuration::new(month, january) > AnchoredDuration::new(days, january));
+///
+/// let february = datetime!(2023-02-01 00:00:00 UTC);
+/// assert!(AnchoredDuration::new(month, february) < AnchoredDuration::new(days, february));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AnchoredDuration {
+    pub duration: Duration,
+    pub anchor: OffsetDateTime,
+}
+
+impl AnchoredDuration {
+    /// Anchor `duration` at `anchor`
+    pub const fn new(duration: Duration, anchor: OffsetDateTime) -> Self {
+        AnchoredDuration { duration, anchor }
+    }
+
+    /// Return the datetime the duration ends at
+    pub fn end(&self) -> OffsetDateTime {
+        self.anchor + self.duration
+    }
+
+    /// Convert to the exact `time::Duration` covered from the anchor
+    pub fn to_time(&self) -> time::Duration {
+        self.end() - self.anchor
+    }
+
+    /// Convert to the exact `std::time::Duration` covered from the anchor
+    ///
+    /// This method will return `None` if the span is negative.
+    pub fn to_std(&self) -> Option<StdDuration> {
+        StdDuration::try_from(self.to_time()).ok()
+    }
+}
+
+impl PartialEq for AnchoredDuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_time() == other.to_time()
+    }
+}
+
+impl Eq for AnchoredDuration {}
+
+impl PartialOrd for AnchoredDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AnchoredDuration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_time().cmp(&other.to_time())
+    }
+}
+
+impl Hash for AnchoredDuration {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_time().hash(state)
+    }
+}
+
+/// Formats as an ISO8601 time interval, `<anchor>/<duration>`.
+///
+/// The anchor is written like RFC 3339 when it can be, and otherwise with
+/// an expanded year such as `-0001` or `+10000`, and an offset with
+/// seconds such as `+01:02:03`, which RFC 3339 can't represent.
+impl fmt::Display for AnchoredDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let anchor = self.anchor;
+
+        if (0..=9999).contains(&anchor.year()) {
+            write!(f, "{:04}", anchor.year())?;
+        } else {
+            write!(f, "{:+05}", anchor.year())?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            u8::from(anchor.month()),
+            anchor.day(),
+            anchor.hour(),
+            anchor.minute(),
+            anchor.second()
+        )?;
+        if anchor.nanosecond() != 0 {
+            let fraction = format!("{:09}", anchor.nanosecond());
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        let offset = anchor.offset();
+        if offset.is_utc() {
+            f.write_str("Z")?;
+        } else {
+            let (hours, minutes, seconds) = offset.as_hms();
+            let sign = if offset.is_negative() { '-' } else { '+' };
+            write!(f, "{}{:02}:{:02}", sign, hours.abs(), minutes.abs())?;
+            if seconds != 0 {
+                write!(f, ":{:02}", seconds.abs())?;
+            }
+        }
+
+        write!(f, "/{}", self.duration)
+    }
+}
+
+impl Duration {
+    /// Return the number of days the duration covers when applied at
+    /// `anchor`, with the fraction of the last day.
+    ///
+    /// This is handy for progress calculations, e.g. how far into a
+    /// monthly billing period a given day is.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// assert_eq!(month.days_at(datetime!(2023-02-01 00:00:00 UTC)), 28.);
+    /// assert_eq!(month.days_at(datetime!(2023-03-01 00:00:00 UTC)), 31.);
+    /// ```
+    pub fn days_at(&self, anchor: OffsetDateTime) -> f64 {
+        self.seconds_at(anchor) / 86400.
+    }
+
+    /// Return the number of hours the duration covers when applied at `anchor`
+    pub fn hours_at(&self, anchor: OffsetDateTime) -> f64 {
+        self.seconds_at(anchor) / 3600.
+    }
+
+    /// Return the number of seconds the duration covers when applied at `anchor`
+    pub fn seconds_at(&self, anchor: OffsetDateTime) -> f64 {
+        self.to_exact(anchor).as_seconds_f64()
+    }
+
+    /// Return the exact span the duration covers when applied at `anchor`.
+    ///
+    /// Calendar components are resolved against the anchor, so `P1M`
+    /// anchored on February 1st, 2023 is exactly 28 days.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// let anchor = datetime!(2023-02-01 00:00:00 UTC);
+    /// assert_eq!(month.to_exact(anchor), time::Duration::days(28));
+    /// assert_eq!(month.num_seconds_from(anchor), 28 * 86400);
+    /// ```
+    pub fn to_exact(&self, anchor: OffsetDateTime) -> time::Duration {
+        AnchoredDuration::new(*self, anchor).to_time()
+    }
+
+    /// Return the number of whole seconds the duration covers when applied
+    /// at `anchor`
+    pub fn num_seconds_from(&self, anchor: OffsetDateTime) -> i64 {
+        self.to_exact(anchor).whole_seconds()
+    }
+
+    /// Return a key that orders durations by the exact span they cover
+    /// when applied at `anchor`, in nanoseconds.
+    ///
+    /// Unlike the floating point queries above, the key is exact, so it
+    /// can be stored in a database column and sorted or indexed there.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let anchor = datetime!(2023-02-01 00:00:00 UTC);
+    /// let mut durations: Vec<Duration> = ["P30D", "PT1H", "P1M"]
+    ///     .iter()
+    ///     .map(|s| s.parse().unwrap())
+    ///     .collect();
+    ///
+    /// durations.sort_by_key(|d| d.sort_key_at(anchor));
+    /// assert_eq!(durations[0].to_string(), "PT1H");
+    /// assert_eq!(durations[1].to_string(), "P1M");
+    /// assert_eq!(durations[2].to_string(), "P30D");
+    /// ```
+    pub fn sort_key_at(&self, anchor: OffsetDateTime) -> i128 {
+        AnchoredDuration::new(*self, anchor)
+            .to_time()
+            .whole_nanoseconds()
+    }
+
+    /// Compare the exact spans the duration and `other` cover when both
+    /// are applied at `anchor`
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// let days: Duration = "P30D".parse().unwrap();
+    ///
+    /// let january = datetime!(2023-01-01 00:00:00 UTC);
+    /// assert_eq!(month.cmp_at(&days, january), Ordering::Greater);
+    /// assert!(days.shorter_than_at(&month, january));
+    ///
+    /// let february = datetime!(2023-02-01 00:00:00 UTC);
+    /// assert!(month.shorter_than_at(&days, february));
+    /// assert!(!month.longer_than_at(&month, february));
+    /// ```
+    pub fn cmp_at(&self, other: &Duration, anchor: OffsetDateTime) -> Ordering {
+        AnchoredDuration::new(*self, anchor).cmp(&AnchoredDuration::new(*other, anchor))
+    }
+
+    /// Return `true` if the duration covers less time than `other` when
+    /// both are applied at `anchor`
+    pub fn shorter_than_at(&self, other: &Duration, anchor: OffsetDateTime) -> bool {
+        self.cmp_at(other, anchor) == Ordering::Less
+    }
+
+    /// Return `true` if the duration covers more time than `other` when
+    /// both are applied at `anchor`
+    pub fn longer_than_at(&self, other: &Duration, anchor: OffsetDateTime) -> bool {
+        self.cmp_at(other, anchor) == Ordering::Greater
+    }
+
+    /// Return how many times `other` fits exactly in the duration when
+    /// both are applied at `anchor`.
+    ///
+    /// `other` is scaled by the candidate quotient and applied at `anchor`
+    /// in one go, so `P1Y` is 12 times `P1M` at any anchor, even though
+    /// months have different lengths. This method will return `None` if
+    /// `other` is empty or doesn't divide the duration exactly.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let anchor = datetime!(2023-01-31 00:00:00 UTC);
+    /// let year: Duration = "P1Y".parse().unwrap();
+    ///
+    /// assert_eq!(year.checked_div_exact_at(&"P1M".parse().unwrap(), anchor), Some(12));
+    /// assert_eq!(year.checked_div_exact_at(&"P5M".parse().unwrap(), anchor), None);
+    /// ```
+    pub fn checked_div_exact_at(&self, other: &Duration, anchor: OffsetDateTime) -> Option<i64> {
+        let divisor = other.sort_key_at(anchor);
+        if divisor == 0 {
+            return None;
+        }
+
+        // months of different lengths make the exact ratio drift, while
+        // the average one is off when the span is mostly a single month
+        let exact = self.sort_key_at(anchor) as f64 / divisor as f64;
+        let average = self.to_seconds_f64(CalendarPolicy::Average)?
+            / other.to_seconds_f64(CalendarPolicy::Average)?;
+        let end = anchor + *self;
+
+        [exact.round(), average.round()]
+            .iter()
+            .find(|quotient| {
+                other
+                    .checked_mul(**quotient as f32)
+                    .is_some_and(|scaled| anchor + scaled == end)
+            })
+            .map(|quotient| *quotient as i64)
+    }
+
+    /// Return `true` if the duration is a whole multiple of `other` when
+    /// both are applied at `anchor`, see [`Duration::checked_div_exact_at`].
+    ///
+    /// This is meant for validating configurations, e.g. that a retention
+    /// period is a whole number of rollup intervals.
+    pub fn is_multiple_of_at(&self, other: &Duration, anchor: OffsetDateTime) -> bool {
+        self.checked_div_exact_at(other, anchor).is_some()
+    }
+
+    /// Return `true` if the duration and `other` end within `tolerance` of
+    /// each other when applied at `anchor`.
+    ///
+    /// The tolerance is itself resolved at `anchor`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// let days: Duration = "P30D".parse().unwrap();
+    /// let tolerance: Duration = "P1D".parse().unwrap();
+    ///
+    /// assert!(month.approx_eq(&days, &tolerance, datetime!(2023-01-01 00:00:00 UTC)));
+    /// assert!(!month.approx_eq(&days, &tolerance, datetime!(2023-02-01 00:00:00 UTC)));
+    /// ```
+    pub fn approx_eq(
+        &self,
+        other: &Duration,
+        tolerance: &Duration,
+        anchor: OffsetDateTime,
+    ) -> bool {
+        let difference = (anchor + *self) - (anchor + *other);
+        let tolerance = AnchoredDuration::new(*tolerance, anchor).to_time();
+
+        difference.abs() <= tolerance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn coverage_at_anchor() {
+        let duration: Duration = "P1MT12H".parse().unwrap();
+        let anchor = datetime!(2024-02-01 00:00:00 UTC);
+
+        assert_eq!(duration.days_at(anchor), 29.5);
+        assert_eq!(duration.hours_at(anchor), 29.5 * 24.);
+        assert_eq!(duration.seconds_at(anchor), 29.5 * 86400.);
+    }
+
+    #[test]
+    fn sort_key() {
+        let anchor = datetime!(2024-02-01 00:00:00 UTC);
+        let month: Duration = "P1M".parse().unwrap();
+        let days: Duration = "P29D".parse().unwrap();
+
+        assert_eq!(month.sort_key_at(anchor), days.sort_key_at(anchor));
+        assert_eq!(
+            "PT1.5S".parse::<Duration>().unwrap().sort_key_at(anchor),
+            1_500_000_000
+        );
+        assert!(Duration::MIN.sort_key_at(anchor) < Duration::ZERO.sort_key_at(anchor));
+        assert!(Duration::MAX.sort_key_at(anchor) > month.sort_key_at(anchor));
+    }
+
+    #[test]
+    fn exact_division() {
+        let anchor = datetime!(2024-02-01 00:00:00 UTC);
+        let retention: Duration = "P90D".parse().unwrap();
+
+        assert!(retention.is_multiple_of_at(&"P1D".parse().unwrap(), anchor));
+        assert!(retention.is_multiple_of_at(&"PT6H".parse().unwrap(), anchor));
+        assert!(!retention.is_multiple_of_at(&"P7D".parse().unwrap(), anchor));
+        assert!(!retention.is_multiple_of_at(&Duration::ZERO, anchor));
+        assert_eq!(
+            "P1M"
+                .parse::<Duration>()
+                .unwrap()
+                .checked_div_exact_at(&"P1D".parse().unwrap(), anchor),
+            Some(29)
+        );
+        assert_eq!(
+            Duration::ZERO.checked_div_exact_at(&"PT1H".parse().unwrap(), anchor),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn exact_conversion() {
+        let anchored = AnchoredDuration::new(
+            "P1MT1S".parse().unwrap(),
+            datetime!(2023-02-01 00:00:00 UTC),
+        );
+
+        assert_eq!(
+            anchored.to_time(),
+            time::Duration::days(28) + time::Duration::SECOND
+        );
+        assert_eq!(
+            anchored.to_std(),
+            Some(StdDuration::from_secs(28 * 86400 + 1))
+        );
+        assert_eq!(
+            anchored.duration.to_exact(anchored.anchor),
+            anchored.to_time()
+        );
+        assert_eq!(
+            Duration::parse("-P1M")
+                .unwrap()
+                .num_seconds_from(datetime!(2023-03-01 00:00:00 UTC)),
+            -28 * 86400
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let anchor = datetime!(2024-02-01 00:00:00 UTC);
+        let month = AnchoredDuration::new("P1M".parse().unwrap(), anchor);
+        let days = AnchoredDuration::new("P29D".parse().unwrap(), anchor);
+
+        assert_eq!(month, days);
+        assert_eq!(month.cmp(&days), Ordering::Equal);
+    }
+
+    #[test]
+    fn display() {
+        let anchored = AnchoredDuration::new(
+            "P1DT2H".parse().unwrap(),
+            datetime!(2023-02-01 00:00:00 UTC),
+        );
+
+        assert_eq!(anchored.to_string(), "2023-02-01T00:00:00Z/P1DT2H");
+
+        let display = |anchor| AnchoredDuration::new(anchored.duration, anchor).to_string();
+        assert_eq!(
+            display(datetime!(2023-02-01 12:30:05.25 -05:30)),
+            "2023-02-01T12:30:05.25-05:30/P1DT2H"
+        );
+        assert_eq!(
+            display(datetime!(-0001-12-31 00:00:00 UTC)),
+            "-0001-12-31T00:00:00Z/P1DT2H"
+        );
+        assert_eq!(
+            display(datetime!(2023-02-01 00:00:00 +01:02:03)),
+            "2023-02-01T00:00:00+01:02:03/P1DT2H"
+        );
+        assert_eq!(
+            display(datetime!(2023-02-01 00:00:00 -00:00:30)),
+            "2023-02-01T00:00:00-00:00:30/P1DT2H"
+        );
+    }
+
+    #[test]
+    fn approx_eq_within_tolerance() {
+        let anchor = datetime!(2023-02-01 00:00:00 UTC);
+        let a: Duration = "PT1.0001S".parse().unwrap();
+        let b: Duration = "PT1S".parse().unwrap();
+
+        assert!(a.approx_eq(&b, &"PT0.001S".parse().unwrap(), anchor));
+        assert!(b.approx_eq(&a, &"PT0.001S".parse().unwrap(), anchor));
+        assert!(!a.approx_eq(&b, &"PT0.00001S".parse().unwrap(), anchor));
+    }
+}
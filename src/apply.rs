@@ -0,0 +1,254 @@
+#[cfg(any(feature = "chrono", feature = "time_03"))]
+use std::convert::TryFrom;
+use std::{error::Error, fmt};
+
+use crate::Duration;
+
+/// How adding months picks the day in the target month, in particular
+/// when it would land past the end of it, e.g. one month after January 31.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EomPolicy {
+    /// Use the last day of the target month: January 31 + `P1M` is
+    /// February 28. This is what `+` does.
+    #[default]
+    Clamp,
+    /// Carry the extra days into the next month: January 31 + `P1M` is
+    /// March 3.
+    Overflow,
+    /// Fail with [`ApplyError::DayOutOfMonth`].
+    Reject,
+    /// Keep the weekday of month instead of the day number: the second
+    /// Tuesday of January + `P1M` is the second Tuesday of February. A
+    /// fifth weekday becomes the last one when the target month only has
+    /// four of them.
+    SameWeekday,
+}
+
+/// Error returned by [`ApplyDuration::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ApplyError {
+    /// A component of the duration is NaN or infinite.
+    NonFinite,
+    /// The day doesn't exist in the target month and the policy is
+    /// [`EomPolicy::Reject`].
+    DayOutOfMonth,
+    /// The result is out of the range of the datetime type.
+    OutOfRange,
+    /// The resulting local time is ambiguous or doesn't exist in the
+    /// time zone.
+    InvalidLocalTime,
+}
+
+impl fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ApplyError::NonFinite => "duration has a non-finite component",
+            ApplyError::DayOutOfMonth => "day doesn't exist in the target month",
+            ApplyError::OutOfRange => "result is out of range",
+            ApplyError::InvalidLocalTime => "result is not a valid local time",
+        })
+    }
+}
+
+impl Error for ApplyError {}
+
+/// Datetime types a [`Duration`] can be added to, following the
+/// calendar.
+///
+/// `year` and `month` move the date by whole calendar months, with
+/// `policy` deciding what happens past the end of a month, then `day`
+/// moves it by calendar days and the time components are added as an
+/// exact span. Fractions of years, months and days are dropped.
+///
+/// This crate implements it for `time::OffsetDateTime` (with the
+/// `time_03` feature) and `chrono::DateTime` (with the `chrono`
+/// feature). Implement it for other datetime types to write code that is
+/// generic over them.
+pub trait ApplyDuration: Sized {
+    /// Return `self` moved forward by `duration`
+    fn apply(&self, duration: &Duration, policy: EomPolicy) -> Result<Self, ApplyError>;
+}
+
+/// Another name for [`EomPolicy`], matching the wording of other calendar
+/// libraries.
+pub type OverflowPolicy = EomPolicy;
+
+impl Duration {
+    /// Return `datetime` moved forward by this duration, resolving days
+    /// past the end of a month with `policy`
+    ///
+    /// This is the same as [`ApplyDuration::apply`], written from the
+    /// duration's side.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "time_03")]
+    /// # {
+    /// use iso8601_duration::{ApplyError, Duration, OverflowPolicy};
+    /// use time::macros::datetime;
+    ///
+    /// let month = Duration::months(1);
+    /// let start = datetime!(2023-01-31 00:00 UTC);
+    /// assert_eq!(
+    ///     month.add_to_with(start, OverflowPolicy::Clamp),
+    ///     Ok(datetime!(2023-02-28 00:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     month.add_to_with(start, OverflowPolicy::Overflow),
+    ///     Ok(datetime!(2023-03-03 00:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     month.add_to_with(start, OverflowPolicy::Reject),
+    ///     Err(ApplyError::DayOutOfMonth)
+    /// );
+    /// # }
+    /// ```
+    pub fn add_to_with<T: ApplyDuration>(
+        &self,
+        datetime: T,
+        policy: EomPolicy,
+    ) -> Result<T, ApplyError> {
+        datetime.apply(self, policy)
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time_03"))]
+/// Return the number of days in `month` of `year`.
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time_03"))]
+/// Return the day of the week of a date, from 0 for Sunday to 6 for Saturday.
+fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    const OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+    let year = year as i64 - (month < 3) as i64;
+    let days = year + year.div_euclid(4) - year.div_euclid(100)
+        + year.div_euclid(400)
+        + OFFSETS[month as usize - 1]
+        + day as i64;
+    days.rem_euclid(7) as u32
+}
+
+#[cfg(any(feature = "chrono", feature = "time_03"))]
+/// Move a calendar date by whole `months`, resolving days past the end of
+/// the target month with `policy`.
+///
+/// Returns the target year, month and day, and the number of days to add
+/// on top of it when the policy is `Overflow`.
+pub(crate) fn shift_months(
+    (from_year, from_month, day): (i32, u32, u32),
+    months: i64,
+    policy: EomPolicy,
+) -> Result<(i32, u32, u32, u32), ApplyError> {
+    let total = (from_year as i64 * 12 + from_month as i64 - 1)
+        .checked_add(months)
+        .ok_or(ApplyError::OutOfRange)?;
+    let year = i32::try_from(total.div_euclid(12)).map_err(|_| ApplyError::OutOfRange)?;
+    let month = total.rem_euclid(12) as u32 + 1;
+    let length = days_in_month(year, month);
+
+    match policy {
+        EomPolicy::SameWeekday => {
+            // first day of the target month with the same weekday, then
+            // as many weeks as in the source month
+            let first = (weekday(from_year, from_month, day) + 7 - weekday(year, month, 1)) % 7 + 1;
+            let target = first + (day - 1) / 7 * 7;
+            Ok((
+                year,
+                month,
+                if target > length { target - 7 } else { target },
+                0,
+            ))
+        }
+        _ if day <= length => Ok((year, month, day, 0)),
+        EomPolicy::Clamp => Ok((year, month, length, 0)),
+        EomPolicy::Overflow => Ok((year, month, length, day - length)),
+        EomPolicy::Reject => Err(ApplyError::DayOutOfMonth),
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time_03"))]
+/// Return the whole months, whole days and nanoseconds of time to add,
+/// failing on non-finite components.
+pub(crate) fn whole_parts(duration: &Duration) -> Result<(i64, i64, i128), ApplyError> {
+    if duration
+        .components()
+        .iter()
+        .any(|(_, value)| !value.is_finite())
+    {
+        return Err(ApplyError::NonFinite);
+    }
+
+    let months = (duration.year as i64)
+        .checked_mul(12)
+        .and_then(|months| months.checked_add(duration.month as i64))
+        .ok_or(ApplyError::OutOfRange)?;
+    let nanos =
+        (duration.hour as f64 * 3600. + duration.minute as f64 * 60. + duration.second as f64)
+            * 1e9;
+
+    // no supported calendar spans more days than this
+    let days = duration.day as i64;
+    if days.unsigned_abs() > u32::MAX as u64 {
+        return Err(ApplyError::OutOfRange);
+    }
+
+    Ok((months, days, nanos as i128))
+}
+
+#[cfg(all(test, any(feature = "chrono", feature = "time_03")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shift_keeping_weekday() {
+        // second Tuesday
+        assert_eq!(
+            shift_months((2023, 1, 10), 1, EomPolicy::SameWeekday),
+            Ok((2023, 2, 14, 0))
+        );
+        // fifth Tuesday, February only has four
+        assert_eq!(
+            shift_months((2023, 1, 31), 1, EomPolicy::SameWeekday),
+            Ok((2023, 2, 28, 0))
+        );
+        // first Friday, a year later
+        assert_eq!(
+            shift_months((2024, 3, 1), 12, EomPolicy::SameWeekday),
+            Ok((2025, 3, 7, 0))
+        );
+        assert_eq!(weekday(2000, 1, 1), 6);
+        assert_eq!(weekday(-1, 12, 31), 5);
+    }
+
+    #[test]
+    fn shift_across_month_ends() {
+        assert_eq!(
+            shift_months((2023, 1, 31), 1, EomPolicy::Clamp),
+            Ok((2023, 2, 28, 0))
+        );
+        assert_eq!(
+            shift_months((2023, 1, 31), 1, EomPolicy::Overflow),
+            Ok((2023, 2, 28, 3))
+        );
+        assert_eq!(
+            shift_months((2023, 1, 31), 1, EomPolicy::Reject),
+            Err(ApplyError::DayOutOfMonth)
+        );
+        assert_eq!(
+            shift_months((2024, 2, 29), -12, EomPolicy::Clamp),
+            Ok((2023, 2, 28, 0))
+        );
+        assert_eq!(
+            shift_months((2023, 12, 15), 1, EomPolicy::Reject),
+            Ok((2024, 1, 15, 0))
+        );
+    }
+}
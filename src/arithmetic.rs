@@ -0,0 +1,218 @@
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::Duration;
+
+fn zip_with(a: &Duration, b: &Duration, f: impl Fn(f32, f32) -> f32) -> Duration {
+    Duration::new(
+        f(a.year, b.year),
+        f(a.month, b.month),
+        f(a.day, b.day),
+        f(a.hour, b.hour),
+        f(a.minute, b.minute),
+        f(a.second, b.second),
+    )
+}
+
+fn is_finite(duration: &Duration) -> bool {
+    duration
+        .components()
+        .iter()
+        .all(|(_, value)| value.is_finite())
+}
+
+impl Duration {
+    /// The empty duration.
+    pub const ZERO: Duration = Duration::new(0., 0., 0., 0., 0., 0.);
+
+    /// The largest duration, every component being `f32::MAX`.
+    ///
+    /// It can be used as a "forever" sentinel: saturating arithmetic stops
+    /// at it, and adding it to a datetime gives the latest representable
+    /// datetime instead of panicking.
+    pub const MAX: Duration =
+        Duration::new(f32::MAX, f32::MAX, f32::MAX, f32::MAX, f32::MAX, f32::MAX);
+
+    /// The smallest duration, every component being `f32::MIN`.
+    pub const MIN: Duration =
+        Duration::new(f32::MIN, f32::MIN, f32::MIN, f32::MIN, f32::MIN, f32::MIN);
+
+    /// Add `other` component-wise, returning `None` if a component
+    /// overflows or isn't finite
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use iso8601_duration::Duration;
+    ///
+    /// let day: Duration = "P1D".parse().unwrap();
+    /// assert_eq!(day.checked_add(&day), Some("P2D".parse().unwrap()));
+    /// assert_eq!(Duration::MAX.checked_add(&Duration::MAX), None);
+    /// # }
+    /// ```
+    pub fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        Some(zip_with(self, other, |a, b| a + b)).filter(is_finite)
+    }
+
+    /// Subtract `other` component-wise, returning `None` if a component
+    /// overflows or isn't finite
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        Some(zip_with(self, other, |a, b| a - b)).filter(is_finite)
+    }
+
+    /// Add `other` component-wise, clamping components between
+    /// [`Duration::MIN`] and [`Duration::MAX`]
+    pub fn saturating_add(&self, other: &Duration) -> Duration {
+        zip_with(self, other, |a, b| (a + b).clamp(f32::MIN, f32::MAX))
+    }
+
+    /// Multiply every component by `factor`, returning `None` if a
+    /// component overflows or isn't finite
+    pub fn checked_mul(&self, factor: f32) -> Option<Duration> {
+        Some(zip_with(self, self, |a, _| a * factor)).filter(is_finite)
+    }
+
+    /// Divide every component by `divisor`, returning `None` if a
+    /// component overflows or isn't finite, which includes dividing by zero
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use iso8601_duration::Duration;
+    ///
+    /// let hour: Duration = "PT1H".parse().unwrap();
+    /// assert_eq!(hour.checked_div(4.), Some("PT0.25H".parse().unwrap()));
+    /// assert_eq!(hour.checked_div(0.), None);
+    /// # }
+    /// ```
+    pub fn checked_div(&self, divisor: f32) -> Option<Duration> {
+        Some(zip_with(self, self, |a, _| a / divisor)).filter(is_finite)
+    }
+
+    /// Multiply every component by `factor`, clamping components between
+    /// [`Duration::MIN`] and [`Duration::MAX`]
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert_eq!(Duration::MAX.saturating_mul(2.), Duration::MAX);
+    /// ```
+    pub fn saturating_mul(&self, factor: f32) -> Duration {
+        zip_with(self, self, |a, _| (a * factor).clamp(f32::MIN, f32::MAX))
+    }
+}
+
+/// Add component-wise, without carrying between units: `PT45M + PT30M`
+/// is `PT75M`.
+///
+/// ```rust
+/// # #[cfg(feature = "std")]
+/// # {
+/// use iso8601_duration::Duration;
+///
+/// let month: Duration = "P1M".parse().unwrap();
+/// let fortnight: Duration = "P15D".parse().unwrap();
+/// assert_eq!(month + fortnight, "P1M15D".parse().unwrap());
+/// assert_eq!(month + fortnight - month, fortnight);
+/// # }
+/// ```
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Duration) -> Duration {
+        zip_with(&self, &rhs, |a, b| a + b)
+    }
+}
+
+/// Subtract component-wise, without borrowing between units.
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, rhs: Duration) -> Duration {
+        zip_with(&self, &rhs, |a, b| a - b)
+    }
+}
+
+/// Multiply every component.
+///
+/// ```rust
+/// # #[cfg(feature = "std")]
+/// # {
+/// use iso8601_duration::Duration;
+///
+/// let half_hour: Duration = "PT30M".parse().unwrap();
+/// assert_eq!(half_hour * 4., "PT120M".parse().unwrap());
+/// assert_eq!(half_hour * 4. / 2., "PT60M".parse().unwrap());
+/// # }
+/// ```
+impl Mul<f32> for Duration {
+    type Output = Duration;
+
+    fn mul(self, rhs: f32) -> Duration {
+        zip_with(&self, &self, |a, _| a * rhs)
+    }
+}
+
+/// Divide every component.
+impl Div<f32> for Duration {
+    type Output = Duration;
+
+    fn div(self, rhs: f32) -> Duration {
+        zip_with(&self, &self, |a, _| a / rhs)
+    }
+}
+
+/// Negate every component.
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        Duration::new(
+            -self.year,
+            -self.month,
+            -self.day,
+            -self.hour,
+            -self.minute,
+            -self.second,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_and_saturating() {
+        let duration: Duration = "P1Y2M3DT4H5M6S".parse().unwrap();
+
+        assert_eq!(duration.checked_add(&Duration::ZERO), Some(duration));
+        assert_eq!(
+            duration.checked_mul(2.),
+            Some("P2Y4M6DT8H10M12S".parse().unwrap())
+        );
+        assert_eq!(Duration::MAX.checked_add(&duration), Some(Duration::MAX));
+        assert_eq!(Duration::MAX.checked_mul(2.), None);
+        assert_eq!(Duration::MAX.checked_mul(f32::NAN), None);
+
+        assert_eq!(Duration::MAX.saturating_add(&Duration::MAX), Duration::MAX);
+        assert_eq!(Duration::MIN.saturating_add(&Duration::MIN), Duration::MIN);
+        assert_eq!(Duration::MAX.saturating_mul(-2.), Duration::MIN);
+        assert_eq!(Duration::MAX.saturating_add(&Duration::MIN), Duration::ZERO);
+    }
+
+    #[test]
+    fn operators() {
+        let a: Duration = "P1Y2DT3H".parse().unwrap();
+        let b: Duration = "P1MT30M".parse().unwrap();
+
+        assert_eq!(a + b, "P1Y1M2DT3H30M".parse().unwrap());
+        assert_eq!(a - b, Duration::new(1., -1., 2., 3., -30., 0.));
+        assert_eq!(a.checked_sub(&b), Some(a - b));
+        assert_eq!(Duration::MIN.checked_sub(&Duration::MAX), None);
+
+        assert_eq!(a * 2., a + a);
+        assert_eq!((a * 2.) / 2., a);
+        assert_eq!(a.checked_div(2.), Some(a / 2.));
+        assert_eq!(Duration::MIN.checked_div(0.5), None);
+    }
+}
@@ -0,0 +1,133 @@
+use std::{error::Error, fmt};
+
+use axum::{
+    extract::{rejection::PathRejection, FromRequestParts, Path},
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{DurationParam, ErrorBody, ParseDurationError};
+
+/// Extracts the duration from the only path parameter of the route.
+///
+/// Invalid durations are rejected with a 400 response whose body is the
+/// [`ErrorBody`] of the parse error as JSON. For query strings or routes
+/// with several parameters, use `DurationParam` as a field of the
+/// `Query` or `Path` struct instead.
+///
+/// ```rust
+/// use axum::{routing::get, Router};
+/// use iso8601_duration::DurationParam;
+///
+/// async fn sleep(DurationParam(duration): DurationParam) -> String {
+///     format!("sleeping for {}", duration)
+/// }
+///
+/// let app: Router = Router::new().route("/sleep/{duration}", get(sleep));
+/// ```
+impl<S: Send + Sync> FromRequestParts<S> for DurationParam {
+    type Rejection = DurationRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(param) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(DurationRejection::Path)?;
+
+        param.parse().map_err(DurationRejection::Invalid)
+    }
+}
+
+/// Rejection used for [`DurationParam`] when extracted from a path.
+#[derive(Debug)]
+pub enum DurationRejection {
+    /// The route doesn't have exactly one path parameter.
+    Path(PathRejection),
+    /// The path parameter isn't a valid ISO8601 duration.
+    Invalid(ParseDurationError),
+}
+
+impl fmt::Display for DurationRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationRejection::Path(rejection) => rejection.fmt(f),
+            DurationRejection::Invalid(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for DurationRejection {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DurationRejection::Path(rejection) => Some(rejection),
+            DurationRejection::Invalid(error) => Some(error),
+        }
+    }
+}
+
+impl IntoResponse for DurationRejection {
+    fn into_response(self) -> Response {
+        match self {
+            DurationRejection::Path(rejection) => rejection.into_response(),
+            DurationRejection::Invalid(error) => error.to_error_body().into_response(),
+        }
+    }
+}
+
+/// Responds with a 400 and the error as JSON.
+impl IntoResponse for ErrorBody<'_> {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, Json(self)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+        routing::get,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn get_path(app: Router, uri: &str) -> (StatusCode, String) {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn extract_path() {
+        let app = Router::new()
+            .route(
+                "/sleep/{duration}",
+                get(|DurationParam(duration): DurationParam| async move { duration.to_string() }),
+            )
+            .route(
+                "/{a}/{b}",
+                get(|DurationParam(duration): DurationParam| async move { duration.to_string() }),
+            );
+
+        assert_eq!(
+            get_path(app.clone(), "/sleep/PT90M").await,
+            (StatusCode::OK, "PT90M".to_string())
+        );
+        assert_eq!(
+            get_path(app.clone(), "/sleep/5M").await,
+            (
+                StatusCode::BAD_REQUEST,
+                r#"{"code":"E001_MISSING_P","message":"duration must start with 'P'","input":"5M","position":0}"#
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            get_path(app, "/PT1S/PT2S").await.0,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
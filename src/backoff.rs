@@ -0,0 +1,155 @@
+use crate::{CalendarPolicy, Duration};
+
+/// How the delays of a [`Backoff`] grow from one attempt to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Growth {
+    /// The `n`-th delay is `base * factor^n`.
+    #[default]
+    Exponential,
+    /// The `n`-th delay is `base * (1 + factor * n)`.
+    Linear,
+}
+
+/// A retry policy producing a sequence of delays from a base `Duration`.
+///
+/// Delays are scaled component-wise, so `P1D` doubles to `P2D` rather
+/// than to 48 hours, and are capped at `max` when it's set. Durations
+/// are compared with the average Gregorian year and month lengths for
+/// the cap, see [`CalendarPolicy::Average`].
+///
+/// With the `serde` feature, it (de)serializes as a struct such as
+/// `{"base": "PT1S", "factor": 2, "max": "PT1M", "growth": "exponential"}`,
+/// where only `base` is required.
+///
+/// ```rust
+/// use iso8601_duration::{Backoff, Duration};
+///
+/// let backoff = Backoff::exponential("PT1S".parse().unwrap(), 2.)
+///     .with_max("PT5S".parse().unwrap());
+///
+/// let delays: Vec<String> = backoff.iter().take(5).map(|d| d.to_string()).collect();
+/// assert_eq!(delays, ["PT1S", "PT2S", "PT4S", "PT5S", "PT5S"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    pub base: Duration,
+    pub factor: f32,
+    pub max: Option<Duration>,
+    pub growth: Growth,
+}
+
+impl Backoff {
+    /// Create a backoff whose delays are `base * factor^n`
+    pub const fn exponential(base: Duration, factor: f32) -> Self {
+        Backoff {
+            base,
+            factor,
+            max: None,
+            growth: Growth::Exponential,
+        }
+    }
+
+    /// Create a backoff whose delays are `base * (1 + factor * n)`
+    pub const fn linear(base: Duration, factor: f32) -> Self {
+        Backoff {
+            base,
+            factor,
+            max: None,
+            growth: Growth::Linear,
+        }
+    }
+
+    /// Cap the delays at `max`
+    pub const fn with_max(self, max: Duration) -> Self {
+        Backoff {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    /// Return the delay before the retry number `attempt`, starting at 0
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let scale = match self.growth {
+            Growth::Exponential => self.factor.powf(attempt as f32),
+            Growth::Linear => 1. + self.factor * attempt as f32,
+        };
+        // an infinite scale would turn zero components into NaN
+        let delay = self.base.saturating_mul(scale.min(f32::MAX));
+
+        match self.max {
+            Some(max) if length(&delay) > length(&max) => max,
+            _ => delay,
+        }
+    }
+
+    /// Return an endless iterator over the delays
+    pub fn iter(&self) -> BackoffIter<'_> {
+        BackoffIter {
+            backoff: self,
+            attempt: 0,
+        }
+    }
+}
+
+fn length(duration: &Duration) -> f64 {
+    duration
+        .to_seconds_f64(CalendarPolicy::Average)
+        .unwrap_or_default()
+}
+
+/// Iterator over the delays of a [`Backoff`], see [`Backoff::iter`].
+#[derive(Debug, Clone)]
+pub struct BackoffIter<'a> {
+    backoff: &'a Backoff,
+    attempt: u32,
+}
+
+impl Iterator for BackoffIter<'_> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let delay = self.backoff.delay(self.attempt);
+        self.attempt = self.attempt.saturating_add(1);
+        Some(delay)
+    }
+}
+
+impl<'a> IntoIterator for &'a Backoff {
+    type Item = Duration;
+    type IntoIter = BackoffIter<'a>;
+
+    fn into_iter(self) -> BackoffIter<'a> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_delays() {
+        let backoff = Backoff::linear("PT10S".parse().unwrap(), 0.5);
+        let delays: Vec<Duration> = backoff.iter().take(3).collect();
+
+        assert_eq!(
+            delays,
+            [
+                Duration::new(0., 0., 0., 0., 0., 10.),
+                Duration::new(0., 0., 0., 0., 0., 15.),
+                Duration::new(0., 0., 0., 0., 0., 20.),
+            ]
+        );
+    }
+
+    #[test]
+    fn capped_delays() {
+        let backoff =
+            Backoff::exponential("P1D".parse().unwrap(), 3.).with_max("P1M".parse().unwrap());
+
+        assert_eq!(backoff.delay(2), "P9D".parse().unwrap());
+        assert_eq!(backoff.delay(3), "P27D".parse().unwrap());
+        assert_eq!(backoff.delay(4), "P1M".parse().unwrap());
+        assert_eq!(backoff.delay(u32::MAX), "P1M".parse().unwrap());
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt;
+
+use crate::{Duration, Unit};
+
+/// The non-zero components of a duration, see [`Duration::breakdown`].
+///
+/// `Display` renders one component per line, names left-aligned and
+/// values right-aligned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Breakdown {
+    pub components: Vec<(Unit, f64)>,
+}
+
+fn unit_name(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Year => "years",
+        #[cfg(feature = "extensions")]
+        Unit::Quarter => "quarters",
+        Unit::Month => "months",
+        Unit::Day => "days",
+        Unit::Hour => "hours",
+        Unit::Minute => "minutes",
+        Unit::Second => "seconds",
+    }
+}
+
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.components.is_empty() {
+            return f.write_str("(zero)");
+        }
+
+        let values: Vec<String> = self
+            .components
+            .iter()
+            .map(|(_, value)| value.to_string())
+            .collect();
+        let width = values.iter().map(String::len).max().unwrap_or_default();
+
+        for (i, ((unit, _), value)) in self.components.iter().zip(&values).enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{:<8}{:>width$}", unit_name(*unit), value, width = width)?;
+        }
+        Ok(())
+    }
+}
+
+impl Duration {
+    /// Return the non-zero components of the duration, for debug output
+    /// or explaining a duration to users.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, Unit};
+    ///
+    /// let breakdown = Duration::parse("P1DT12H0.5S").unwrap().breakdown();
+    /// assert_eq!(breakdown.components[0], (Unit::Day, 1.));
+    /// assert_eq!(
+    ///     breakdown.to_string(),
+    ///     "days      1\nhours    12\nseconds 0.5"
+    /// );
+    /// ```
+    pub fn breakdown(&self) -> Breakdown {
+        Breakdown {
+            components: self
+                .components()
+                .iter()
+                .filter(|(_, value)| *value != 0.0)
+                .map(|(unit, value)| (*unit, *value as f64))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakdown() {
+        let zero = Duration::new(0., 0., 0., 0., 0., 0.).breakdown();
+        assert!(zero.components.is_empty());
+        assert_eq!(zero.to_string(), "(zero)");
+
+        let breakdown = Duration::parse("P2Y3M").unwrap().breakdown();
+        assert_eq!(
+            breakdown.components,
+            vec![(Unit::Year, 2.), (Unit::Month, 3.)]
+        );
+        assert_eq!(breakdown.to_string(), "years   2\nmonths  3");
+    }
+}
@@ -0,0 +1,202 @@
+use std::{error::Error, fmt};
+
+use crate::{Duration, Unit};
+
+/// Error returned by [`DurationBuilder::build`] and the shorthand
+/// constructors which validate their input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// A component is negative.
+    Negative(Unit),
+    /// A component is NaN or infinite.
+    NonFinite(Unit),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::Negative(unit) => write!(f, "{:?} component is negative", unit),
+            BuildError::NonFinite(unit) => {
+                write!(f, "{:?} component is not a finite number", unit)
+            }
+        }
+    }
+}
+
+impl Error for BuildError {}
+
+/// A builder for [`Duration`], see [`Duration::builder`].
+///
+/// Setting a component again adds to it, so `.weeks(1).days(2)` gives
+/// nine days.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationBuilder {
+    duration: Duration,
+}
+
+impl Default for DurationBuilder {
+    fn default() -> Self {
+        DurationBuilder {
+            duration: Duration::ZERO,
+        }
+    }
+}
+
+impl DurationBuilder {
+    /// Add `years` years
+    pub fn years(mut self, years: f32) -> Self {
+        self.duration.year += years;
+        self
+    }
+
+    /// Add `months` months
+    pub fn months(mut self, months: f32) -> Self {
+        self.duration.month += months;
+        self
+    }
+
+    /// Add `weeks` weeks, stored as days
+    pub fn weeks(mut self, weeks: f32) -> Self {
+        self.duration.day += weeks * 7.;
+        self
+    }
+
+    /// Add `days` days
+    pub fn days(mut self, days: f32) -> Self {
+        self.duration.day += days;
+        self
+    }
+
+    /// Add `hours` hours
+    pub fn hours(mut self, hours: f32) -> Self {
+        self.duration.hour += hours;
+        self
+    }
+
+    /// Add `minutes` minutes
+    pub fn minutes(mut self, minutes: f32) -> Self {
+        self.duration.minute += minutes;
+        self
+    }
+
+    /// Add `seconds` seconds
+    pub fn seconds(mut self, seconds: f32) -> Self {
+        self.duration.second += seconds;
+        self
+    }
+
+    /// Return the duration, failing on the first negative, NaN or
+    /// infinite component
+    pub fn build(self) -> Result<Duration, BuildError> {
+        for (unit, value) in self.duration.components() {
+            if !value.is_finite() {
+                return Err(BuildError::NonFinite(unit));
+            }
+            if value < 0. {
+                return Err(BuildError::Negative(unit));
+            }
+        }
+
+        Ok(self.duration)
+    }
+}
+
+impl Duration {
+    /// Return a builder setting components one at a time.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{BuildError, Duration, Unit};
+    ///
+    /// let duration = Duration::builder().years(1.).days(3.).seconds(30.).build();
+    /// assert_eq!(duration, Ok(Duration::new(1., 0., 3., 0., 0., 30.)));
+    ///
+    /// let duration = Duration::builder().hours(-1.).build();
+    /// assert_eq!(duration, Err(BuildError::Negative(Unit::Hour)));
+    /// ```
+    pub fn builder() -> DurationBuilder {
+        DurationBuilder::default()
+    }
+
+    /// Return the empty duration, [`Duration::ZERO`]
+    pub const fn zero() -> Self {
+        Duration::ZERO
+    }
+
+    /// Return `true` if every component is zero
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert!(Duration::zero().is_zero());
+    /// assert!(Duration::parse("P0Y0DT0S").unwrap().is_zero());
+    /// assert!(!Duration::new(0., 0., f32::NAN, 0., 0., 0.).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.components().iter().all(|(_, value)| *value == 0.)
+    }
+
+    /// Create a duration of `days` days, which may have a fraction,
+    /// failing if it's negative or not finite
+    pub fn from_days(days: f32) -> Result<Duration, BuildError> {
+        Duration::builder().days(days).build()
+    }
+
+    /// Create a duration of `hours`, `minutes` and `seconds`, failing if a
+    /// component is negative or not finite
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert_eq!(Duration::hms(1., 30., 0.5).unwrap().to_string(), "PT1H30M0.5S");
+    /// assert!(Duration::hms(0., f32::NAN, 0.).is_err());
+    /// ```
+    pub fn hms(hours: f32, minutes: f32, seconds: f32) -> Result<Duration, BuildError> {
+        Duration::builder()
+            .hours(hours)
+            .minutes(minutes)
+            .seconds(seconds)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder() {
+        assert_eq!(Duration::builder().build(), Ok(Duration::ZERO));
+        assert_eq!(
+            Duration::builder().weeks(1.).days(2.).minutes(90.).build(),
+            Ok(Duration::new(0., 0., 9., 0., 90., 0.))
+        );
+        assert_eq!(
+            Duration::builder().months(1.).months(0.5).build(),
+            Ok(Duration::new(0., 1.5, 0., 0., 0., 0.))
+        );
+        assert_eq!(
+            Duration::builder().years(f32::INFINITY).build(),
+            Err(BuildError::NonFinite(Unit::Year))
+        );
+        assert_eq!(
+            Duration::builder()
+                .seconds(f32::MAX)
+                .seconds(f32::MAX)
+                .build(),
+            Err(BuildError::NonFinite(Unit::Second))
+        );
+
+        assert_eq!(
+            Duration::from_days(1.5),
+            Ok(Duration::new(0., 0., 1.5, 0., 0., 0.))
+        );
+        assert_eq!(
+            Duration::from_days(-1.),
+            Err(BuildError::Negative(Unit::Day))
+        );
+        assert_eq!(
+            Duration::hms(0., -1., 0.),
+            Err(BuildError::Negative(Unit::Minute))
+        );
+    }
+}
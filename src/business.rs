@@ -0,0 +1,466 @@
+use std::{error::Error, fmt};
+
+use time::{Date, OffsetDateTime, Time, Weekday};
+
+use crate::time_03::{add_time, add_year_month, time_duration};
+use crate::Duration;
+
+/// A working week, describing which weekdays count as business days.
+///
+/// The default calendar treats Saturday and Sunday as the weekend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    // indexed by `Weekday::number_days_from_monday`
+    weekend: [bool; 7],
+}
+
+/// Error returned when the `day` component can't be counted in business
+/// days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusinessDayError {
+    /// `day` has a fractional part, e.g. `P1.5D`.
+    FractionalDays,
+    /// `day` is NaN or infinite.
+    NonFinite,
+}
+
+impl fmt::Display for BusinessDayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BusinessDayError::FractionalDays => "business days must be a whole number",
+            BusinessDayError::NonFinite => "duration has a non-finite day component",
+        })
+    }
+}
+
+impl Error for BusinessDayError {}
+
+/// Return the `day` component of `duration` as a whole number of days.
+fn whole_days(duration: &Duration) -> Result<i64, BusinessDayError> {
+    if !duration.day.is_finite() {
+        return Err(BusinessDayError::NonFinite);
+    }
+    if duration.day.fract() != 0.0 {
+        return Err(BusinessDayError::FractionalDays);
+    }
+    Ok(duration.day as i64)
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar::with_weekend(&[Weekday::Saturday, Weekday::Sunday])
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a calendar with a Saturday/Sunday weekend
+    pub fn new() -> Self {
+        BusinessCalendar::default()
+    }
+
+    /// Create a calendar with the given weekend days
+    pub fn with_weekend(days: &[Weekday]) -> Self {
+        let mut weekend = [false; 7];
+        for day in days {
+            weekend[day.number_days_from_monday() as usize] = true;
+        }
+        BusinessCalendar { weekend }
+    }
+
+    /// Return `true` if `date` is a business day
+    pub fn is_business_day(&self, date: Date) -> bool {
+        !self.weekend[date.weekday().number_days_from_monday() as usize]
+    }
+
+    /// Move `date` by `days` business days, skipping `holidays`, forward
+    /// when `days` is positive and backward when it's negative.
+    ///
+    /// The starting date itself is never counted. If the calendar has
+    /// no business day at all, `date` is returned unchanged.
+    pub(crate) fn add_business_days<H: HolidayProvider + ?Sized>(
+        &self,
+        mut date: Date,
+        mut days: i64,
+        holidays: &H,
+    ) -> Date {
+        if self.weekend.iter().all(|&weekend| weekend) {
+            return date;
+        }
+
+        while days != 0 {
+            let next = if days > 0 {
+                date.next_day()
+            } else {
+                date.previous_day()
+            };
+            date = match next {
+                Some(next) => next,
+                None => return date,
+            };
+            if self.is_business_day(date) && !holidays.is_holiday(date) {
+                days -= days.signum();
+            }
+        }
+
+        date
+    }
+}
+
+/// Daily working window on the business days of a [`BusinessCalendar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingHours {
+    start: Time,
+    end: Time,
+    calendar: BusinessCalendar,
+}
+
+impl WorkingHours {
+    /// Create working hours from `start` to `end` on each business day
+    /// of `calendar`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not before `end`.
+    pub fn new(start: Time, end: Time, calendar: BusinessCalendar) -> Self {
+        assert!(start < end, "working hours must start before they end");
+        WorkingHours {
+            start,
+            end,
+            calendar,
+        }
+    }
+
+    /// Consume `remaining` within the working windows, starting at
+    /// `datetime` and going backward when `remaining` is negative.
+    fn consume<H: HolidayProvider + ?Sized>(
+        &self,
+        mut datetime: OffsetDateTime,
+        mut remaining: time::Duration,
+        holidays: &H,
+    ) -> OffsetDateTime {
+        if remaining.is_negative() {
+            return self.consume_backward(datetime, -remaining, holidays);
+        }
+        if remaining.is_zero() {
+            return datetime;
+        }
+
+        loop {
+            let date = datetime.date();
+            if !self.calendar.is_business_day(date)
+                || holidays.is_holiday(date)
+                || datetime.time() >= self.end
+            {
+                let next = self.calendar.add_business_days(date, 1, holidays);
+                if next == date {
+                    return datetime;
+                }
+                datetime = datetime.replace_date(next).replace_time(self.start);
+                continue;
+            }
+
+            if datetime.time() < self.start {
+                datetime = datetime.replace_time(self.start);
+            }
+
+            let available = datetime.replace_time(self.end) - datetime;
+            if remaining <= available {
+                return datetime + remaining;
+            }
+
+            remaining -= available;
+            datetime = datetime.replace_time(self.end);
+        }
+    }
+
+    /// Consume `remaining` within the working windows, going backward
+    /// from `datetime`.
+    fn consume_backward<H: HolidayProvider + ?Sized>(
+        &self,
+        mut datetime: OffsetDateTime,
+        mut remaining: time::Duration,
+        holidays: &H,
+    ) -> OffsetDateTime {
+        loop {
+            let date = datetime.date();
+            if !self.calendar.is_business_day(date)
+                || holidays.is_holiday(date)
+                || datetime.time() <= self.start
+            {
+                let previous = self.calendar.add_business_days(date, -1, holidays);
+                if previous == date {
+                    return datetime;
+                }
+                datetime = datetime.replace_date(previous).replace_time(self.end);
+                continue;
+            }
+
+            if datetime.time() > self.end {
+                datetime = datetime.replace_time(self.end);
+            }
+
+            let available = datetime - datetime.replace_time(self.start);
+            if remaining <= available {
+                return datetime - remaining;
+            }
+
+            remaining -= available;
+            datetime = datetime.replace_time(self.start);
+        }
+    }
+}
+
+/// A source of non-working days, consulted on top of a [`BusinessCalendar`].
+///
+/// This crate doesn't ship any regional calendar, implement this trait
+/// to plug one in. A plain list of dates can be used as is:
+///
+/// ```rust
+/// use iso8601_duration::{BusinessCalendar, Duration};
+/// use time::macros::{date, datetime};
+///
+/// let holidays = vec![date!(2023-12-25), date!(2023-12-26)];
+/// let duration: Duration = "P1D".parse().unwrap();
+/// assert_eq!(
+///     duration.add_business_with_holidays(
+///         datetime!(2023-12-22 09:00:00 UTC),
+///         &BusinessCalendar::new(),
+///         &holidays,
+///     ),
+///     Ok(datetime!(2023-12-27 09:00:00 UTC))
+/// );
+/// ```
+pub trait HolidayProvider {
+    /// Return `true` if `date` is a holiday
+    fn is_holiday(&self, date: Date) -> bool;
+}
+
+impl HolidayProvider for [Date] {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.contains(&date)
+    }
+}
+
+impl HolidayProvider for Vec<Date> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.as_slice().is_holiday(date)
+    }
+}
+
+impl Duration {
+    /// Add the duration to `datetime`, treating `day` as business days.
+    ///
+    /// `year` and `month` are applied first with the usual month-end
+    /// clamping, then `day` skips the weekend of `calendar`, and the time
+    /// components are added last. A negative `day` walks back over
+    /// business days, while a fractional one is rejected with
+    /// [`BusinessDayError::FractionalDays`].
+    pub fn add_business(
+        &self,
+        datetime: OffsetDateTime,
+        calendar: &BusinessCalendar,
+    ) -> Result<OffsetDateTime, BusinessDayError> {
+        self.add_business_with_holidays(datetime, calendar, &[][..])
+    }
+
+    /// Add the duration to `datetime`, treating `day` as business days
+    /// and skipping both the weekend of `calendar` and `holidays`.
+    pub fn add_business_with_holidays<H: HolidayProvider + ?Sized>(
+        &self,
+        datetime: OffsetDateTime,
+        calendar: &BusinessCalendar,
+        holidays: &H,
+    ) -> Result<OffsetDateTime, BusinessDayError> {
+        let days = whole_days(self)?;
+        let datetime = add_year_month(datetime, self);
+        let date = calendar.add_business_days(datetime.date(), days, holidays);
+
+        Ok(add_time(datetime.replace_date(date), self))
+    }
+
+    /// Add the duration to `datetime`, consuming the time components
+    /// only within `hours`.
+    ///
+    /// `year` and `month` are applied as usual and `day` counts business
+    /// days of the working hours' calendar. The remaining `hour`,
+    /// `minute` and `second` are then spent inside the daily working
+    /// window, rolling over into the next business day when it closes.
+    /// Negative components go backward, and a fractional `day` is
+    /// rejected as in [`Duration::add_business`].
+    pub fn add_working_hours(
+        &self,
+        datetime: OffsetDateTime,
+        hours: &WorkingHours,
+    ) -> Result<OffsetDateTime, BusinessDayError> {
+        self.add_working_hours_with_holidays(datetime, hours, &[][..])
+    }
+
+    /// Same as [`Duration::add_working_hours`], also skipping `holidays`.
+    pub fn add_working_hours_with_holidays<H: HolidayProvider + ?Sized>(
+        &self,
+        datetime: OffsetDateTime,
+        hours: &WorkingHours,
+        holidays: &H,
+    ) -> Result<OffsetDateTime, BusinessDayError> {
+        let days = whole_days(self)?;
+        let datetime = add_year_month(datetime, self);
+        let date = hours
+            .calendar
+            .add_business_days(datetime.date(), days, holidays);
+
+        Ok(hours.consume(datetime.replace_date(date), time_duration(self), holidays))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime, time};
+
+    #[test]
+    fn add_business_days_skips_weekend() {
+        let calendar = BusinessCalendar::new();
+        // Friday
+        let start = datetime!(2023-03-17 10:00:00 UTC);
+        let duration: Duration = "P1D".parse().unwrap();
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Ok(datetime!(2023-03-20 10:00:00 UTC))
+        );
+
+        let duration: Duration = "P6DT2H".parse().unwrap();
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Ok(datetime!(2023-03-27 12:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_business_days_backward() {
+        let calendar = BusinessCalendar::new();
+        // Monday
+        let start = datetime!(2023-03-20 10:00:00 UTC);
+        let duration: Duration = "-P1D".parse().unwrap();
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Ok(datetime!(2023-03-17 10:00:00 UTC))
+        );
+
+        let start = datetime!(2023-03-27 12:00:00 UTC);
+        let duration: Duration = "-P6DT2H".parse().unwrap();
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Ok(datetime!(2023-03-17 10:00:00 UTC))
+        );
+
+        let holidays = vec![date!(2023 - 04 - 07), date!(2023 - 04 - 10)];
+        let start = datetime!(2023-04-12 10:00:00 UTC);
+        let duration: Duration = "-P2D".parse().unwrap();
+        assert_eq!(
+            duration.add_business_with_holidays(start, &calendar, &holidays),
+            Ok(datetime!(2023-04-06 10:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_business_days_rejects_fractions() {
+        let calendar = BusinessCalendar::new();
+        let start = datetime!(2023-03-20 10:00:00 UTC);
+        let duration: Duration = "P1.5D".parse().unwrap();
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Err(BusinessDayError::FractionalDays)
+        );
+
+        let duration = Duration::new(0., 0., f32::INFINITY, 0., 0., 0.);
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Err(BusinessDayError::NonFinite)
+        );
+
+        let hours = WorkingHours::new(time!(09:00), time!(17:00), calendar);
+        let duration: Duration = "P0.5DT1H".parse().unwrap();
+        assert_eq!(
+            duration.add_working_hours(start, &hours),
+            Err(BusinessDayError::FractionalDays)
+        );
+    }
+
+    #[test]
+    fn add_business_days_from_weekend() {
+        let calendar = BusinessCalendar::new();
+        // Saturday
+        let start = datetime!(2023-03-18 10:00:00 UTC);
+        let duration: Duration = "P1D".parse().unwrap();
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Ok(datetime!(2023-03-20 10:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_business_days_custom_weekend() {
+        let calendar = BusinessCalendar::with_weekend(&[Weekday::Friday, Weekday::Saturday]);
+        // Thursday
+        let start = datetime!(2023-03-16 10:00:00 UTC);
+        let duration: Duration = "P1M1D".parse().unwrap();
+        // 2023-04-16 is a Sunday, a business day in this calendar
+        assert_eq!(
+            duration.add_business(start, &calendar),
+            Ok(datetime!(2023-04-17 10:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_business_days_skips_holidays() {
+        let calendar = BusinessCalendar::new();
+        let holidays = vec![date!(2023 - 04 - 07), date!(2023 - 04 - 10)];
+        // Thursday before Easter
+        let start = datetime!(2023-04-06 10:00:00 UTC);
+        let duration: Duration = "P2D".parse().unwrap();
+        assert_eq!(
+            duration.add_business_with_holidays(start, &calendar, &holidays),
+            Ok(datetime!(2023-04-12 10:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_working_hours_rolls_over() {
+        let hours = WorkingHours::new(time!(09:00), time!(17:00), BusinessCalendar::new());
+        // Friday afternoon
+        let start = datetime!(2023-03-17 15:00:00 UTC);
+        let duration: Duration = "PT4H".parse().unwrap();
+        assert_eq!(
+            duration.add_working_hours(start, &hours),
+            Ok(datetime!(2023-03-20 11:00:00 UTC))
+        );
+
+        // Before opening
+        let start = datetime!(2023-03-20 07:30:00 UTC);
+        let duration: Duration = "PT8H30M".parse().unwrap();
+        assert_eq!(
+            duration.add_working_hours(start, &hours),
+            Ok(datetime!(2023-03-21 09:30:00 UTC))
+        );
+
+        // Monday morning, going back into Friday afternoon
+        let start = datetime!(2023-03-20 11:00:00 UTC);
+        let duration: Duration = "-PT4H".parse().unwrap();
+        assert_eq!(
+            duration.add_working_hours(start, &hours),
+            Ok(datetime!(2023-03-17 15:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_working_hours_with_holidays() {
+        let hours = WorkingHours::new(time!(09:00), time!(17:00), BusinessCalendar::new());
+        let holidays = vec![date!(2023 - 03 - 21)];
+        let start = datetime!(2023-03-20 16:00:00 UTC);
+        let duration: Duration = "P1DT2H".parse().unwrap();
+        assert_eq!(
+            duration.add_working_hours_with_holidays(start, &hours, &holidays),
+            Ok(datetime!(2023-03-23 10:00:00 UTC))
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use crate::{Duration, ParseDurationError};
+
+/// Number of inputs remembered per thread
+const SLOTS: usize = 64;
+
+/// Longer inputs aren't cached, so that the cache stays small
+const MAX_INPUT_LEN: usize = 32;
+
+/// A cached input along with its parsed duration
+type Slot = Option<(Box<str>, Duration)>;
+
+thread_local! {
+    static CACHE: RefCell<Vec<Slot>> = RefCell::new(vec![None; SLOTS]);
+}
+
+impl Duration {
+    /// Parse given string into Duration, remembering recently parsed inputs.
+    ///
+    /// Each thread keeps its own small table of inputs, indexed by their
+    /// hash, so there is no locking or sharing between threads, and an
+    /// input only evicts the one it collides with. This pays off when the
+    /// same few strings are parsed over and over, e.g. in log pipelines.
+    /// Errors and inputs longer than 32 bytes aren't cached.
+    ///
+    /// Requires the `cache` feature.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// for _ in 0..3 {
+    ///     assert_eq!(Duration::parse_cached("PT30S"), "PT30S".parse());
+    /// }
+    /// ```
+    pub fn parse_cached(input: &str) -> Result<Duration, ParseDurationError> {
+        if input.len() > MAX_INPUT_LEN {
+            return Duration::parse(input);
+        }
+
+        let mut hasher = DefaultHasher::new();
+        input.hash(&mut hasher);
+        let slot = hasher.finish() as usize % SLOTS;
+
+        CACHE.with(|cache| {
+            if let Some((cached, duration)) = &cache.borrow()[slot] {
+                if **cached == *input {
+                    return Ok(*duration);
+                }
+            }
+
+            let duration = Duration::parse(input)?;
+            cache.borrow_mut()[slot] = Some((input.into(), duration));
+            Ok(duration)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_parse() {
+        let inputs: Vec<String> = (0..200).map(|i| format!("PT{}S", i)).collect();
+
+        for _ in 0..2 {
+            for input in &inputs {
+                assert_eq!(Duration::parse_cached(input), Duration::parse(input));
+            }
+        }
+
+        assert!(Duration::parse_cached("P1X").is_err());
+        let long = format!("PT{}S", "1".repeat(40));
+        assert_eq!(Duration::parse_cached(&long), Duration::parse(&long));
+    }
+}
@@ -1,14 +1,32 @@
-use std::ops::Add;
+use std::{
+    convert::TryFrom,
+    ops::{Add, Sub},
+};
 
-use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveDate, TimeZone};
+use chrono::{
+    DateTime, Datelike, Days, Duration as ChronoDuration, Months, NaiveDate, TimeZone, Utc,
+};
 
-use crate::Duration;
+use crate::{
+    apply::{shift_months, whole_parts},
+    ApplyDuration, ApplyError, Duration, EomPolicy,
+};
 
+/// Spans too large to be represented saturate to `ChronoDuration::MIN` or
+/// `ChronoDuration::MAX`.
 fn seconds_to_chrono_duration(seconds: f32) -> ChronoDuration {
+    if seconds.is_nan() {
+        return ChronoDuration::zero();
+    }
+
     let nanoseconds = seconds.fract() * 1_000_000_000.;
     let seconds = seconds.trunc();
 
-    ChronoDuration::seconds(seconds as i64) + ChronoDuration::nanoseconds(nanoseconds as i64)
+    match ChronoDuration::try_seconds(seconds as i64) {
+        Some(duration) => duration + ChronoDuration::nanoseconds(nanoseconds as i64),
+        None if seconds > 0. => ChronoDuration::MAX,
+        None => ChronoDuration::MIN,
+    }
 }
 
 impl Duration {
@@ -19,7 +37,7 @@ impl Duration {
     pub fn to_chrono(&self) -> Option<ChronoDuration> {
         // we can't get the duration of year or month,
         // without knowing the start date.
-        if self.year > 0.0 || self.month > 0.0 {
+        if self.year != 0.0 || self.month != 0.0 {
             return None;
         }
 
@@ -29,63 +47,341 @@ impl Duration {
         Some(seconds_to_chrono_duration(seconds))
     }
 
+    /// Decompose an exact `chrono::TimeDelta` into days, hours, minutes
+    /// and seconds, the nanoseconds being added as a fraction of `second`.
+    ///
+    /// A day counts as 24 hours. A negative delta gives negative
+    /// components.
+    ///
+    /// ```rust
+    /// use chrono::TimeDelta;
+    /// use iso8601_duration::Duration;
+    ///
+    /// let delta = TimeDelta::hours(50) + TimeDelta::milliseconds(1500);
+    /// assert_eq!(Duration::from_time_delta(delta).to_string(), "P2DT2H1.5S");
+    /// ```
+    pub fn from_time_delta(delta: ChronoDuration) -> Duration {
+        let days = delta.num_days();
+        let hours = delta.num_hours() - days * 24;
+        let minutes = delta.num_minutes() - delta.num_hours() * 60;
+        let seconds = delta.num_seconds() - delta.num_minutes() * 60;
+        let nanos = delta.subsec_nanos();
+
+        Duration::new(
+            0.,
+            0.,
+            days as f32,
+            hours as f32,
+            minutes as f32,
+            seconds as f32 + nanos as f32 / 1e9,
+        )
+    }
+
+    /// Flatten `year` and `month` into whole `chrono::Months`, returning
+    /// what doesn't fit as a remainder.
+    ///
+    /// A year counts as 12 months and the total is truncated: the fraction
+    /// of a month is left in the remainder's `month`, and `day`, `hour`,
+    /// `minute` and `second` are left untouched. Nothing is lost as long as
+    /// the remainder is applied too, but adding the months alone silently
+    /// drops it.
+    ///
+    /// This method will return `None` if the number of months is negative,
+    /// not finite or doesn't fit in `u32`.
+    ///
+    /// ```rust
+    /// use chrono::Months;
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration: Duration = "P1Y1.5MT1H".parse().unwrap();
+    /// assert_eq!(
+    ///     duration.to_months_lossy(),
+    ///     Some((Months::new(13), "P0.5MT1H".parse().unwrap()))
+    /// );
+    /// ```
+    pub fn to_months_lossy(&self) -> Option<(Months, Duration)> {
+        let months = self.year as f64 * 12. + self.month as f64;
+        let whole = months.trunc();
+        if !(0. ..=u32::MAX as f64).contains(&whole) {
+            return None;
+        }
+
+        let remainder = Duration {
+            year: 0.,
+            month: (months - whole) as f32,
+            ..*self
+        };
+        Some((Months::new(whole as u32), remainder))
+    }
+
+    /// Flatten `day`, `hour`, `minute` and `second` into whole
+    /// `chrono::Days`, returning what doesn't fit as a remainder.
+    ///
+    /// A day counts as 24 hours and the total is truncated: the rest is
+    /// left in the remainder's `second`, and `year` and `month` are left
+    /// untouched since they don't have a fixed number of days. Nothing is
+    /// lost as long as the remainder is applied too, but adding the days
+    /// alone silently drops it, as well as any leap or DST shift.
+    ///
+    /// This method will return `None` if the number of days is negative,
+    /// not finite or doesn't fit in `u64`.
+    ///
+    /// ```rust
+    /// use chrono::Days;
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration: Duration = "P1M1DT36H".parse().unwrap();
+    /// assert_eq!(
+    ///     duration.to_days_lossy(),
+    ///     Some((Days::new(2), "P1MT43200S".parse().unwrap()))
+    /// );
+    /// ```
+    pub fn to_days_lossy(&self) -> Option<(Days, Duration)> {
+        let seconds = self.day as f64 * 86400.
+            + self.hour as f64 * 3600.
+            + self.minute as f64 * 60.
+            + self.second as f64;
+        let whole = (seconds / 86400.).trunc();
+        if !(0. ..u64::MAX as f64).contains(&whole) {
+            return None;
+        }
+
+        let remainder = Duration::new(
+            self.year,
+            self.month,
+            0.,
+            0.,
+            0.,
+            (seconds - whole * 86400.) as f32,
+        );
+        Some((Days::new(whole as u64), remainder))
+    }
+
+    /// Add the duration to `datetime` as seen in `tz`, keeping the local
+    /// time of day, e.g. "same time tomorrow" with `P1D`.
+    ///
+    /// This is [`ApplyDuration::apply`] in `tz` with [`EomPolicy::Clamp`],
+    /// so across a daylight saving time change `P1D` may be 23 or 25 hours
+    /// long. This method will return [`ApplyError::InvalidLocalTime`] if
+    /// the local time is skipped or repeated on the resulting day.
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use iso8601_duration::Duration;
+    ///
+    /// let cet = FixedOffset::east_opt(3600).unwrap();
+    /// let start = Utc.with_ymd_and_hms(2023, 3, 1, 8, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     Duration::days(1).add_preserving_local(&start, &cet),
+    ///     Ok(cet.with_ymd_and_hms(2023, 3, 2, 9, 0, 0).unwrap())
+    /// );
+    /// ```
+    pub fn add_preserving_local<Tz: TimeZone, Local: TimeZone>(
+        &self,
+        datetime: &DateTime<Tz>,
+        tz: &Local,
+    ) -> Result<DateTime<Local>, ApplyError> {
+        datetime.with_timezone(tz).apply(self, EomPolicy::Clamp)
+    }
+
     /// Convert `Duration` to `chrono::Duration` at given datetime.
     pub fn to_chrono_at_datetime<Tz: TimeZone>(&self, at: DateTime<Tz>) -> ChronoDuration {
         (at.clone() + *self) - at
     }
 }
 
+impl From<ChronoDuration> for Duration {
+    fn from(delta: ChronoDuration) -> Self {
+        Duration::from_time_delta(delta)
+    }
+}
+
+/// Return the span of `rhs`, counting `year` as a proportion of the length
+/// of `year`, and `month` as a proportion of the length of `month`.
+///
+/// This method will return `None` if the span overflows, or if `year` or
+/// `month` is at the edge of the supported range.
+fn proportional_span(year: i32, month: (i32, u32), rhs: &Duration) -> Option<ChronoDuration> {
+    let mut d = ChronoDuration::zero();
+
+    if rhs.year != 0.0 {
+        let seconds_in_this_year = NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+            .signed_duration_since(NaiveDate::from_ymd_opt(year, 1, 1)?)
+            .num_seconds();
+
+        d = d.checked_add(&seconds_to_chrono_duration(
+            rhs.year * seconds_in_this_year as f32,
+        ))?;
+    }
+
+    if rhs.month != 0.0 {
+        let (year, month) = month;
+        let seconds_in_this_month = NaiveDate::from_ymd_opt(
+            if month == 12 { year + 1 } else { year },
+            if month == 12 { 1 } else { month + 1 },
+            1,
+        )?
+        .signed_duration_since(NaiveDate::from_ymd_opt(year, month, 1)?)
+        .num_seconds();
+
+        d = d.checked_add(&seconds_to_chrono_duration(
+            rhs.month * seconds_in_this_month as f32,
+        ))?;
+    }
+
+    d.checked_add(&seconds_to_chrono_duration(
+        rhs.day * 60. * 60. * 24. + rhs.hour * 60. * 60. + rhs.minute * 60. + rhs.second,
+    ))
+}
+
+/// Return the whole number of months in the `year` and `month` components
+/// of `rhs`, a year being 12 months.
+fn whole_months(rhs: &Duration) -> i64 {
+    (rhs.year as i64)
+        .saturating_mul(12)
+        .saturating_add(rhs.month as i64)
+}
+
+/// Return `rhs` without the whole years and months of its `year` and
+/// `month` components.
+fn fractional_months(rhs: &Duration) -> Duration {
+    Duration {
+        year: rhs.year.fract(),
+        month: rhs.month.fract(),
+        ..*rhs
+    }
+}
+
+/// Move `date` by whole `months`, clamping the day to the end of the
+/// resulting month.
+fn shift_date(date: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let (year, month, day, _) = shift_months(
+        (date.year(), date.month(), date.day()),
+        months,
+        EomPolicy::Clamp,
+    )
+    .ok()?;
+
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Return the latest datetime if `forward`, the earliest one otherwise.
+fn saturate<Tz: TimeZone>(tz: &Tz, forward: bool) -> DateTime<Tz> {
+    if forward {
+        DateTime::<Utc>::MAX_UTC.with_timezone(tz)
+    } else {
+        DateTime::<Utc>::MIN_UTC.with_timezone(tz)
+    }
+}
+
+/// Whether `rhs` points forward in time, counting a year as 12 months and
+/// a month as 31 days, for spans too large to be computed exactly.
+fn is_forward(rhs: &Duration) -> bool {
+    let days = (rhs.year as f64 * 12. + rhs.month as f64) * 31. + rhs.day as f64;
+    let seconds = rhs.hour as f64 * 3600. + rhs.minute as f64 * 60. + rhs.second as f64;
+
+    days * 86400. + seconds > 0.
+}
+
+/// Add a duration, moving the local date by the whole years and months
+/// first, and clamping the day to the end of the month reached.
+///
+/// What's left of `year` and `month` is counted as a proportion of the
+/// year and of the month reached, so `2001-01-31 + P1M` is `2001-02-28`
+/// and `2001-02-01 + P0.5M` is `2001-02-15`.
+///
+/// Results past the supported range saturate to the latest or the earliest
+/// datetime, so `Duration::MAX` can be used as a "forever" sentinel.
 impl<Tz: TimeZone> Add<Duration> for DateTime<Tz> {
     type Output = DateTime<Tz>;
 
     fn add(self, rhs: Duration) -> Self {
-        let mut d = ChronoDuration::zero();
+        let date = self.date_naive();
+        let tz = self.timezone();
 
-        if rhs.year > 0.0 {
-            let year = self.date_naive().year();
+        shift_date(date, whole_months(&rhs))
+            .and_then(|shifted| {
+                let rest = fractional_months(&rhs);
+                let span =
+                    proportional_span(shifted.year(), (shifted.year(), shifted.month()), &rest)?;
+                shifted.signed_duration_since(date).checked_add(&span)
+            })
+            .and_then(|span| self.checked_add_signed(span))
+            .unwrap_or_else(|| saturate(&tz, is_forward(&rhs)))
+    }
+}
 
-            let seconds_in_this_year = NaiveDate::from_ymd_opt(year + 1, 1, 1)
-                .expect("Date out of range")
-                .signed_duration_since(
-                    NaiveDate::from_ymd_opt(year, 1, 1).expect("Date out of range"),
-                )
-                .num_seconds();
+/// Subtract a duration, undoing `+` in reverse: the days, the time and
+/// what's left of `year` and `month` come off first, then the local date
+/// walks back by the whole years and months, clamping the day to the end
+/// of the month reached.
+///
+/// What's left of `year` and `month` is counted as a proportion of the
+/// year and of the month that precede the current ones. So
+/// `2001-03-31 - P1M` is `2001-02-28`, and `2001-03-15 - P1M` is
+/// `2001-02-15`, which adding `P1M` back undoes. Like `+`, results past
+/// the supported range saturate.
+impl<Tz: TimeZone> Sub<Duration> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
 
-            d = d + seconds_to_chrono_duration(rhs.year * seconds_in_this_year as f32)
-        }
+    fn sub(self, rhs: Duration) -> Self {
+        let date = self.date_naive();
+        let tz = self.timezone();
+        let previous_month = match date.month() {
+            1 => (date.year() - 1, 12),
+            month => (date.year(), month - 1),
+        };
 
-        if rhs.month > 0.0 {
-            let year = self.date_naive().year();
-            let month = self.date_naive().month();
-
-            let seconds_in_this_month = NaiveDate::from_ymd_opt(
-                if month == 12 { year + 1 } else { year },
-                if month == 12 { 1 } else { month + 1 },
-                1,
-            )
-            .expect("Date out of range")
-            .signed_duration_since(
-                NaiveDate::from_ymd_opt(year, month, 1).expect("Date out of range"),
-            )
-            .num_seconds();
+        proportional_span(date.year() - 1, previous_month, &fractional_months(&rhs))
+            .and_then(|span| self.checked_sub_signed(span))
+            .and_then(|datetime| {
+                let date = datetime.date_naive();
+                let shifted = shift_date(date, whole_months(&rhs).saturating_neg())?;
+                datetime.checked_sub_signed(date.signed_duration_since(shifted))
+            })
+            .unwrap_or_else(|| saturate(&tz, !is_forward(&rhs)))
+    }
+}
 
-            d = d + seconds_to_chrono_duration(rhs.month * seconds_in_this_month as f32)
-        }
+/// Unlike `+`, which turns years and months into seconds proportionally
+/// to the current year and month, this moves the local date by whole
+/// calendar months.
+impl<Tz: TimeZone> ApplyDuration for DateTime<Tz> {
+    fn apply(&self, duration: &Duration, policy: EomPolicy) -> Result<Self, ApplyError> {
+        let (months, days, nanos) = whole_parts(duration)?;
 
-        d = d + seconds_to_chrono_duration(
-            rhs.day * 60. * 60. * 24. + rhs.hour * 60. * 60. + rhs.minute * 60. + rhs.second,
-        );
+        let local = self.naive_local();
+        let (year, month, day, extra) =
+            shift_months((local.year(), local.month(), local.day()), months, policy)?;
+
+        let date = ChronoDuration::try_days(days + extra as i64)
+            .and_then(|days| NaiveDate::from_ymd_opt(year, month, day)?.checked_add_signed(days))
+            .ok_or(ApplyError::OutOfRange)?;
+        let datetime = self
+            .timezone()
+            .from_local_datetime(&date.and_time(local.time()))
+            .single()
+            .ok_or(ApplyError::InvalidLocalTime)?;
+
+        let time = i64::try_from(nanos.div_euclid(1_000_000_000))
+            .ok()
+            .and_then(ChronoDuration::try_seconds)
+            .ok_or(ApplyError::OutOfRange)?
+            + ChronoDuration::nanoseconds(nanos.rem_euclid(1_000_000_000) as i64);
 
-        self + d
+        datetime
+            .checked_add_signed(time)
+            .ok_or(ApplyError::OutOfRange)
     }
 }
 
 #[cfg(all(test, feature = "chrono"))]
+#[test]
 fn test_chrono() {
     use chrono::Utc;
 
     fn ymd(y: i32, m: u32, d: u32) -> DateTime<Utc> {
-        DateTime::<Utc>::from_utc(
+        DateTime::<Utc>::from_naive_utc_and_offset(
             NaiveDate::from_ymd_opt(y, m, d)
                 .unwrap()
                 .and_hms_opt(0, 0, 0)
@@ -118,4 +414,118 @@ fn test_chrono() {
 
     let week: Duration = "P1W".parse().unwrap();
     assert_eq!(week.to_chrono(), Some(ChronoDuration::weeks(1)));
+
+    assert_eq!(Duration::MIN.to_chrono(), None);
+    let min_days = Duration {
+        year: 0.,
+        month: 0.,
+        ..Duration::MIN
+    };
+    assert_eq!(min_days.to_chrono(), Some(ChronoDuration::MIN));
+
+    // whole months move the calendar date
+    assert_eq!(
+        (ymd(2001, 3, 1) + "-P1MT12H".parse::<Duration>().unwrap()).to_rfc3339(),
+        "2001-01-31T12:00:00+00:00"
+    );
+
+    let month: Duration = "P1M".parse().unwrap();
+    assert_eq!(ymd(2001, 3, 15) - month, ymd(2001, 2, 15));
+    assert_eq!(ymd(2001, 1, 15) - month, ymd(2000, 12, 15));
+    assert_eq!((ymd(2001, 3, 15) - month) + month, ymd(2001, 3, 15));
+    assert_eq!((ymd(2001, 3, 15) + month) - month, ymd(2001, 3, 15));
+    assert_eq!(ymd(2023, 3, 15) - Duration::years(1), ymd(2022, 3, 15));
+    assert_eq!(ymd(2024, 3, 15) - Duration::years(1), ymd(2023, 3, 15));
+
+    // the day is clamped to the end of shorter months, both ways
+    assert_eq!(ymd(2023, 1, 31) + month, ymd(2023, 2, 28));
+    assert_eq!((ymd(2023, 1, 31) + month) - month, ymd(2023, 1, 28));
+    assert_eq!(ymd(2023, 3, 31) - month, ymd(2023, 2, 28));
+    assert_eq!(ymd(2024, 3, 31) - month, ymd(2024, 2, 29));
+    assert_eq!(ymd(2024, 2, 29) - Duration::years(1), ymd(2023, 2, 28));
+    assert_eq!(ymd(2024, 2, 29) + Duration::years(1), ymd(2025, 2, 28));
+    assert_eq!(
+        ymd(2023, 5, 31) - "P1M1D".parse::<Duration>().unwrap(),
+        ymd(2023, 4, 30)
+    );
+
+    let delta = ChronoDuration::days(3) + ChronoDuration::minutes(61);
+    assert_eq!(Duration::from(delta), Duration::new(0., 0., 3., 1., 1., 0.));
+    assert_eq!(
+        Duration::from(-delta - ChronoDuration::milliseconds(250)),
+        Duration::new(0., 0., -3., -1., -1., -0.25)
+    );
+    assert_eq!(Duration::from(delta).to_chrono(), Some(delta));
+
+    let duration: Duration = "P2.25YT90M".parse().unwrap();
+    let (months, remainder) = duration.to_months_lossy().unwrap();
+    assert_eq!(months, Months::new(27));
+    assert_eq!(remainder, "PT90M".parse().unwrap());
+    let (days, remainder) = remainder.to_days_lossy().unwrap();
+    assert_eq!(days, Days::new(0));
+    assert_eq!(remainder, "PT5400S".parse().unwrap());
+
+    assert_eq!(Duration::MIN.to_months_lossy(), None);
+    assert_eq!(Duration::MAX.to_days_lossy(), None);
+    assert_eq!(
+        Duration::new(0., 0., f32::NAN, 0., 0., 0.).to_days_lossy(),
+        None
+    );
+    assert_eq!(
+        ymd(2023, 1, 31).apply(&Duration::MAX, EomPolicy::Clamp),
+        Err(ApplyError::OutOfRange)
+    );
+
+    assert_eq!(ymd(2023, 1, 31) + Duration::MAX, DateTime::<Utc>::MAX_UTC);
+    assert_eq!(ymd(2023, 1, 31) - Duration::MIN, DateTime::<Utc>::MAX_UTC);
+    assert_eq!(ymd(2023, 1, 31) - Duration::MAX, DateTime::<Utc>::MIN_UTC);
+    assert_eq!(ymd(2023, 1, 31) + Duration::MIN, DateTime::<Utc>::MIN_UTC);
+    assert_eq!(
+        DateTime::<Utc>::MAX_UTC + Duration::years(1),
+        DateTime::<Utc>::MAX_UTC
+    );
+}
+
+#[cfg(all(test, feature = "chrono"))]
+#[test]
+fn test_chrono_apply() {
+    use chrono::{FixedOffset, Utc};
+
+    let start = Utc.with_ymd_and_hms(2023, 1, 31, 10, 0, 0).unwrap();
+    let duration: Duration = "P1MT1H".parse().unwrap();
+
+    assert_eq!(
+        start.apply(&duration, EomPolicy::Clamp),
+        Ok(Utc.with_ymd_and_hms(2023, 2, 28, 11, 0, 0).unwrap())
+    );
+    assert_eq!(
+        start.apply(&duration, EomPolicy::Overflow),
+        Ok(Utc.with_ymd_and_hms(2023, 3, 3, 11, 0, 0).unwrap())
+    );
+    assert_eq!(
+        start.apply(&duration, EomPolicy::Reject),
+        Err(ApplyError::DayOutOfMonth)
+    );
+    assert_eq!(
+        start.apply(&duration, EomPolicy::SameWeekday),
+        Ok(Utc.with_ymd_and_hms(2023, 2, 28, 11, 0, 0).unwrap())
+    );
+
+    // the local date moves, not the UTC one
+    let offset = FixedOffset::east_opt(-5 * 3600).unwrap();
+    let start = offset.with_ymd_and_hms(2023, 2, 28, 22, 0, 0).unwrap();
+    assert_eq!(
+        start.apply(&"P1M".parse().unwrap(), EomPolicy::Clamp),
+        Ok(offset.with_ymd_and_hms(2023, 3, 28, 22, 0, 0).unwrap())
+    );
+
+    let week: Duration = "P1W".parse().unwrap();
+    assert_eq!(
+        week.add_preserving_local(&Utc.with_ymd_and_hms(2023, 3, 1, 2, 0, 0).unwrap(), &offset),
+        Ok(offset.with_ymd_and_hms(2023, 3, 7, 21, 0, 0).unwrap())
+    );
+    assert_eq!(
+        Duration::MAX.add_preserving_local(&start, &Utc),
+        Err(ApplyError::OutOfRange)
+    );
 }
@@ -0,0 +1,273 @@
+//! ISO 8601 duration test vectors, and a check of which profile a parser
+//! conforms to.
+//!
+//! Requires the `conformance` feature. [`VECTORS`] is a table of inputs,
+//! each either valid under a [`Profile`] along with the duration it stands
+//! for, or invalid under every profile. [`check`] runs a parser through
+//! the table, so an application can verify at startup that the parser it
+//! configured accepts exactly what it expects:
+//!
+//! ```rust
+//! use iso8601_duration::conformance::{check, Profile};
+//! use iso8601_duration::Duration;
+//!
+//! let report = check(Duration::parse);
+//! for outcome in report.mismatches(Profile::Signed) {
+//!     eprintln!("{:?} gives {:?}", outcome.vector.input, outcome.result);
+//! }
+//! ```
+
+use crate::{Duration, ParseDurationError};
+
+/// A set of inputs a parser may accept, each profile including the
+/// previous ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Profile {
+    /// The format of ISO 8601-1: `PnYnMnDTnHnMnS` or `PnW`, with a
+    /// fraction written with a dot or a comma.
+    Basic,
+    /// The signs of ISO 8601-2, on the whole duration or on components,
+    /// as in `-P1D` and `P1DT-12H`.
+    Signed,
+    /// Weeks combined with other components, as in `P1W2D`, which ISO
+    /// 8601 doesn't allow.
+    Lenient,
+}
+
+/// An input along with whether it's valid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector {
+    pub input: &'static str,
+    /// The least profile the input is valid under, or `None` if it's
+    /// invalid under all of them
+    pub profile: Option<Profile>,
+    /// The duration a valid input stands for
+    pub expected: Option<Duration>,
+}
+
+const fn valid(input: &'static str, profile: Profile, expected: Duration) -> Vector {
+    Vector {
+        input,
+        profile: Some(profile),
+        expected: Some(expected),
+    }
+}
+
+const fn invalid(input: &'static str) -> Vector {
+    Vector {
+        input,
+        profile: None,
+        expected: None,
+    }
+}
+
+/// The test vectors.
+pub const VECTORS: &[Vector] = &[
+    valid("P1Y", Profile::Basic, Duration::new(1., 0., 0., 0., 0., 0.)),
+    valid("P1M", Profile::Basic, Duration::new(0., 1., 0., 0., 0., 0.)),
+    valid(
+        "PT1M",
+        Profile::Basic,
+        Duration::new(0., 0., 0., 0., 1., 0.),
+    ),
+    valid("P0D", Profile::Basic, Duration::new(0., 0., 0., 0., 0., 0.)),
+    valid(
+        "PT0S",
+        Profile::Basic,
+        Duration::new(0., 0., 0., 0., 0., 0.),
+    ),
+    valid(
+        "P3W",
+        Profile::Basic,
+        Duration::new(0., 0., 21., 0., 0., 0.),
+    ),
+    valid(
+        "PT36H",
+        Profile::Basic,
+        Duration::new(0., 0., 0., 36., 0., 0.),
+    ),
+    valid(
+        "P1DT12H",
+        Profile::Basic,
+        Duration::new(0., 0., 1., 12., 0., 0.),
+    ),
+    valid(
+        "P1Y2M3DT4H5M6S",
+        Profile::Basic,
+        Duration::new(1., 2., 3., 4., 5., 6.),
+    ),
+    valid(
+        "P0.5Y",
+        Profile::Basic,
+        Duration::new(0.5, 0., 0., 0., 0., 0.),
+    ),
+    valid(
+        "PT0.5S",
+        Profile::Basic,
+        Duration::new(0., 0., 0., 0., 0., 0.5),
+    ),
+    valid(
+        "PT0,5S",
+        Profile::Basic,
+        Duration::new(0., 0., 0., 0., 0., 0.5),
+    ),
+    valid(
+        "-P1D",
+        Profile::Signed,
+        Duration::new(0., 0., -1., 0., 0., 0.),
+    ),
+    valid(
+        "P-1D",
+        Profile::Signed,
+        Duration::new(0., 0., -1., 0., 0., 0.),
+    ),
+    valid(
+        "-P2W",
+        Profile::Signed,
+        Duration::new(0., 0., -14., 0., 0., 0.),
+    ),
+    valid(
+        "P1DT-12H",
+        Profile::Signed,
+        Duration::new(0., 0., 1., -12., 0., 0.),
+    ),
+    valid(
+        "P1W2D",
+        Profile::Lenient,
+        Duration::new(0., 0., 9., 0., 0., 0.),
+    ),
+    valid(
+        "P1M2W",
+        Profile::Lenient,
+        Duration::new(0., 1., 14., 0., 0., 0.),
+    ),
+    invalid(""),
+    invalid("P"),
+    invalid("PT"),
+    invalid("1D"),
+    invalid("p1d"),
+    invalid("P1S"),
+    invalid("PT1D"),
+    invalid("P1DT"),
+    invalid("P1D2Y"),
+    invalid("P1Y1Y"),
+    invalid("P1.2.3D"),
+    invalid("--P1D"),
+];
+
+/// What a parser gave for a vector.
+#[derive(Debug, PartialEq)]
+pub struct Outcome {
+    pub vector: &'static Vector,
+    pub result: Result<Duration, ParseDurationError>,
+}
+
+impl Outcome {
+    /// Return whether the parser behaved as `profile` requires: valid
+    /// inputs of this profile or of a lesser one give the expected
+    /// duration, other inputs are rejected.
+    pub fn conforms_to(&self, profile: Profile) -> bool {
+        match (self.vector.profile, &self.result) {
+            (Some(least), Ok(duration)) if least <= profile => {
+                self.vector.expected.as_ref() == Some(duration)
+            }
+            (Some(least), Err(_)) => least > profile,
+            (None, result) => result.is_err(),
+            (Some(_), Ok(_)) => false,
+        }
+    }
+}
+
+/// The outcomes of a parser for every vector.
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Report {
+    /// Return whether every valid input of `profile` gives the expected
+    /// duration and every invalid input is rejected.
+    ///
+    /// Inputs of greater profiles may be accepted.
+    pub fn accepts(&self, profile: Profile) -> bool {
+        self.outcomes.iter().all(|outcome| {
+            outcome.conforms_to(profile)
+                || outcome.vector.profile.is_some_and(|least| least > profile)
+        })
+    }
+
+    /// Return the outcomes that don't conform to `profile`
+    pub fn mismatches(&self, profile: Profile) -> Vec<&Outcome> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| !outcome.conforms_to(profile))
+            .collect()
+    }
+
+    /// Return the profile the parser strictly conforms to, accepting its
+    /// inputs and nothing else, if any
+    pub fn profile(&self) -> Option<Profile> {
+        [Profile::Basic, Profile::Signed, Profile::Lenient]
+            .iter()
+            .copied()
+            .find(|profile| self.mismatches(*profile).is_empty())
+    }
+}
+
+/// Run `parser` on every vector.
+pub fn check<F>(parser: F) -> Report
+where
+    F: Fn(&str) -> Result<Duration, ParseDurationError>,
+{
+    Report {
+        outcomes: VECTORS
+            .iter()
+            .map(|vector| Outcome {
+                vector,
+                result: parser(vector.input),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mismatched_inputs(report: &Report, profile: Profile) -> Vec<&'static str> {
+        report
+            .mismatches(profile)
+            .iter()
+            .map(|outcome| outcome.vector.input)
+            .collect()
+    }
+
+    #[test]
+    fn builtin_parsers() {
+        // the parsers accept a trailing `T`
+        let report = check(Duration::parse);
+        assert_eq!(mismatched_inputs(&report, Profile::Signed), ["P1DT"]);
+        assert!(!report.accepts(Profile::Basic));
+        assert_eq!(report.profile(), None);
+
+        let report = check(Duration::parse_lenient);
+        assert_eq!(mismatched_inputs(&report, Profile::Lenient), ["P1DT"]);
+    }
+
+    #[test]
+    fn strict_profile() {
+        let strict = |input: &str| match VECTORS.iter().find(|vector| vector.input == input) {
+            Some(Vector {
+                profile: Some(Profile::Basic),
+                expected: Some(duration),
+                ..
+            }) => Ok(*duration),
+            _ => Duration::parse("P"),
+        };
+
+        let report = check(strict);
+        assert_eq!(report.profile(), Some(Profile::Basic));
+        assert!(report.accepts(Profile::Basic));
+        assert!(!report.accepts(Profile::Signed));
+    }
+}
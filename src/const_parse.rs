@@ -0,0 +1,213 @@
+use crate::{Duration, NoAllocParseError};
+
+/// Create a [`Duration`] from an ISO8601 string at compile time.
+///
+/// The string is parsed with [`Duration::parse_const`] in a constant, so
+/// an invalid one fails the build instead of panicking at runtime.
+///
+/// ```rust
+/// use iso8601_duration::{duration, Duration};
+///
+/// const TTL: Duration = duration!("PT15M");
+/// assert_eq!(TTL, Duration::minutes(15));
+/// ```
+///
+/// ```rust,compile_fail
+/// use iso8601_duration::{duration, Duration};
+///
+/// const TTL: Duration = duration!("PT15X");
+/// ```
+#[macro_export]
+macro_rules! duration {
+    ($input:expr) => {{
+        const DURATION: $crate::Duration = match $crate::Duration::parse_const($input) {
+            ::core::result::Result::Ok(duration) => duration,
+            ::core::result::Result::Err(_) => ::core::panic!("invalid ISO8601 duration"),
+        };
+        DURATION
+    }};
+}
+
+const fn error(position: usize) -> Result<Duration, NoAllocParseError> {
+    Err(NoAllocParseError { position })
+}
+
+impl Duration {
+    /// Parse given string into Duration in a `const` context, see the
+    /// [`duration!`] macro.
+    ///
+    /// It accepts the same durations as [`Duration::parse_no_alloc`], and
+    /// fails at the same positions. Numbers are converted with `f64`
+    /// arithmetic rather than the standard library parser, so values with
+    /// more than 15 significant digits may be rounded differently.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// const RETENTION: Duration = match Duration::parse_const("P1Y6M") {
+    ///     Ok(duration) => duration,
+    ///     Err(_) => panic!("invalid retention"),
+    /// };
+    /// assert_eq!(RETENTION, Duration::new(1., 6., 0., 0., 0., 0.));
+    /// ```
+    pub const fn parse_const(input: &str) -> Result<Duration, NoAllocParseError> {
+        let bytes = input.as_bytes();
+        let len = bytes.len();
+
+        let negative = len > 0 && bytes[0] == b'-';
+        let mut position = negative as usize;
+        if position >= len || bytes[position] != b'P' {
+            return error(position);
+        }
+        position += 1;
+
+        // year, month, day, hour, minute and second
+        let mut values = [0f32; 6];
+        let mut in_time = false;
+        // index of the next designator allowed in the current part
+        let mut next = 0;
+        let mut components = 0;
+        let mut week = false;
+
+        while position < len {
+            if bytes[position] == b'T' && !in_time {
+                in_time = true;
+                next = 0;
+                position += 1;
+                if position == len {
+                    return error(position);
+                }
+                continue;
+            }
+
+            let start = position;
+            let mut sign = 1f64;
+            if bytes[position] == b'-' || bytes[position] == b'+' {
+                if bytes[position] == b'-' {
+                    sign = -1.;
+                }
+                position += 1;
+            }
+
+            let integer_start = position;
+            let mut integer = 0f64;
+            while position < len && bytes[position].is_ascii_digit() {
+                integer = integer * 10. + (bytes[position] - b'0') as f64;
+                position += 1;
+            }
+            if position == integer_start {
+                return error(start);
+            }
+
+            let mut fraction = 0f64;
+            let mut scale = 1f64;
+            if position + 1 < len
+                && (bytes[position] == b'.' || bytes[position] == b',')
+                && bytes[position + 1].is_ascii_digit()
+            {
+                position += 1;
+                while position < len && bytes[position].is_ascii_digit() {
+                    fraction = fraction * 10. + (bytes[position] - b'0') as f64;
+                    scale *= 10.;
+                    position += 1;
+                }
+            }
+            if position == len {
+                return error(position);
+            }
+            let value = (sign * (integer + fraction / scale)) as f32;
+
+            let designators: &[u8] = if in_time { b"HMS" } else { b"YMWD" };
+            let mut index = next;
+            while index < designators.len() && designators[index] != bytes[position] {
+                index += 1;
+            }
+            if index == designators.len() {
+                return error(start);
+            }
+            match (in_time, designators[index]) {
+                (false, b'Y') => values[0] = value,
+                (false, b'M') => values[1] = value,
+                (false, b'W') => {
+                    week = true;
+                    values[2] = value * 7.;
+                }
+                (false, _) => values[2] = value,
+                (true, b'H') => values[3] = value,
+                (true, b'M') => values[4] = value,
+                (true, _) => values[5] = value,
+            }
+
+            // weeks can't be combined with other components
+            if week && components > 0 {
+                return error(start);
+            }
+            next = if week { designators.len() } else { index + 1 };
+            components += 1;
+            position += 1;
+        }
+
+        if components == 0 {
+            return error(len);
+        }
+
+        let sign = if negative { -1. } else { 1. };
+        Ok(Duration::new(
+            sign * values[0],
+            sign * values[1],
+            sign * values[2],
+            sign * values[3],
+            sign * values[4],
+            sign * values[5],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_as_parse_no_alloc() {
+        for input in [
+            "P1Y2M3DT4H5M6S",
+            "PT0.5S",
+            "P1,5D",
+            "PT0.1S",
+            "P3.14159Y",
+            "-P1DT12H",
+            "P1DT-12H",
+            "P-1M1D",
+            "P+1D",
+            "P12W",
+            "-P0.5W",
+            "P1Y1W",
+            "P1D2Y",
+            "P1Y1Y",
+            "P",
+            "PT",
+            "P1DT",
+            "1D",
+            "-1D",
+            "P1",
+            "P1X",
+            "P1.D",
+            "P1.5.5D",
+            "P1D ",
+            "",
+        ] {
+            assert_eq!(
+                Duration::parse_const(input),
+                Duration::parse_no_alloc(input),
+                "{}",
+                input
+            );
+        }
+
+        // negating zero components keeps them equal to zero
+        assert_eq!(
+            crate::duration!("-P1D"),
+            Duration::new(0., 0., -1., 0., 0., 0.)
+        );
+    }
+}
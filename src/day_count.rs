@@ -0,0 +1,104 @@
+use time::OffsetDateTime;
+
+use crate::Duration;
+
+/// Day-count conventions for turning a span of days into a fraction of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayCount {
+    /// Actual days elapsed over a 365 days year, also known as ACT/365 Fixed.
+    Act365,
+    /// Actual days elapsed over a 360 days year.
+    Act360,
+    /// Every month counts as 30 days and a year as 360 days, with the
+    /// U.S. bond basis rules for the 31st.
+    Thirty360,
+}
+
+impl DayCount {
+    /// Return the fraction of a year between `start` and `end`
+    pub fn year_fraction(self, start: OffsetDateTime, end: OffsetDateTime) -> f64 {
+        match self {
+            DayCount::Act365 => actual_days(start, end) / 365.,
+            DayCount::Act360 => actual_days(start, end) / 360.,
+            DayCount::Thirty360 => {
+                let (y1, m1, mut d1) = start.date().to_calendar_date();
+                let (y2, m2, mut d2) = end.date().to_calendar_date();
+
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 == 30 {
+                    d2 = 30;
+                }
+
+                let days =
+                    360 * (y2 - y1) as i64 + 30 * (m2 as i64 - m1 as i64) + (d2 as i64 - d1 as i64);
+                let time = (end.time() - start.time()).as_seconds_f64() / 86400.;
+
+                (days as f64 + time) / 360.
+            }
+        }
+    }
+}
+
+fn actual_days(start: OffsetDateTime, end: OffsetDateTime) -> f64 {
+    (end - start).as_seconds_f64() / 86400.
+}
+
+impl Duration {
+    /// Return the fraction of a year the duration covers when applied at
+    /// `anchor`, according to the `convention`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{DayCount, Duration};
+    /// use time::macros::datetime;
+    ///
+    /// let duration: Duration = "P6M".parse().unwrap();
+    /// let anchor = datetime!(2023-01-31 00:00:00 UTC);
+    /// assert_eq!(duration.year_fraction_at(anchor, DayCount::Act360), 181. / 360.);
+    /// assert_eq!(duration.year_fraction_at(anchor, DayCount::Thirty360), 0.5);
+    /// ```
+    pub fn year_fraction_at(&self, anchor: OffsetDateTime, convention: DayCount) -> f64 {
+        convention.year_fraction(anchor, anchor + *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn actual_conventions() {
+        let year: Duration = "P1Y".parse().unwrap();
+        let anchor = datetime!(2024-01-01 00:00:00 UTC);
+
+        assert_eq!(year.year_fraction_at(anchor, DayCount::Act365), 366. / 365.);
+        assert_eq!(year.year_fraction_at(anchor, DayCount::Act360), 366. / 360.);
+
+        let half_day: Duration = "PT12H".parse().unwrap();
+        assert_eq!(
+            half_day.year_fraction_at(anchor, DayCount::Act365),
+            0.5 / 365.
+        );
+    }
+
+    #[test]
+    fn thirty_360_convention() {
+        let anchor = datetime!(2023-01-31 00:00:00 UTC);
+
+        let month: Duration = "P1M".parse().unwrap();
+        // 2023-01-31 to 2023-02-28
+        assert_eq!(
+            month.year_fraction_at(anchor, DayCount::Thirty360),
+            28. / 360.
+        );
+
+        let days: Duration = "P59D".parse().unwrap();
+        // 2023-01-31 to 2023-03-31
+        assert_eq!(
+            days.year_fraction_at(anchor, DayCount::Thirty360),
+            60. / 360.
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use time::OffsetDateTime;
+
+use crate::Duration;
+
+/// The datetime a timeout expires at, see [`Duration::deadline_from`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deadline {
+    /// The datetime the timeout started at
+    pub start: OffsetDateTime,
+    /// The datetime the timeout expires at
+    pub at: OffsetDateTime,
+}
+
+impl Deadline {
+    /// Return `true` if `current` is at or past the deadline
+    pub fn has_elapsed(&self, current: OffsetDateTime) -> bool {
+        current >= self.at
+    }
+
+    /// Return the time left from `current` until the deadline, zero once
+    /// it has elapsed
+    pub fn remaining(&self, current: OffsetDateTime) -> time::Duration {
+        if self.has_elapsed(current) {
+            time::Duration::ZERO
+        } else {
+            self.at - current
+        }
+    }
+}
+
+impl Duration {
+    /// Return the deadline this duration after `now`
+    ///
+    /// Years and months are applied in calendar terms, so a `P1M` timeout
+    /// started on January 31 expires on February 28 or 29.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let timeout: Duration = "P1DT12H".parse().unwrap();
+    /// let deadline = timeout.deadline_from(datetime!(2023-03-01 00:00 UTC));
+    /// assert_eq!(deadline.at, datetime!(2023-03-02 12:00 UTC));
+    ///
+    /// let current = datetime!(2023-03-02 06:00 UTC);
+    /// assert!(!deadline.has_elapsed(current));
+    /// assert_eq!(deadline.remaining(current), time::Duration::hours(6));
+    ///
+    /// let current = datetime!(2023-03-03 00:00 UTC);
+    /// assert!(deadline.has_elapsed(current));
+    /// assert_eq!(deadline.remaining(current), time::Duration::ZERO);
+    /// ```
+    pub fn deadline_from(&self, now: OffsetDateTime) -> Deadline {
+        Deadline {
+            start: now,
+            at: now + *self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn deadline() {
+        let deadline = Duration::months(1).deadline_from(datetime!(2024-01-31 10:00 UTC));
+        assert_eq!(deadline.at, datetime!(2024-02-29 10:00 UTC));
+        assert!(deadline.has_elapsed(deadline.at));
+        assert_eq!(
+            deadline.remaining(datetime!(2024-02-28 10:00 UTC)),
+            time::Duration::days(1)
+        );
+
+        let deadline = Duration::ZERO.deadline_from(datetime!(2024-01-31 10:00 UTC));
+        assert!(deadline.has_elapsed(deadline.start));
+    }
+}
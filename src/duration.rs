@@ -1,17 +1,23 @@
-use std::time::Duration as StdDuration;
-use std::{fmt, str::FromStr};
+use crate::Unit;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::str::FromStr;
 
+#[cfg(feature = "std")]
 use nom::{
     branch::alt,
-    bytes::complete::tag,
+    bytes::complete::{tag, take_while_m_n},
     character::complete::digit1,
-    combinator::{all_consuming, map_res, opt},
-    error::{ErrorKind, ParseError},
+    combinator::{eof, map, map_res, opt, recognize, verify},
+    error::{ErrorKind, FromExternalError, ParseError},
     number::complete::float,
-    sequence::{preceded, separated_pair, terminated, tuple},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
     Err, Finish, IResult,
 };
 
+#[cfg(feature = "std")]
+use crate::ErrorReason;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Duration {
     pub year: f32,
@@ -23,6 +29,22 @@ pub struct Duration {
 }
 
 impl Duration {
+    /// What `Display` writes for a duration with a NaN or infinite
+    /// component, which has no ISO8601 representation. Use
+    /// [`Duration::try_to_string`] to get an error instead.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration = Duration::new(0., 0., 1., f32::NAN, 0., 0.);
+    /// assert_eq!(duration.to_string(), Duration::NON_FINITE_PLACEHOLDER);
+    /// assert!(Duration::parse(Duration::NON_FINITE_PLACEHOLDER).is_err());
+    /// # }
+    /// ```
+    pub const NON_FINITE_PLACEHOLDER: &'static str = "P<non-finite>";
+
     /// Create a new duration
     pub const fn new(year: f32, month: f32, day: f32, hour: f32, minute: f32, second: f32) -> Self {
         Duration {
@@ -35,12 +57,83 @@ impl Duration {
         }
     }
 
+    /// Create a duration of `years` years
+    ///
+    /// Single-unit constructors keep the value in the given unit, so
+    /// `Duration::minutes(90)` is `PT90M` rather than `PT1H30M`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert_eq!(Duration::years(2).to_string(), "P2Y");
+    /// assert_eq!(Duration::weeks(2).to_string(), "P14D");
+    /// assert_eq!(Duration::minutes(90).to_string(), "PT90M");
+    /// ```
+    pub const fn years(years: u32) -> Self {
+        Duration::new(years as f32, 0., 0., 0., 0., 0.)
+    }
+
+    /// Create a duration of `months` months
+    pub const fn months(months: u32) -> Self {
+        Duration::new(0., months as f32, 0., 0., 0., 0.)
+    }
+
+    /// Create a duration of `weeks` weeks, stored as days
+    pub const fn weeks(weeks: u32) -> Self {
+        Duration::new(0., 0., weeks as f32 * 7., 0., 0., 0.)
+    }
+
+    /// Create a duration of `days` days
+    pub const fn days(days: u32) -> Self {
+        Duration::new(0., 0., days as f32, 0., 0., 0.)
+    }
+
+    /// Create a duration of `hours` hours
+    pub const fn hours(hours: u32) -> Self {
+        Duration::new(0., 0., 0., hours as f32, 0., 0.)
+    }
+
+    /// Create a duration of `minutes` minutes
+    pub const fn minutes(minutes: u32) -> Self {
+        Duration::new(0., 0., 0., 0., minutes as f32, 0.)
+    }
+
+    /// Create a duration of `seconds` seconds
+    pub const fn seconds(seconds: u32) -> Self {
+        Duration::new(0., 0., 0., 0., 0., seconds as f32)
+    }
+
+    /// Return each component along with its unit
+    pub(crate) fn components(&self) -> [(Unit, f32); 6] {
+        [
+            (Unit::Year, self.year),
+            (Unit::Month, self.month),
+            (Unit::Day, self.day),
+            (Unit::Hour, self.hour),
+            (Unit::Minute, self.minute),
+            (Unit::Second, self.second),
+        ]
+    }
+
+    /// Return a mutable reference to each component along with its unit
+    #[cfg(feature = "std")]
+    pub(crate) fn components_mut(&mut self) -> [(Unit, &mut f32); 6] {
+        [
+            (Unit::Year, &mut self.year),
+            (Unit::Month, &mut self.month),
+            (Unit::Day, &mut self.day),
+            (Unit::Hour, &mut self.hour),
+            (Unit::Minute, &mut self.minute),
+            (Unit::Second, &mut self.second),
+        ]
+    }
+
     /// Return number of years in the duration
     ///
     /// This method will return `None` is `Duration` contains
     /// `second`, `minute` or `hour`.
     pub fn num_years(&self) -> Option<f32> {
-        if self.second > 0.0 || self.minute > 0.0 || self.hour > 0.0 {
+        if self.second != 0.0 || self.minute != 0.0 || self.hour != 0.0 {
             return None;
         }
 
@@ -52,7 +145,7 @@ impl Duration {
     /// This method will return `None` is `Duration` contains
     /// `second`, `minute` or `hour`.
     pub fn num_months(&self) -> Option<f32> {
-        if self.second > 0.0 || self.minute > 0.0 || self.hour > 0.0 {
+        if self.second != 0.0 || self.minute != 0.0 || self.hour != 0.0 {
             return None;
         }
 
@@ -64,7 +157,7 @@ impl Duration {
     /// This method will return `None` is `Duration` contains
     /// `year` or `month`.
     pub fn num_weeks(&self) -> Option<f32> {
-        if self.month > 0.0 || self.year > 0.0 {
+        if self.month != 0.0 || self.year != 0.0 {
             return None;
         }
 
@@ -81,7 +174,7 @@ impl Duration {
     /// This method will return `None` is `Duration` contains
     /// `year` or `month`.
     pub fn num_days(&self) -> Option<f32> {
-        if self.month > 0.0 || self.year > 0.0 {
+        if self.month != 0.0 || self.year != 0.0 {
             return None;
         }
 
@@ -93,7 +186,7 @@ impl Duration {
     /// This method will return `None` is `Duration` contains
     /// `year` or `month`.
     pub fn num_hours(&self) -> Option<f32> {
-        if self.month > 0.0 || self.year > 0.0 {
+        if self.month != 0.0 || self.year != 0.0 {
             return None;
         }
 
@@ -105,7 +198,7 @@ impl Duration {
     /// This method will return `None` is `Duration` contains
     /// `year` or `month`.
     pub fn num_minutes(&self) -> Option<f32> {
-        if self.month > 0.0 || self.year > 0.0 {
+        if self.month != 0.0 || self.year != 0.0 {
             return None;
         }
 
@@ -117,60 +210,495 @@ impl Duration {
     /// This method will return `None` is `Duration` contains
     /// `year` or `month`.
     pub fn num_seconds(&self) -> Option<f32> {
-        if self.month > 0.0 || self.year > 0.0 {
+        if self.month != 0.0 || self.year != 0.0 {
             return None;
         }
 
         Some(self.second + self.minute * 60. + self.hour * 60. * 60. + self.day * 60. * 60. * 24.)
     }
 
-    /// Convert duration to `std::time::Duration`.
+    /// Format the duration as an ISO8601 string.
     ///
-    /// This method will return `None` is `Duration` contains
-    /// `year` or `month`.
+    /// Components are written as they are, without carrying e.g. 90
+    /// seconds over to minutes, zero components are omitted and an empty
+    /// duration is `PT0S`. The result parses back to the same duration,
+    /// unless a component is NaN or infinite, see
+    /// [`Duration::try_to_string`].
     ///
-    /// See to know how to convert a `Duration` contains
-    /// `year` or `month`.
-    pub fn to_std(&self) -> Option<StdDuration> {
-        self.num_seconds().map(StdDuration::from_secs_f32)
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration = Duration::new(1., 2., 3., 4., 5., 6.5);
+    /// assert_eq!(duration.to_iso8601(), "P1Y2M3DT4H5M6.5S");
+    /// assert_eq!(Duration::ZERO.to_iso8601(), "PT0S");
+    /// assert_eq!(Duration::parse(&duration.to_iso8601()), Ok(duration));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_iso8601(&self) -> String {
+        self.to_string()
+    }
+
+    /// Write the duration as an ISO8601 string into `w`, as `Display`
+    /// does.
+    ///
+    /// A duration with a NaN or infinite component is written as
+    /// [`Duration::NON_FINITE_PLACEHOLDER`].
+    ///
+    /// Nothing is allocated, so in hot paths the same buffer can be
+    /// reused for every duration.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let mut line = String::with_capacity(64);
+    /// Duration::new(0., 0., 1., 12., 0., 0.5).write_iso(&mut line).unwrap();
+    /// assert_eq!(line, "P1DT12H0.5S");
+    /// ```
+    pub fn write_iso<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        if self
+            .components()
+            .iter()
+            .any(|(_, value)| !value.is_finite())
+        {
+            return w.write_str(Duration::NON_FINITE_PLACEHOLDER);
+        }
+
+        let present = |value: f32| value != 0.0;
+
+        if !self.components().iter().any(|(_, value)| present(*value)) {
+            return w.write_str("PT0S");
+        }
+
+        // write `-P1D` rather than `P-1D` when every component is negative
+        let negative = !self.components().iter().any(|(_, value)| *value > 0.0);
+        let duration = if negative { -*self } else { *self };
+
+        w.write_str(if negative { "-P" } else { "P" })?;
+        if present(duration.year) {
+            write_component(w, duration.year, 'Y')?;
+        }
+        if present(duration.month) {
+            write_component(w, duration.month, 'M')?;
+        }
+        if present(duration.day) {
+            write_component(w, duration.day, 'D')?;
+        }
+        if present(duration.hour) || present(duration.minute) || present(duration.second) {
+            w.write_str("T")?;
+        }
+        if present(duration.hour) {
+            write_component(w, duration.hour, 'H')?;
+        }
+        if present(duration.minute) {
+            write_component(w, duration.minute, 'M')?;
+        }
+        if present(duration.second) {
+            write_component(w, duration.second, 'S')?;
+        }
+        Ok(())
     }
 
     /// Parse given string into Duration
+    ///
+    /// Components may be signed, as in `PT-30M`, and a leading `-`
+    /// negates every component, as in `-P1DT12H`, following ISO 8601-2.
+    ///
+    /// The alternative format, `PYYYY-MM-DDThh:mm:ss` or
+    /// `PYYYYMMDDThhmmss`, is accepted as well.
+    ///
+    /// Numbers may have an exponent, as in `PT1e3S`, as long as they fit in
+    /// an `f32`. `NaN` and `inf` are rejected.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert_eq!(Duration::parse("-P1DT12H"), Ok(Duration::new(0., 0., -1., -12., 0., 0.)));
+    /// assert_eq!(Duration::parse("P1DT-12H"), Ok(Duration::new(0., 0., 1., -12., 0., 0.)));
+    /// assert_eq!(Duration::parse("P0003-06-04T12:30:05"), Duration::parse("P3Y6M4DT12H30M5S"));
+    /// assert_eq!(Duration::parse("P00030604T123005"), Duration::parse("P3Y6M4DT12H30M5S"));
+    /// ```
+    #[cfg(feature = "std")]
     pub fn parse(input: &str) -> Result<Duration, ParseDurationError> {
-        all_consuming(preceded(tag("P"), alt((parse_week_format, parse_basic_format))))(input)
+        complete(signed(alt((
+            parse_week_format,
+            parse_alternative_format,
+            parse_basic_format,
+        ))))(input)
+        .finish()
+        .map(|(_, duration)| duration)
+        .map_err(|err| ParseDurationError::new(input, err))
+    }
+
+    /// Parse given string into Duration, following the ISO8601 rules on
+    /// fractions.
+    ///
+    /// Only the last component may have a fraction, so `PT1.5H` is valid
+    /// but `P1.5Y2M` isn't, and all fractions must use the same decimal
+    /// separator, either `.` or `,`. [`Duration::parse`] accepts both, as
+    /// well as exponents, which are rejected here.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, ErrorReason};
+    ///
+    /// assert!(Duration::parse_strict("P1Y2M3DT4H5M6,5S").is_ok());
+    ///
+    /// let error = Duration::parse_strict("P1.5Y2M").unwrap_err();
+    /// assert_eq!(error.reason(), ErrorReason::FractionNotLast);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "only the last component can have a fraction, found one at position 1"
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_strict(input: &str) -> Result<Duration, StrictParseError> {
+        let duration = Duration::parse(input)?;
+
+        // start of each number, with its decimal separator
+        let mut numbers: Vec<(usize, Option<char>)> = Vec::new();
+        let mut in_number = false;
+        for (i, c) in input.char_indices() {
+            match c {
+                '0'..='9' | '.' | ',' if !in_number => {
+                    numbers.push((i, None));
+                    in_number = true;
+                }
+                '0'..='9' | '.' | ',' => {}
+                // `parse` reads exponents such as `1e3`, which ISO8601 doesn't have
+                'e' | 'E' if in_number => {
+                    let start = numbers.last().map_or(0, |(start, _)| *start);
+                    return Err(StrictParseError::Parse(ParseDurationError::new(
+                        input,
+                        SyntaxError::new(&input[start..], ErrorReason::InvalidComponent),
+                    )));
+                }
+                _ => in_number = false,
+            }
+            if c == '.' || c == ',' {
+                if let Some((_, separator)) = numbers.last_mut() {
+                    *separator = Some(c);
+                }
+            }
+        }
+
+        let mut separators = numbers
+            .iter()
+            .filter_map(|(start, separator)| Some((*start, (*separator)?)));
+        if let Some((_, first)) = separators.next() {
+            if let Some((start, _)) = separators.find(|(_, separator)| *separator != first) {
+                return Err(StrictParseError::MixedDecimalSeparators {
+                    input: input.to_string(),
+                    position: start,
+                });
+            }
+        }
+
+        let last = numbers.len().saturating_sub(1);
+        match numbers
+            .iter()
+            .take(last)
+            .find(|(_, separator)| separator.is_some())
+        {
+            Some((start, _)) => Err(StrictParseError::FractionNotLast {
+                input: input.to_string(),
+                position: *start,
+            }),
+            None => Ok(duration),
+        }
+    }
+
+    /// Parse given string into Duration, also accepting a quarter (`Q`)
+    /// component between `year` and `month`.
+    ///
+    /// Quarters are not part of ISO8601, each one is stored as three
+    /// months, so the duration formats back to months.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration = Duration::parse_with_quarters("P1Y2Q").unwrap();
+    /// assert_eq!(duration, Duration::new(1., 6., 0., 0., 0., 0.));
+    /// assert_eq!(duration.to_string(), "P1Y6M");
+    /// ```
+    #[cfg(feature = "extensions")]
+    pub fn parse_with_quarters(input: &str) -> Result<Duration, ParseDurationError> {
+        complete(signed(alt((
+            parse_week_format,
+            parse_basic_format_with_quarters,
+        ))))(input)
+        .finish()
+        .map(|(_, duration)| duration)
+        .map_err(|err| ParseDurationError::new(input, err))
+    }
+
+    /// Parse given string into Duration, also accepting weeks (`W`)
+    /// combined with other components, between `month` and `day`.
+    ///
+    /// ISO8601 only allows weeks on their own, as in `P3W`, which
+    /// [`Duration::parse`] accepts. Weeks are stored as seven days each,
+    /// so the duration formats back to days.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert!(Duration::parse("P1W2D").is_err());
+    ///
+    /// let duration = Duration::parse_lenient("P1W2DT12H").unwrap();
+    /// assert_eq!(duration, Duration::new(0., 0., 9., 12., 0., 0.));
+    /// assert_eq!(duration.to_string(), "P9DT12H");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_lenient(input: &str) -> Result<Duration, ParseDurationError> {
+        complete(signed(parse_basic_format_with_weeks))(input)
             .finish()
             .map(|(_, duration)| duration)
             .map_err(|err| ParseDurationError::new(input, err))
     }
+
+    /// Return number of quarters in the duration
+    ///
+    /// This method will return `None` is `Duration` contains
+    /// `second`, `minute` or `hour`.
+    #[cfg(feature = "extensions")]
+    pub fn num_quarters(&self) -> Option<f32> {
+        self.num_months()
+            .map(|months| months / Unit::Quarter.months())
+    }
 }
 
+/// Error returned when the input isn't a valid duration.
+///
+/// [`reason`](ParseDurationError::reason) tells what the parser found
+/// wrong at `position`, the byte offset where it stopped.
+#[cfg(feature = "std")]
 #[derive(PartialEq, Eq)]
 pub struct ParseDurationError {
     pub input: String,
     pub position: usize,
-    pub kind: ErrorKind,
+    pub(crate) reason: ErrorReason,
 }
 
+#[cfg(feature = "std")]
 impl ParseDurationError {
-    fn new(input: &str, err: nom::error::Error<&str>) -> Self {
+    pub(crate) fn new(input: &str, err: SyntaxError<'_>) -> Self {
         ParseDurationError {
             input: input.to_string(),
             position: input.len() - err.input.len(),
-            kind: err.code,
+            reason: err.reason,
         }
     }
 }
 
+/// Error of the nom parsers: the input left where parsing stopped, and
+/// why it stopped there.
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq)]
+pub(crate) struct SyntaxError<'a> {
+    input: &'a str,
+    reason: ErrorReason,
+}
+
+#[cfg(feature = "std")]
+impl<'a> SyntaxError<'a> {
+    pub(crate) fn new(input: &'a str, reason: ErrorReason) -> Self {
+        SyntaxError { input, reason }
+    }
+}
+
+/// Failures of nom's own combinators only say that the component at
+/// `input` couldn't be read, the parsers replace them with a more precise
+/// reason where they know one.
+#[cfg(feature = "std")]
+impl<'a> ParseError<&'a str> for SyntaxError<'a> {
+    fn from_error_kind(input: &'a str, _: ErrorKind) -> Self {
+        SyntaxError::new(input, ErrorReason::InvalidComponent)
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, E> FromExternalError<&'a str, E> for SyntaxError<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        SyntaxError::from_error_kind(input, kind)
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) type ParseResult<'a, T> = IResult<&'a str, T, SyntaxError<'a>>;
+
+/// Run `parser`, failing with `reason` if it fails.
+#[cfg(feature = "std")]
+pub(crate) fn expect<'a, T>(
+    reason: ErrorReason,
+    mut parser: impl FnMut(&'a str) -> ParseResult<'a, T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, T> {
+    move |input| parser(input).map_err(|err| err.map(|_| SyntaxError::new(input, reason)))
+}
+
+/// Run `parser` on the whole input, failing with the reason the rest
+/// couldn't be parsed if it stops before the end.
+#[cfg(feature = "std")]
+pub(crate) fn complete<'a, T>(
+    mut parser: impl FnMut(&'a str) -> ParseResult<'a, T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, T> {
+    move |input| {
+        let (rest, output) = parser(input)?;
+        if rest.is_empty() {
+            Ok((rest, output))
+        } else {
+            let parsed = &input[..input.len() - rest.len()];
+            Err(Err::Error(SyntaxError::new(
+                rest,
+                trailing_reason(parsed, rest),
+            )))
+        }
+    }
+}
+
+/// Fail because none of the components could be parsed at `input`, the
+/// rest of the input after `P` (or `PT`).
+#[cfg(feature = "std")]
+pub(crate) fn no_component<T>(input: &str) -> ParseResult<'_, T> {
+    let reason = if input.is_empty() {
+        ErrorReason::Empty
+    } else {
+        component_reason(input)
+    };
+
+    Err(Err::Error(SyntaxError::new(input, reason)))
+}
+
+/// Return the letter following the number at the start of `rest`, if any
+#[cfg(feature = "std")]
+pub(crate) fn designator_of(rest: &str) -> Option<char> {
+    rest.trim_start_matches('-')
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c == ',')
+        .chars()
+        .next()
+        .filter(|c| c.is_alphabetic())
+}
+
+/// Return `true` if the number at the start of `rest` has an exponent
+#[cfg(feature = "std")]
+fn has_exponent(rest: &str) -> bool {
+    let mut exponent = rest
+        .trim_start_matches('-')
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c == ',')
+        .chars()
+        .skip(1)
+        .skip_while(|c| *c == '+' || *c == '-');
+    exponent.next().is_some_and(|c| c.is_ascii_digit())
+}
+
+/// Tell why the component at the start of `rest` isn't valid.
+#[cfg(feature = "std")]
+fn component_reason(rest: &str) -> ErrorReason {
+    if !rest
+        .trim_start_matches('-')
+        .starts_with(|c: char| c.is_ascii_digit())
+    {
+        return ErrorReason::InvalidComponent;
+    }
+
+    match designator_of(rest) {
+        None => ErrorReason::MissingDesignator,
+        // an exponent, which makes the number too large for `f32`
+        Some('e' | 'E') if has_exponent(rest) => ErrorReason::InvalidComponent,
+        Some(designator) if !"YMWDHS".contains(designator) => ErrorReason::UnknownDesignator,
+        Some(_) => ErrorReason::InvalidComponent,
+    }
+}
+
+/// Tell why `rest` can't follow the valid components of `parsed`.
+#[cfg(feature = "std")]
+fn trailing_reason(parsed: &str, rest: &str) -> ErrorReason {
+    if !rest.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+        return ErrorReason::UnexpectedCharacter;
+    }
+
+    let designator = match component_reason(rest) {
+        ErrorReason::InvalidComponent => match designator_of(rest) {
+            Some(designator) if "YMWDHS".contains(designator) => designator,
+            _ => return ErrorReason::InvalidComponent,
+        },
+        reason => return reason,
+    };
+
+    // the designators allowed in the current part, in order
+    let (part, order) = match parsed.find('T') {
+        Some(t) => (&parsed[t + 1..], "HMS"),
+        None => (&parsed[parsed.find('P').map_or(0, |p| p + 1)..], "YMWD"),
+    };
+    let rank = match order.find(designator) {
+        Some(rank) => rank,
+        None => return ErrorReason::OutOfOrder,
+    };
+
+    if part.contains(designator) {
+        ErrorReason::DuplicateComponent
+    } else if part.contains(|c: char| order[rank + 1..].contains(c)) {
+        ErrorReason::OutOfOrder
+    } else {
+        ErrorReason::InvalidComponent
+    }
+}
+
+/// Error returned by [`Duration::parse_strict`].
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum StrictParseError {
+    /// The input isn't a valid duration, even for [`Duration::parse`].
+    Parse(ParseDurationError),
+    /// A component other than the last one has a fraction, `position`
+    /// being the start of its number.
+    FractionNotLast { input: String, position: usize },
+    /// A fraction uses another decimal separator than the previous ones,
+    /// `position` being the start of its number.
+    MixedDecimalSeparators { input: String, position: usize },
+}
+
+#[cfg(feature = "std")]
+impl StrictParseError {
+    /// Return the input which failed to parse
+    pub fn input(&self) -> &str {
+        match self {
+            StrictParseError::Parse(err) => &err.input,
+            StrictParseError::FractionNotLast { input, .. }
+            | StrictParseError::MixedDecimalSeparators { input, .. } => input,
+        }
+    }
+
+    /// Return the byte offset the error refers to
+    pub fn position(&self) -> usize {
+        match self {
+            StrictParseError::Parse(err) => err.position,
+            StrictParseError::FractionNotLast { position, .. }
+            | StrictParseError::MixedDecimalSeparators { position, .. } => *position,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ParseDurationError> for StrictParseError {
+    fn from(err: ParseDurationError) -> Self {
+        StrictParseError::Parse(err)
+    }
+}
+
+#[cfg(feature = "std")]
 impl fmt::Debug for ParseDurationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "Parse error: {:?} in {:?} at position {}",
-            self.kind, self.input, self.position
+            self.reason, self.input, self.position
         )
     }
 }
 
+#[cfg(feature = "std")]
 impl FromStr for Duration {
     type Err = ParseDurationError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -178,55 +706,96 @@ impl FromStr for Duration {
     }
 }
 
+/// Formats as the canonical ISO8601 string, see [`Duration::to_iso8601`].
 impl fmt::Display for Duration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("P")?;
-        if self.year > 0.0 {
-            write!(f, "{}Y", self.year)?;
-        }
-        if self.month > 0.0 {
-            write!(f, "{}M", self.month)?;
-        }
-        if self.day > 0.0 {
-            write!(f, "{}D", self.day)?;
-        }
-        if self.hour > 0.0 || self.minute > 0.0 || self.second > 0.0 {
-            f.write_str("T")?;
-        }
-        if self.hour > 0.0 {
-            write!(f, "{}H", self.hour)?;
-        }
-        if self.minute > 0.0 {
-            write!(f, "{}M", self.minute)?;
-        }
-        if self.second > 0.0 {
-            write!(f, "{}S", self.second)?;
-        }
-        Ok(())
+        self.write_iso(f)
+    }
+}
+
+/// Write `value` followed by `designator`.
+fn write_component<W: fmt::Write>(w: &mut W, value: f32, designator: char) -> fmt::Result {
+    // whole values below 2^24 are exact as `i32`, and formatting an
+    // integer is much cheaper than finding the shortest float digits
+    if value.abs() < 16_777_216. && value == value as i32 as f32 {
+        write!(w, "{}{}", value as i32, designator)
+    } else {
+        write!(w, "{}{}", value, designator)
     }
 }
 
-fn decimal_comma_number(input: &str) -> IResult<&str, f32> {
+#[cfg(feature = "std")]
+fn decimal_comma_number(input: &str) -> ParseResult<'_, f32> {
     map_res(separated_pair(digit1, tag(","), digit1), |(a, b)| {
         f32::from_str(&format!("{}.{}", a, b))
     })(input)
 }
 
-fn value_with_designator(designator: &str) -> impl Fn(&str) -> IResult<&str, f32> + '_ {
+#[cfg(feature = "std")]
+pub(crate) fn value_with_designator(
+    designator: &str,
+) -> impl Fn(&str) -> ParseResult<'_, f32> + '_ {
     move |input| {
         terminated(
-            alt((float, decimal_comma_number, map_res(digit1, f32::from_str))),
+            // `float` also reads `NaN`, `inf` and exponents too large for `f32`
+            verify(
+                alt((decimal_comma_number, float, map_res(digit1, f32::from_str))),
+                |value: &f32| value.is_finite(),
+            ),
             tag(designator),
         )(input)
     }
 }
 
-fn parse_basic_format(input: &str) -> IResult<&str, Duration> {
-    let (input, (year, month, day)) = tuple((
-        opt(value_with_designator("Y")),
-        opt(value_with_designator("M")),
-        opt(value_with_designator("D")),
-    ))(input)?;
+/// Parse the `P` designator and the components with `parser`, negating
+/// them when preceded by `-`.
+#[cfg(feature = "std")]
+fn signed<'a>(
+    parser: impl FnMut(&'a str) -> ParseResult<'a, Duration>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Duration> {
+    map(
+        pair(
+            opt(tag("-")),
+            preceded(expect(ErrorReason::MissingPrefix, tag("P")), parser),
+        ),
+        |(sign, duration)| if sign.is_some() { -duration } else { duration },
+    )
+}
+
+#[cfg(feature = "std")]
+fn parse_basic_format(input: &str) -> ParseResult<'_, Duration> {
+    parse_basic_format_with(input, false, false)
+}
+
+#[cfg(feature = "extensions")]
+fn parse_basic_format_with_quarters(input: &str) -> ParseResult<'_, Duration> {
+    parse_basic_format_with(input, true, false)
+}
+
+#[cfg(feature = "std")]
+fn parse_basic_format_with_weeks(input: &str) -> ParseResult<'_, Duration> {
+    parse_basic_format_with(input, false, true)
+}
+
+#[cfg(feature = "std")]
+fn parse_basic_format_with(input: &str, quarters: bool, weeks: bool) -> ParseResult<'_, Duration> {
+    let (input, year) = opt(value_with_designator("Y"))(input)?;
+
+    let (input, quarter) = if quarters {
+        opt(value_with_designator("Q"))(input)?
+    } else {
+        (input, None)
+    };
+
+    let (input, month) = opt(value_with_designator("M"))(input)?;
+
+    let (input, week) = if weeks {
+        opt(value_with_designator("W"))(input)?
+    } else {
+        (input, None)
+    };
+
+    let (input, day) = opt(value_with_designator("D"))(input)?;
 
     let (input, time) = opt(preceded(
         tag("T"),
@@ -240,20 +809,22 @@ fn parse_basic_format(input: &str) -> IResult<&str, Duration> {
     let (hour, minute, second) = time.unwrap_or_default();
 
     if year.is_none()
+        && quarter.is_none()
         && month.is_none()
+        && week.is_none()
         && day.is_none()
         && hour.is_none()
         && minute.is_none()
         && second.is_none()
     {
-        Err(Err::Error(ParseError::from_error_kind(input, ErrorKind::Verify)))
+        no_component(input)
     } else {
         Ok((
             input,
             Duration {
                 year: year.unwrap_or_default(),
-                month: month.unwrap_or_default(),
-                day: day.unwrap_or_default(),
+                month: quarter.unwrap_or_default() * 3. + month.unwrap_or_default(),
+                day: week.unwrap_or_default() * 7. + day.unwrap_or_default(),
                 hour: hour.unwrap_or_default(),
                 minute: minute.unwrap_or_default(),
                 second: second.unwrap_or_default(),
@@ -262,7 +833,8 @@ fn parse_basic_format(input: &str) -> IResult<&str, Duration> {
     }
 }
 
-fn parse_week_format(input: &str) -> IResult<&str, Duration> {
+#[cfg(feature = "std")]
+fn parse_week_format(input: &str) -> ParseResult<'_, Duration> {
     let (input, week) = value_with_designator("W")(input)?;
 
     Ok((
@@ -278,6 +850,240 @@ fn parse_week_format(input: &str) -> IResult<&str, Duration> {
     ))
 }
 
-fn _parse_extended_format(_input: &str) -> IResult<&str, Duration> {
-    unimplemented!()
+/// Parse the alternative format of ISO8601 §4.4.3.3, either basic
+/// (`00030604T123005`) or extended (`0003-06-04T12:30:05`).
+///
+/// Values can't exceed the carry-over points of 12 months, 30 days, 24
+/// hours, 60 minutes and 60 seconds. Only the seconds can have a fraction.
+#[cfg(feature = "std")]
+fn parse_alternative_format(input: &str) -> ParseResult<'_, Duration> {
+    alt((
+        |input| parse_alternative_format_with(input, "-", ":"),
+        |input| parse_alternative_format_with(input, "", ""),
+    ))(input)
+}
+
+#[cfg(feature = "std")]
+fn parse_alternative_format_with<'a>(
+    input: &'a str,
+    date_separator: &str,
+    time_separator: &str,
+) -> ParseResult<'a, Duration> {
+    let (input, (year, month, day)) = tuple((
+        fixed_digits(4, 9999),
+        preceded(tag(date_separator), fixed_digits(2, 12)),
+        preceded(tag(date_separator), fixed_digits(2, 30)),
+    ))(input)?;
+
+    let seconds = verify(
+        map_res(
+            recognize(pair(
+                take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+                opt(pair(alt((tag("."), tag(","))), digit1)),
+            )),
+            |s: &str| f32::from_str(&s.replace(',', ".")),
+        ),
+        |second| *second <= 60.,
+    );
+
+    let (input, time) = opt(preceded(
+        tag("T"),
+        tuple((
+            fixed_digits(2, 24),
+            preceded(tag(time_separator), fixed_digits(2, 60)),
+            preceded(tag(time_separator), seconds),
+        )),
+    ))(input)?;
+
+    // stop here so that `P20000101D` falls back to the basic format
+    let (input, _) = eof(input)?;
+    let (hour, minute, second) = time.unwrap_or_default();
+
+    Ok((input, Duration::new(year, month, day, hour, minute, second)))
+}
+
+/// Parse exactly `len` digits, up to `max`.
+#[cfg(feature = "std")]
+fn fixed_digits(len: usize, max: u32) -> impl Fn(&str) -> ParseResult<'_, f32> {
+    move |input| {
+        map(
+            verify(
+                map_res(
+                    take_while_m_n(len, len, |c: char| c.is_ascii_digit()),
+                    u32::from_str,
+                ),
+                |value| *value <= max,
+            ),
+            |value| value as f32,
+        )(input)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{ErrorReason, ToStdError};
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "PT0S",
+            "P1Y",
+            "P1M",
+            "PT1M",
+            "P1DT0.25S",
+            "P1Y2M3DT4H5M6.5S",
+        ] {
+            let duration = Duration::parse(input).unwrap();
+            assert_eq!(duration.to_string(), input);
+            assert_eq!(Duration::parse(&duration.to_string()), Ok(duration));
+        }
+
+        assert_eq!(Duration::parse("PT90S").unwrap().to_string(), "PT90S");
+        assert_eq!(Duration::parse("P0Y0D").unwrap().to_string(), "PT0S");
+
+        // whole components are written as integers, the same as floats
+        for value in [1., 16_777_215., 16_777_216., 1e20, 0.5] {
+            let duration = Duration::new(0., 0., value, -3., 0., 0.);
+            assert_eq!(duration.to_string(), format!("P{}DT-3H", value));
+        }
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(Duration::parse("P2W").unwrap().to_string(), "P14D");
+        assert!(Duration::parse("P1Y1W").is_err());
+
+        assert_eq!(
+            Duration::parse_lenient("P1Y2M3W4D"),
+            Ok(Duration::new(1., 2., 25., 0., 0., 0.))
+        );
+        assert_eq!(Duration::parse_lenient("-P0.5W").unwrap().day, -3.5);
+        assert_eq!(Duration::parse_lenient("P2W"), Duration::parse("P2W"));
+        assert!(Duration::parse_lenient("P1D1W").is_err());
+    }
+
+    #[test]
+    fn strict_fractions() {
+        assert_eq!(
+            Duration::parse_strict("PT1.5H"),
+            Ok(Duration::new(0., 0., 0., 1.5, 0., 0.))
+        );
+        assert_eq!(
+            Duration::parse_strict("P1,5D").ok(),
+            Duration::parse("P1.5D").ok()
+        );
+        assert_eq!(Duration::parse_strict("-P1DT0,25S").unwrap().second, -0.25);
+
+        let error = Duration::parse_strict("P1Y2.5MT3.5H").unwrap_err();
+        assert_eq!(error.position(), 3);
+        assert_eq!(error.reason(), ErrorReason::FractionNotLast);
+
+        let error = Duration::parse_strict("P1.5Y2,5M").unwrap_err();
+        assert_eq!(error.position(), 5);
+        assert_eq!(error.reason(), ErrorReason::MixedDecimalSeparators);
+
+        assert!(Duration::parse_strict("P1.5Y2M").is_err());
+        assert_eq!(
+            Duration::parse_strict("P1X").unwrap_err(),
+            StrictParseError::Parse(Duration::parse("P1X").unwrap_err())
+        );
+        assert_eq!(
+            Duration::parse_strict("P1X").unwrap_err().reason(),
+            ErrorReason::UnknownDesignator
+        );
+    }
+
+    #[test]
+    fn non_finite_and_exponents() {
+        for input in ["PNaND", "PTnanS", "PinfD", "P1DT-infS", "-PTinfS", "P1e39Y"] {
+            let error = Duration::parse(input).unwrap_err();
+            assert_eq!(error.reason(), ErrorReason::InvalidComponent, "{}", input);
+        }
+        assert_eq!(Duration::parse("P1e39Y").unwrap_err().position, 1);
+
+        // exponents are still read by `parse`, but not by `parse_strict`
+        assert_eq!(Duration::parse("P1e10Y").unwrap().year, 1e10);
+        for (input, position) in [("P1e10Y", 1), ("PT1.5E-3S", 2), ("P1DT2e1H", 4)] {
+            let error = Duration::parse_strict(input).unwrap_err();
+            assert_eq!(error.reason(), ErrorReason::InvalidComponent, "{}", input);
+            assert_eq!(error.position(), position, "{}", input);
+        }
+    }
+
+    #[test]
+    fn alternative_format() {
+        let expected = Duration::new(3., 6., 4., 12., 30., 5.);
+        for input in [
+            "P0003-06-04T12:30:05",
+            "P00030604T123005",
+            "P0003-06-04T12:30:05.0",
+        ] {
+            assert_eq!(Duration::parse(input), Ok(expected));
+        }
+        assert_eq!(
+            Duration::parse("P0001-00-00"),
+            Ok(Duration::new(1., 0., 0., 0., 0., 0.))
+        );
+        assert_eq!(
+            Duration::parse("-P00000000T000030,5").unwrap().second,
+            -30.5
+        );
+        assert_eq!(
+            Duration::parse_strict("P0000-00-00T00:00:01,5")
+                .unwrap()
+                .second,
+            1.5
+        );
+
+        for input in [
+            "P0003-13-04",
+            "P0003-06-31",
+            "P0003-06-04T25:00:00",
+            "P0003-06-04T12:61:00",
+            "P0003-0604",
+            "P0003-06-04T123005",
+            "P3-06-04",
+            "P0003-06-04T",
+        ] {
+            assert!(Duration::parse(input).is_err(), "{}", input);
+        }
+
+        // eight digits followed by a designator are the basic format
+        assert_eq!(
+            Duration::parse("P20000101D"),
+            Ok(Duration::new(0., 0., 20000101., 0., 0., 0.))
+        );
+        assert_eq!(
+            Duration::parse("P00010101D"),
+            Ok(Duration::new(0., 0., 10101., 0., 0., 0.))
+        );
+        assert_eq!(
+            Duration::parse("P00010101Y"),
+            Ok(Duration::new(10101., 0., 0., 0., 0., 0.))
+        );
+    }
+
+    #[test]
+    fn negative_durations() {
+        for input in ["-P1D", "-PT0.5S", "-P1Y2MT3H", "P1DT-12H", "P-1M1D"] {
+            let duration = Duration::parse(input).unwrap();
+            assert_eq!(duration.to_string(), input);
+        }
+
+        assert_eq!(
+            Duration::parse("-P-1D"),
+            Ok(Duration::new(0., 0., 1., 0., 0., 0.))
+        );
+        assert_eq!(Duration::parse("-P1W").unwrap().day, -7.);
+        assert!(Duration::parse("--P1D").is_err());
+        assert!(Duration::parse("P-").is_err());
+
+        assert_eq!(Duration::parse("-PT1S").unwrap().num_seconds(), Some(-1.));
+        assert_eq!(
+            Duration::parse("-PT1S").unwrap().to_std(),
+            Err(ToStdError::Negative)
+        );
+        assert_eq!(Duration::parse("-P1M").unwrap().num_seconds(), None);
+    }
 }
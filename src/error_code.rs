@@ -0,0 +1,117 @@
+use std::fmt;
+
+use crate::{ErrorReason, ParseDurationError, StrictParseError};
+
+/// Machine-readable code of a parse error.
+///
+/// The string form of each code, as returned by [`ErrorCode::as_str`], is
+/// guaranteed to stay the same across minor versions, so it can be sent
+/// to API clients which match on it. New codes may be added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `E001_MISSING_P`: the input doesn't start with `P`.
+    MissingP,
+    /// `E002_EMPTY`: the input has no component.
+    Empty,
+    /// `E003_INVALID_COMPONENT`: a component is malformed.
+    InvalidComponent,
+    /// `E004_TRAILING_CHARACTERS`: the input goes on after the last component.
+    TrailingCharacters,
+    /// `E005_FRACTION_NOT_LAST`: a component other than the last one has a
+    /// fraction.
+    FractionNotLast,
+    /// `E006_MIXED_DECIMAL_SEPARATORS`: fractions use both `.` and `,`.
+    MixedDecimalSeparators,
+    /// `E007_DUPLICATE_COMPONENT`: a component appears twice.
+    DuplicateComponent,
+    /// `E008_OUT_OF_ORDER`: a component comes after one it should precede.
+    OutOfOrder,
+}
+
+impl ErrorCode {
+    /// Return the stable string form of the code
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::MissingP => "E001_MISSING_P",
+            ErrorCode::Empty => "E002_EMPTY",
+            ErrorCode::InvalidComponent => "E003_INVALID_COMPONENT",
+            ErrorCode::TrailingCharacters => "E004_TRAILING_CHARACTERS",
+            ErrorCode::FractionNotLast => "E005_FRACTION_NOT_LAST",
+            ErrorCode::MixedDecimalSeparators => "E006_MIXED_DECIMAL_SEPARATORS",
+            ErrorCode::DuplicateComponent => "E007_DUPLICATE_COMPONENT",
+            ErrorCode::OutOfOrder => "E008_OUT_OF_ORDER",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<ErrorReason> for ErrorCode {
+    fn from(reason: ErrorReason) -> Self {
+        match reason {
+            ErrorReason::MissingPrefix => ErrorCode::MissingP,
+            ErrorReason::Empty => ErrorCode::Empty,
+            ErrorReason::InvalidComponent
+            | ErrorReason::MissingDesignator
+            | ErrorReason::UnknownDesignator => ErrorCode::InvalidComponent,
+            ErrorReason::DuplicateComponent => ErrorCode::DuplicateComponent,
+            ErrorReason::OutOfOrder => ErrorCode::OutOfOrder,
+            ErrorReason::UnexpectedCharacter => ErrorCode::TrailingCharacters,
+            ErrorReason::FractionNotLast => ErrorCode::FractionNotLast,
+            ErrorReason::MixedDecimalSeparators => ErrorCode::MixedDecimalSeparators,
+        }
+    }
+}
+
+impl ParseDurationError {
+    /// Return the machine-readable code of the error
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, ErrorCode};
+    ///
+    /// let error = Duration::parse("1D").unwrap_err();
+    /// assert_eq!(error.code(), ErrorCode::MissingP);
+    /// assert_eq!(error.code().as_str(), "E001_MISSING_P");
+    /// ```
+    pub fn code(&self) -> ErrorCode {
+        self.reason().into()
+    }
+}
+
+impl StrictParseError {
+    /// Return the machine-readable code of the error
+    pub fn code(&self) -> ErrorCode {
+        self.reason().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Duration;
+
+    #[test]
+    fn codes() {
+        let code = |input: &str| Duration::parse(input).unwrap_err().code().to_string();
+
+        assert_eq!(code("X"), "E001_MISSING_P");
+        assert_eq!(code("P"), "E002_EMPTY");
+        assert_eq!(code("P1.5.5D"), "E003_INVALID_COMPONENT");
+        assert_eq!(code("P1D "), "E004_TRAILING_CHARACTERS");
+        assert_eq!(code("P1Y2Y"), "E007_DUPLICATE_COMPONENT");
+        assert_eq!(code("PT1H2D"), "E008_OUT_OF_ORDER");
+
+        let strict_code = |input: &str| {
+            Duration::parse_strict(input)
+                .unwrap_err()
+                .code()
+                .to_string()
+        };
+        assert_eq!(strict_code("P1.5DT1H"), "E005_FRACTION_NOT_LAST");
+        assert_eq!(strict_code("P1.5DT1,5H"), "E006_MIXED_DECIMAL_SEPARATORS");
+    }
+}
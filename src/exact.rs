@@ -0,0 +1,412 @@
+use std::{convert::TryFrom, error::Error, fmt, str::FromStr};
+
+use crate::seconds::decimal_parts;
+use crate::{Duration, ParseDurationError, Unit};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Error returned when a duration can't be represented as an
+/// [`ExactDuration`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExactDurationError {
+    /// The input isn't a valid duration.
+    Parse(ParseDurationError),
+    /// A fraction of the unit can't be converted to whole smaller units
+    /// and nanoseconds, e.g. a fraction of a month, or a fraction of a
+    /// second below the nanosecond.
+    Inexact(Unit),
+    /// A component doesn't fit in an `i64`, or isn't finite.
+    OutOfRange(Unit),
+}
+
+impl fmt::Display for ExactDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExactDurationError::Parse(error) => error.fmt(f),
+            ExactDurationError::Inexact(unit) => {
+                write!(f, "{:?} component can't be represented exactly", unit)
+            }
+            ExactDurationError::OutOfRange(unit) => {
+                write!(f, "{:?} component is out of range", unit)
+            }
+        }
+    }
+}
+
+impl Error for ExactDurationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExactDurationError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseDurationError> for ExactDurationError {
+    fn from(error: ParseDurationError) -> Self {
+        ExactDurationError::Parse(error)
+    }
+}
+
+/// A duration stored without loss: whole components, plus a nanosecond
+/// fraction of the second.
+///
+/// Unlike [`Duration`], whose components are `f32`, `PT0.000001S` or
+/// `PT123456789S` round-trip exactly and equality doesn't depend on
+/// float rounding. A fraction of a year, a day, an hour or a minute is
+/// converted to the smaller units, so `P0.5Y` is stored as `P6M` and
+/// `PT1.5H` as `PT1H30M`. A fraction of a month has no exact length and
+/// is rejected.
+///
+/// Each component can be negative, the nanoseconds having the sign of
+/// the seconds. Convert with `From`/`TryFrom` to use the float API of
+/// `Duration`.
+///
+/// ```rust
+/// use iso8601_duration::{Duration, ExactDuration};
+///
+/// let exact = ExactDuration::parse("PT0.000001S").unwrap();
+/// assert_eq!(exact.nanosecond, 1_000);
+/// assert_eq!(exact.to_string(), "PT0.000001S");
+///
+/// let exact = ExactDuration::parse("PT123456789S").unwrap();
+/// assert_eq!(exact.second, 123_456_789);
+/// assert_eq!(Duration::from(exact).second, 123_456_790.);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExactDuration {
+    pub year: i64,
+    pub month: i64,
+    pub day: i64,
+    pub hour: i64,
+    pub minute: i64,
+    pub second: i64,
+    /// Fraction of the second, from -999,999,999 to 999,999,999
+    pub nanosecond: i32,
+}
+
+impl ExactDuration {
+    /// Parse given string into an `ExactDuration`, accepting the same
+    /// syntax as [`Duration::parse`]
+    pub fn parse(input: &str) -> Result<ExactDuration, ExactDurationError> {
+        let (negative, components) = read_components(input)?;
+
+        let exact = from_decimals(components)?;
+        Ok(if negative { exact.negate() } else { exact })
+    }
+
+    fn negate(self) -> ExactDuration {
+        ExactDuration {
+            year: -self.year,
+            month: -self.month,
+            day: -self.day,
+            hour: -self.hour,
+            minute: -self.minute,
+            second: -self.second,
+            nanosecond: -self.nanosecond,
+        }
+    }
+}
+
+/// A number as its digits and the number of them after the decimal point
+pub(crate) type Decimal = (i128, u32);
+
+/// Read the decimal value of each component of `input`, and whether the
+/// whole duration is negated.
+pub(crate) fn read_components(input: &str) -> Result<(bool, [Decimal; 6]), ParseDurationError> {
+    // validate with the float parser, then read the digits again
+    Duration::parse(input)?;
+
+    let negative = input.starts_with('-');
+    let rest = input.trim_start_matches('-').trim_start_matches('P');
+    let components = if rest.contains(|c: char| c.is_ascii_alphabetic() && c != 'T') {
+        designated_components(rest)
+    } else {
+        alternative_components(rest)
+    };
+
+    Ok((negative, components))
+}
+
+/// Read the components of `P1Y2M3DT4H5M6S`, without the `P`.
+fn designated_components(input: &str) -> [Decimal; 6] {
+    let mut components = [(0, 0); 6];
+    let mut time = false;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        let index = match c {
+            'T' => {
+                time = true;
+                start = i + 1;
+                continue;
+            }
+            'Y' => 0,
+            'M' if !time => 1,
+            'W' | 'D' => 2,
+            'H' => 3,
+            'M' => 4,
+            'S' => 5,
+            _ => continue,
+        };
+
+        let (digits, scale) = parse_decimal(&input[start..i]);
+        let digits = if c == 'W' { digits * 7 } else { digits };
+        components[index] = (digits, scale);
+        start = i + 1;
+    }
+
+    components
+}
+
+/// Read the components of `0003-06-04T12:30:05` or `00030604T123005`.
+fn alternative_components(input: &str) -> [Decimal; 6] {
+    let digits: String = input.chars().filter(|c| *c != '-' && *c != ':').collect();
+    let (date, time) = digits.split_once('T').unwrap_or((&digits, "000000"));
+
+    [
+        parse_decimal(&date[0..4]),
+        parse_decimal(&date[4..6]),
+        parse_decimal(&date[6..8]),
+        parse_decimal(&time[0..2]),
+        parse_decimal(&time[2..4]),
+        parse_decimal(&time[4..]),
+    ]
+}
+
+/// Parse a number accepted by [`Duration::parse`] into its decimal digits,
+/// saturating when it has too many of them.
+fn parse_decimal(text: &str) -> Decimal {
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(e) => (&text[..e], text[e + 1..].parse::<i32>().unwrap_or_default()),
+        None => (text, 0),
+    };
+    let (whole, fraction) = mantissa.split_once(['.', ',']).unwrap_or((mantissa, ""));
+    let digits: String = whole.chars().chain(fraction.chars()).collect();
+    let digits = digits
+        .parse::<i128>()
+        .unwrap_or(if digits.starts_with('-') {
+            i128::MIN
+        } else {
+            i128::MAX
+        });
+
+    let scale = fraction.len() as i32 - exponent;
+    if scale >= 0 {
+        (digits, scale as u32)
+    } else {
+        let factor = 10i128
+            .checked_pow(scale.unsigned_abs())
+            .unwrap_or(i128::MAX);
+        (digits.saturating_mul(factor), 0)
+    }
+}
+
+/// Build a duration from the decimal value of each component, pushing
+/// fractions down to the smaller units.
+pub(crate) fn from_decimals(components: [Decimal; 6]) -> Result<ExactDuration, ExactDurationError> {
+    const UNITS: [(Unit, i128); 6] = [
+        (Unit::Year, 12),
+        (Unit::Month, 0),
+        (Unit::Day, 24),
+        (Unit::Hour, 60),
+        (Unit::Minute, 60),
+        (Unit::Second, NANOS_PER_SECOND),
+    ];
+
+    let scale = components
+        .iter()
+        .map(|(_, scale)| *scale)
+        .max()
+        .unwrap_or(0);
+    let denominator = 10i128
+        .checked_pow(scale)
+        .ok_or(ExactDurationError::Inexact(Unit::Second))?;
+
+    let mut values = [0; 6];
+    // fraction of the previous unit, in units of the current one and over
+    // `denominator`
+    let mut carry: i128 = 0;
+    for (i, ((unit, factor), (digits, digits_scale))) in UNITS.iter().zip(components).enumerate() {
+        let out_of_range = || ExactDurationError::OutOfRange(*unit);
+        let value = digits
+            .checked_mul(10i128.pow(scale - digits_scale))
+            .and_then(|value| value.checked_add(carry))
+            .ok_or_else(out_of_range)?;
+
+        values[i] = i64::try_from(value / denominator).map_err(|_| out_of_range())?;
+        let remainder = value % denominator;
+        if remainder != 0 && *factor == 0 {
+            return Err(ExactDurationError::Inexact(*unit));
+        }
+        carry = remainder.checked_mul(*factor).ok_or_else(out_of_range)?;
+    }
+
+    if carry % denominator != 0 {
+        return Err(ExactDurationError::Inexact(Unit::Second));
+    }
+
+    Ok(ExactDuration {
+        year: values[0],
+        month: values[1],
+        day: values[2],
+        hour: values[3],
+        minute: values[4],
+        second: values[5],
+        nanosecond: (carry / denominator) as i32,
+    })
+}
+
+impl FromStr for ExactDuration {
+    type Err = ExactDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ExactDuration::parse(s)
+    }
+}
+
+/// Formats as the shortest exact ISO8601 string, in the same layout as
+/// `Duration`.
+impl fmt::Display for ExactDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == ExactDuration::default() {
+            return f.write_str("PT0S");
+        }
+
+        let components = [
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond as i64,
+        ];
+        // write `-P1D` rather than `P-1D` when every component is negative
+        let negative = components.iter().all(|value| *value <= 0);
+        let duration = if negative { self.negate() } else { *self };
+
+        f.write_str(if negative { "-P" } else { "P" })?;
+        for (value, designator) in [
+            (duration.year, 'Y'),
+            (duration.month, 'M'),
+            (duration.day, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        if duration.hour != 0
+            || duration.minute != 0
+            || duration.second != 0
+            || duration.nanosecond != 0
+        {
+            f.write_str("T")?;
+        }
+        for (value, designator) in [(duration.hour, 'H'), (duration.minute, 'M')] {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+
+        match (duration.second, duration.nanosecond) {
+            (0, 0) => Ok(()),
+            (second, 0) => write!(f, "{}S", second),
+            (second, nanosecond) => {
+                let sign = if second == 0 && nanosecond < 0 {
+                    "-"
+                } else {
+                    ""
+                };
+                let fraction = format!("{:09}", nanosecond.unsigned_abs());
+                write!(f, "{}{}.{}S", sign, second, fraction.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+impl From<ExactDuration> for Duration {
+    fn from(exact: ExactDuration) -> Self {
+        Duration::new(
+            exact.year as f32,
+            exact.month as f32,
+            exact.day as f32,
+            exact.hour as f32,
+            exact.minute as f32,
+            (exact.second as f64 + exact.nanosecond as f64 / NANOS_PER_SECOND as f64) as f32,
+        )
+    }
+}
+
+/// Converts each component from its shortest decimal form, the one
+/// `Display` writes, so `PT0.1S` gives exactly 100,000,000 nanoseconds.
+impl TryFrom<Duration> for ExactDuration {
+    type Error = ExactDurationError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        let mut components = [(0, 0); 6];
+        for (component, (unit, value)) in components.iter_mut().zip(duration.components()) {
+            *component = decimal_parts(value).ok_or(ExactDurationError::OutOfRange(unit))?;
+        }
+
+        from_decimals(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        for input in [
+            "PT0S",
+            "P1Y2M3DT4H5M6S",
+            "PT0.000000001S",
+            "PT123456789.123456789S",
+            "-P1DT0.5S",
+            "P1DT-12H",
+            "P1DT-0.25S",
+            "P9223372036854775807D",
+        ] {
+            assert_eq!(ExactDuration::parse(input).unwrap().to_string(), input);
+        }
+
+        let parse = |input: &str| ExactDuration::parse(input).map(|d| d.to_string());
+        assert_eq!(parse("P0.5Y").unwrap(), "P6M");
+        assert_eq!(parse("P2W").unwrap(), "P14D");
+        assert_eq!(parse("P0.1DT0.1H").unwrap(), "PT2H30M");
+        assert_eq!(
+            parse("P0003-06-04T12:30:05.5").unwrap(),
+            "P3Y6M4DT12H30M5.5S"
+        );
+        assert_eq!(parse("PT1e3S").unwrap(), "PT1000S");
+        assert_eq!(
+            parse("P0.5M"),
+            Err(ExactDurationError::Inexact(Unit::Month))
+        );
+        assert_eq!(
+            parse("PT0.0000000001S"),
+            Err(ExactDurationError::Inexact(Unit::Second))
+        );
+        assert_eq!(
+            parse("P9223372036854775808D"),
+            Err(ExactDurationError::OutOfRange(Unit::Day))
+        );
+        assert!(matches!(parse("P1X"), Err(ExactDurationError::Parse(_))));
+    }
+
+    #[test]
+    fn float_conversions() {
+        let duration = Duration::new(1., 0., 0., 0.5, 0., 0.1);
+        let exact = ExactDuration::try_from(duration).unwrap();
+
+        assert_eq!(exact.to_string(), "P1YT30M0.1S");
+        assert_eq!(exact.nanosecond, 100_000_000);
+        assert_eq!(Duration::from(exact), "P1YT30M0.1S".parse().unwrap());
+        assert_eq!(
+            ExactDuration::try_from(Duration::new(0., 0., f32::NAN, 0., 0., 0.)),
+            Err(ExactDurationError::OutOfRange(Unit::Day))
+        );
+    }
+}
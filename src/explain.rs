@@ -0,0 +1,180 @@
+use time::OffsetDateTime;
+
+use crate::{
+    time_03::{add_time, add_year_month, whole_days},
+    Duration, Unit,
+};
+
+/// A step of [`Duration::add_explain`], applying some of the components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddStep {
+    /// The components applied by this step
+    pub units: Vec<Unit>,
+    /// The datetime before the step
+    pub from: OffsetDateTime,
+    /// The datetime after the step
+    pub to: OffsetDateTime,
+    /// The day of month which didn't exist in the target month and was
+    /// clamped to its last day, only set on the month step
+    pub clamped_from: Option<u8>,
+}
+
+impl AddStep {
+    /// Return the number of calendar days the date moved by
+    pub fn days_moved(&self) -> i64 {
+        (self.to.date() - self.from.date()).whole_days()
+    }
+}
+
+/// How [`Duration::add_explain`] got to its result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddExplanation {
+    /// The datetime the duration was added to
+    pub start: OffsetDateTime,
+    /// The same result as `start + duration`
+    pub result: OffsetDateTime,
+    /// The steps which moved the datetime, in the order they were applied:
+    /// years and months, then days, then the time components
+    pub steps: Vec<AddStep>,
+    /// The components whose fraction was dropped
+    pub truncated: Vec<Unit>,
+}
+
+impl AddExplanation {
+    /// Return `true` if the day was clamped to the end of a shorter month
+    pub fn clamped(&self) -> bool {
+        self.steps.iter().any(|step| step.clamped_from.is_some())
+    }
+
+    /// Return the step applying years and months, if any
+    pub fn month_step(&self) -> Option<&AddStep> {
+        self.steps
+            .iter()
+            .find(|step| step.units.contains(&Unit::Month) || step.units.contains(&Unit::Year))
+    }
+}
+
+impl Duration {
+    /// Add the duration to `datetime` like `+` does, recording each step.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, Unit};
+    /// use time::macros::datetime;
+    ///
+    /// let renewal: Duration = "P1M1D".parse().unwrap();
+    /// let start = datetime!(2023-01-31 09:00 UTC);
+    /// let explanation = renewal.add_explain(start);
+    /// assert_eq!(explanation.result, start + renewal);
+    /// assert!(explanation.clamped());
+    ///
+    /// let month = explanation.month_step().unwrap();
+    /// assert_eq!(month.units, [Unit::Month]);
+    /// assert_eq!(month.to, datetime!(2023-02-28 09:00 UTC));
+    /// assert_eq!(month.clamped_from, Some(31));
+    /// assert_eq!(month.days_moved(), 28);
+    /// ```
+    pub fn add_explain(&self, datetime: OffsetDateTime) -> AddExplanation {
+        let components = self.components();
+        let applied = |units: &[Unit], whole: bool| -> Vec<Unit> {
+            components
+                .iter()
+                .filter(|(unit, value)| {
+                    units.contains(unit) && if whole { value.trunc() } else { *value } != 0.
+                })
+                .map(|(unit, _)| *unit)
+                .collect()
+        };
+
+        let mut steps = Vec::new();
+        let mut current = datetime;
+
+        let units = applied(&[Unit::Year, Unit::Month], true);
+        if !units.is_empty() {
+            let to = add_year_month(current, self);
+            let day = current.day();
+            steps.push(AddStep {
+                units,
+                from: current,
+                to,
+                clamped_from: if to.day() != day { Some(day) } else { None },
+            });
+            current = to;
+        }
+
+        let units = applied(&[Unit::Day], true);
+        if !units.is_empty() {
+            let to = current.replace_date(current.date().saturating_add(whole_days(self.day)));
+            steps.push(AddStep {
+                units,
+                from: current,
+                to,
+                clamped_from: None,
+            });
+            current = to;
+        }
+
+        let units = applied(&[Unit::Hour, Unit::Minute, Unit::Second], false);
+        if !units.is_empty() {
+            let to = add_time(current, self);
+            steps.push(AddStep {
+                units,
+                from: current,
+                to,
+                clamped_from: None,
+            });
+            current = to;
+        }
+
+        AddExplanation {
+            start: datetime,
+            result: current,
+            steps,
+            truncated: components
+                .iter()
+                .filter(|(unit, value)| *unit != Unit::Second && value.fract() != 0.)
+                .map(|(unit, _)| *unit)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn explain() {
+        let start = datetime!(2024-01-31 22:00 UTC);
+        for input in &["P1Y1M", "P1.5DT2.5H", "PT0.5S", "P0D", "-P1MT1H", "P13M40D"] {
+            let duration: Duration = input.parse().unwrap();
+            assert_eq!(
+                duration.add_explain(start).result,
+                start + duration,
+                "{}",
+                input
+            );
+        }
+
+        let explanation = Duration::new(1., 1., 1.5, 2.5, 0., 0.).add_explain(start);
+        assert_eq!(
+            explanation
+                .steps
+                .iter()
+                .map(|step| step.units.clone())
+                .collect::<Vec<_>>(),
+            [
+                vec![Unit::Year, Unit::Month],
+                vec![Unit::Day],
+                vec![Unit::Hour]
+            ]
+        );
+        assert_eq!(explanation.truncated, [Unit::Day, Unit::Hour]);
+        assert!(explanation.clamped());
+        assert_eq!(explanation.month_step().unwrap().days_moved(), 366 + 28);
+
+        let explanation = Duration::ZERO.add_explain(start);
+        assert!(explanation.steps.is_empty());
+        assert_eq!(explanation.result, start);
+    }
+}
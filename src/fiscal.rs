@@ -0,0 +1,185 @@
+use std::convert::TryFrom;
+
+use time::{Date, Month, OffsetDateTime};
+
+use crate::time_03::add_time;
+use crate::Duration;
+
+/// A fiscal calendar, defined by the month its year starts in.
+///
+/// Fiscal years are labelled by the calendar year they start in, so with
+/// an April start, fiscal year 2024 runs from 2024-04-01 to 2025-03-31.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    year_start: Month,
+}
+
+impl FiscalCalendar {
+    /// Create a fiscal calendar whose year starts on the first of `year_start`
+    pub const fn new(year_start: Month) -> Self {
+        FiscalCalendar { year_start }
+    }
+
+    /// Return the fiscal year containing `date`
+    pub fn fiscal_year(&self, date: Date) -> i32 {
+        if date.month() as u8 >= self.year_start as u8 {
+            date.year()
+        } else {
+            date.year() - 1
+        }
+    }
+
+    /// Return the first day of the given fiscal year
+    pub fn year_start(&self, fiscal_year: i32) -> Option<Date> {
+        Date::from_calendar_date(fiscal_year, self.year_start, 1).ok()
+    }
+}
+
+fn first_of_month(year: i32, month_0_based: i64) -> Option<Date> {
+    let year = year.checked_add(i32::try_from(month_0_based.div_euclid(12)).ok()?)?;
+    let month = Month::try_from(month_0_based.rem_euclid(12) as u8 + 1).ok()?;
+
+    Date::from_calendar_date(year, month, 1).ok()
+}
+
+impl FiscalCalendar {
+    /// Return the number of fiscal months from the start of fiscal year 0
+    /// to the month containing `date`
+    fn period(&self, date: Date) -> i64 {
+        let month = (date.month() as i64 - self.year_start as i64).rem_euclid(12);
+        i64::from(self.fiscal_year(date)) * 12 + month
+    }
+
+    /// Return the first day of the month `period` fiscal months after the
+    /// start of fiscal year 0
+    fn period_start(&self, period: i64) -> Option<Date> {
+        let fiscal_year = i32::try_from(period.div_euclid(12)).ok()?;
+        first_of_month(
+            fiscal_year,
+            self.year_start as i64 - 1 + period.rem_euclid(12),
+        )
+    }
+}
+
+impl Duration {
+    /// Add the duration to `datetime`, with `year` and `month` counted in
+    /// fiscal periods of `calendar`.
+    ///
+    /// `year` lands on the start of a fiscal year, so `P1Y` from anywhere
+    /// in fiscal year 2024 gives the first day of fiscal year 2025. `month`
+    /// then moves by whole months from the start of that fiscal year, or
+    /// from the start of the current month without `year`: with an April
+    /// start, `P1Y2M` from 2024-05-20 gives 2025-06-01 and `P2M` gives
+    /// 2024-07-01. `day` and the time components are then added as usual,
+    /// and the time of day of `datetime` is kept.
+    ///
+    /// Returns `None` if `year`, `month` or `day` has a fraction, since a
+    /// fraction of a fiscal period has no start to land on, or if the
+    /// resulting date is out of range.
+    pub fn add_fiscal(
+        &self,
+        datetime: OffsetDateTime,
+        calendar: &FiscalCalendar,
+    ) -> Option<OffsetDateTime> {
+        if [self.year, self.month, self.day]
+            .iter()
+            .any(|value| value.fract() != 0.)
+        {
+            return None;
+        }
+
+        let date = datetime.date();
+        let years = self.year as i64;
+        let months = self.month as i64;
+
+        let date = if years == 0 && months == 0 {
+            date
+        } else {
+            let period = if years != 0 {
+                i64::from(calendar.fiscal_year(date))
+                    .checked_add(years)?
+                    .checked_mul(12)?
+            } else {
+                calendar.period(date)
+            };
+            calendar.period_start(period.checked_add(months)?)?
+        };
+
+        let date = date.checked_add(crate::time_03::whole_days(self.day))?;
+
+        Some(add_time(datetime.replace_date(date), self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn fiscal_year_of_date() {
+        let calendar = FiscalCalendar::new(Month::April);
+        assert_eq!(calendar.fiscal_year(date!(2024 - 04 - 01)), 2024);
+        assert_eq!(calendar.fiscal_year(date!(2025 - 03 - 31)), 2024);
+        assert_eq!(calendar.year_start(2024), Some(date!(2024 - 04 - 01)));
+    }
+
+    #[test]
+    fn add_fiscal_year() {
+        let calendar = FiscalCalendar::new(Month::April);
+        let duration: Duration = "P1Y".parse().unwrap();
+
+        assert_eq!(
+            duration.add_fiscal(datetime!(2024-04-01 00:00:00 UTC), &calendar),
+            Some(datetime!(2025-04-01 00:00:00 UTC))
+        );
+        assert_eq!(
+            duration.add_fiscal(datetime!(2025-02-14 09:00:00 UTC), &calendar),
+            Some(datetime!(2025-04-01 09:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_fiscal_months_and_days() {
+        let calendar = FiscalCalendar::new(Month::July);
+        let add = |duration: &str, datetime| {
+            let duration: Duration = duration.parse().unwrap();
+            duration.add_fiscal(datetime, &calendar)
+        };
+
+        // the start of fiscal year 2025, then two months and three days
+        assert_eq!(
+            add("P1Y2M3D", datetime!(2024-11-20 00:00:00 UTC)),
+            Some(datetime!(2025-09-04 00:00:00 UTC))
+        );
+        assert_eq!(
+            add("P2M", datetime!(2024-11-20 08:00:00 UTC)),
+            Some(datetime!(2025-01-01 08:00:00 UTC))
+        );
+        assert_eq!(
+            add("P-1Y6M", datetime!(2025-03-31 00:00:00 UTC)),
+            Some(datetime!(2024-01-01 00:00:00 UTC))
+        );
+        assert_eq!(
+            add("P-1M", datetime!(2024-07-15 00:00:00 UTC)),
+            Some(datetime!(2024-06-01 00:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn add_fiscal_fractions() {
+        let calendar = FiscalCalendar::new(Month::April);
+        let datetime = datetime!(2024-05-20 00:00:00 UTC);
+
+        for input in ["P0.5Y", "P1Y0.5M", "P1.5D"] {
+            let duration: Duration = input.parse().unwrap();
+            assert_eq!(duration.add_fiscal(datetime, &calendar), None, "{}", input);
+        }
+
+        let duration: Duration = "P1Y2MT1H30M".parse().unwrap();
+        assert_eq!(
+            duration.add_fiscal(datetime, &calendar),
+            Some(datetime!(2025-06-01 01:30:00 UTC))
+        );
+    }
+}
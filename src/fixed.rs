@@ -0,0 +1,330 @@
+use std::time::Duration as StdDuration;
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, one_of},
+    combinator::{map_opt, opt},
+    sequence::{pair, preceded, terminated, tuple},
+    Finish,
+};
+
+use crate::{
+    duration::{complete, expect, no_component, ParseResult},
+    ErrorReason, ParseDurationError,
+};
+
+/// Number of fixed-point units in one whole component.
+pub const FIXED_SCALE: u64 = 1_000_000_000;
+
+const FRACTION_DIGITS: usize = 9;
+
+/// A duration whose components are stored as fixed-point integers.
+///
+/// Each component holds its value multiplied by [`FIXED_SCALE`], so
+/// fractions are kept down to a billionth of the unit, e.g. `PT1.5S` has
+/// `second == 1_500_000_000`. Parsing, formatting and arithmetic only use
+/// integer operations, which makes this type usable on targets without a
+/// floating-point unit. Fractions with more than nine digits are rejected
+/// rather than rounded.
+///
+/// # Exactness
+///
+/// Any duration written with at most nine fractional digits per component
+/// is stored exactly, and formatting gives back the same digits, without
+/// trailing zeros. Additions, subtractions and multiplications are exact
+/// or return `None` on overflow, so `PT0.1S + PT0.2S` is exactly `PT0.3S`.
+/// Conversions to seconds are exact too, since a billionth of a day, an
+/// hour or a minute is a whole number of nanoseconds.
+///
+/// ```rust
+/// use iso8601_duration::FixedDuration;
+///
+/// let a = FixedDuration::parse("PT0.1S").unwrap();
+/// let b = FixedDuration::parse("PT0.2S").unwrap();
+/// assert_eq!(a.checked_add(&b), Some(FixedDuration::parse("PT0.3S").unwrap()));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct FixedDuration {
+    pub year: u64,
+    pub month: u64,
+    pub day: u64,
+    pub hour: u64,
+    pub minute: u64,
+    pub second: u64,
+}
+
+impl FixedDuration {
+    /// Parse given string into a `FixedDuration`
+    ///
+    /// ```rust
+    /// use iso8601_duration::{FixedDuration, FIXED_SCALE};
+    ///
+    /// let duration = FixedDuration::parse("P1DT0,25S").unwrap();
+    /// assert_eq!(duration.day, FIXED_SCALE);
+    /// assert_eq!(duration.second, FIXED_SCALE / 4);
+    /// assert_eq!(duration.to_string(), "P1DT0.25S");
+    /// ```
+    pub fn parse(input: &str) -> Result<FixedDuration, ParseDurationError> {
+        complete(preceded(
+            expect(ErrorReason::MissingPrefix, tag("P")),
+            alt((parse_week_format, parse_basic_format)),
+        ))(input)
+        .finish()
+        .map(|(_, duration)| duration)
+        .map_err(|err| ParseDurationError::new(input, err))
+    }
+
+    /// Add two durations component-wise, returning `None` on overflow
+    pub fn checked_add(&self, other: &FixedDuration) -> Option<FixedDuration> {
+        Some(FixedDuration {
+            year: self.year.checked_add(other.year)?,
+            month: self.month.checked_add(other.month)?,
+            day: self.day.checked_add(other.day)?,
+            hour: self.hour.checked_add(other.hour)?,
+            minute: self.minute.checked_add(other.minute)?,
+            second: self.second.checked_add(other.second)?,
+        })
+    }
+
+    /// Subtract `other` component-wise, returning `None` if any component
+    /// would become negative
+    pub fn checked_sub(&self, other: &FixedDuration) -> Option<FixedDuration> {
+        Some(FixedDuration {
+            year: self.year.checked_sub(other.year)?,
+            month: self.month.checked_sub(other.month)?,
+            day: self.day.checked_sub(other.day)?,
+            hour: self.hour.checked_sub(other.hour)?,
+            minute: self.minute.checked_sub(other.minute)?,
+            second: self.second.checked_sub(other.second)?,
+        })
+    }
+
+    /// Multiply every component by `n`, returning `None` on overflow
+    pub fn checked_mul(&self, n: u64) -> Option<FixedDuration> {
+        Some(FixedDuration {
+            year: self.year.checked_mul(n)?,
+            month: self.month.checked_mul(n)?,
+            day: self.day.checked_mul(n)?,
+            hour: self.hour.checked_mul(n)?,
+            minute: self.minute.checked_mul(n)?,
+            second: self.second.checked_mul(n)?,
+        })
+    }
+
+    /// Return the exact number of nanoseconds in the duration
+    ///
+    /// Returns `None` if the duration contains years or months.
+    pub fn total_nanos(&self) -> Option<u128> {
+        if self.year != 0 || self.month != 0 {
+            return None;
+        }
+
+        Some(
+            self.day as u128 * 86400
+                + self.hour as u128 * 3600
+                + self.minute as u128 * 60
+                + self.second as u128,
+        )
+    }
+
+    /// Convert duration to `std::time::Duration`.
+    ///
+    /// Returns `None` if the duration contains years or months.
+    pub fn to_std(&self) -> Option<StdDuration> {
+        let nanos = self.total_nanos()?;
+        let scale = FIXED_SCALE as u128;
+
+        Some(StdDuration::new(
+            u64::try_from(nanos / scale).ok()?,
+            (nanos % scale) as u32,
+        ))
+    }
+}
+
+impl FromStr for FixedDuration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FixedDuration::parse(s)
+    }
+}
+
+fn write_component(f: &mut fmt::Formatter<'_>, value: u64, designator: char) -> fmt::Result {
+    if value == 0 {
+        return Ok(());
+    }
+
+    write!(f, "{}", value / FIXED_SCALE)?;
+    let mut fraction = value % FIXED_SCALE;
+    if fraction != 0 {
+        let mut width = FRACTION_DIGITS;
+        while fraction.is_multiple_of(10) {
+            fraction /= 10;
+            width -= 1;
+        }
+        write!(f, ".{:0width$}", fraction, width = width)?;
+    }
+    write!(f, "{}", designator)
+}
+
+impl fmt::Display for FixedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == FixedDuration::default() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+        write_component(f, self.year, 'Y')?;
+        write_component(f, self.month, 'M')?;
+        write_component(f, self.day, 'D')?;
+        if self.hour != 0 || self.minute != 0 || self.second != 0 {
+            f.write_str("T")?;
+        }
+        write_component(f, self.hour, 'H')?;
+        write_component(f, self.minute, 'M')?;
+        write_component(f, self.second, 'S')
+    }
+}
+
+fn fixed_number(input: &str) -> ParseResult<'_, u64> {
+    map_opt(
+        pair(digit1, opt(preceded(one_of(".,"), digit1))),
+        |(whole, fraction): (&str, Option<&str>)| {
+            let mut value = whole
+                .bytes()
+                .try_fold(0u64, |acc, b| {
+                    acc.checked_mul(10)?.checked_add((b - b'0') as u64)
+                })?
+                .checked_mul(FIXED_SCALE)?;
+
+            if let Some(fraction) = fraction {
+                if fraction.len() > FRACTION_DIGITS {
+                    return None;
+                }
+                let mut unit = FIXED_SCALE;
+                for b in fraction.bytes() {
+                    unit /= 10;
+                    value = value.checked_add((b - b'0') as u64 * unit)?;
+                }
+            }
+
+            Some(value)
+        },
+    )(input)
+}
+
+fn value_with_designator(designator: &str) -> impl Fn(&str) -> ParseResult<'_, u64> + '_ {
+    move |input| terminated(fixed_number, tag(designator))(input)
+}
+
+fn parse_basic_format(input: &str) -> ParseResult<'_, FixedDuration> {
+    let (input, (year, month, day)) = tuple((
+        opt(value_with_designator("Y")),
+        opt(value_with_designator("M")),
+        opt(value_with_designator("D")),
+    ))(input)?;
+
+    let (input, time) = opt(preceded(
+        tag("T"),
+        tuple((
+            opt(value_with_designator("H")),
+            opt(value_with_designator("M")),
+            opt(value_with_designator("S")),
+        )),
+    ))(input)?;
+
+    let (hour, minute, second) = time.unwrap_or_default();
+
+    if [year, month, day, hour, minute, second]
+        .iter()
+        .all(Option::is_none)
+    {
+        no_component(input)
+    } else {
+        Ok((
+            input,
+            FixedDuration {
+                year: year.unwrap_or_default(),
+                month: month.unwrap_or_default(),
+                day: day.unwrap_or_default(),
+                hour: hour.unwrap_or_default(),
+                minute: minute.unwrap_or_default(),
+                second: second.unwrap_or_default(),
+            },
+        ))
+    }
+}
+
+fn parse_week_format(input: &str) -> ParseResult<'_, FixedDuration> {
+    let (input, week) = map_opt(value_with_designator("W"), |week| week.checked_mul(7))(input)?;
+
+    Ok((
+        input,
+        FixedDuration {
+            day: week,
+            ..FixedDuration::default()
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        let duration = FixedDuration::parse("P1Y2.5MT4H0.000000001S").unwrap();
+        assert_eq!(duration.month, 2_500_000_000);
+        assert_eq!(duration.second, 1);
+        assert_eq!(duration.to_string(), "P1Y2.5MT4H0.000000001S");
+
+        assert_eq!(FixedDuration::parse("P2W").unwrap().day, 14 * FIXED_SCALE);
+        assert_eq!(FixedDuration::default().to_string(), "PT0S");
+        assert!(FixedDuration::parse("PT0.0000000001S").is_err());
+        assert!(FixedDuration::parse("P99999999999Y").is_err());
+    }
+
+    #[test]
+    fn to_std() {
+        let duration = FixedDuration::parse("P1DT1.5S").unwrap();
+        assert_eq!(
+            duration.to_std(),
+            Some(StdDuration::new(86401, 500_000_000))
+        );
+        assert_eq!(FixedDuration::parse("P1M").unwrap().to_std(), None);
+
+        let sum = duration.checked_add(&duration).unwrap();
+        assert_eq!(sum.to_string(), "P2DT3S");
+    }
+
+    #[test]
+    fn exact_round_trip() {
+        for input in [
+            "PT0.1S",
+            "PT0.000000001S",
+            "P1.123456789D",
+            "P12345678901Y",
+            "PT0.999999999H59.5M",
+        ] {
+            assert_eq!(FixedDuration::parse(input).unwrap().to_string(), input);
+        }
+
+        let tenth = FixedDuration::parse("PT0.1S").unwrap();
+        assert_eq!(tenth.checked_mul(10).unwrap().to_string(), "PT1S");
+        assert_eq!(
+            tenth.checked_mul(3).unwrap().checked_sub(&tenth).unwrap(),
+            FixedDuration::parse("PT0.2S").unwrap()
+        );
+        assert_eq!(tenth.checked_sub(&tenth.checked_mul(2).unwrap()), None);
+        assert_eq!(tenth.checked_mul(u64::MAX), None);
+
+        assert_eq!(
+            FixedDuration::parse("PT0.000000001M")
+                .unwrap()
+                .total_nanos(),
+            Some(60)
+        );
+    }
+}
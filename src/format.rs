@@ -0,0 +1,56 @@
+use std::{error::Error, fmt};
+
+use crate::{Duration, Unit};
+
+/// Error returned by [`Duration::try_to_string`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum FormatError {
+    /// A component is NaN or infinite, which can't be written in ISO8601.
+    NonFinite { unit: Unit, value: f32 },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::NonFinite { unit, value } => {
+                write!(
+                    f,
+                    "{:?} component is {}, which can't be formatted",
+                    unit, value
+                )
+            }
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+impl Duration {
+    /// Format the duration as an ISO8601 string, failing on the first
+    /// NaN or infinite component instead of writing
+    /// [`Duration::NON_FINITE_PLACEHOLDER`] as `Display` does.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, FormatError, Unit};
+    ///
+    /// assert_eq!(Duration::days(3).try_to_string(), Ok("P3D".to_string()));
+    /// assert_eq!(
+    ///     Duration::new(0., 0., 0., 0., f32::INFINITY, 0.).try_to_string(),
+    ///     Err(FormatError::NonFinite { unit: Unit::Minute, value: f32::INFINITY })
+    /// );
+    /// ```
+    pub fn try_to_string(&self) -> Result<String, FormatError> {
+        match self
+            .components()
+            .iter()
+            .find(|(_, value)| !value.is_finite())
+        {
+            Some((unit, value)) => Err(FormatError::NonFinite {
+                unit: *unit,
+                value: *value,
+            }),
+            None => Ok(self.to_string()),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use time::OffsetDateTime;
+
+use crate::Duration;
+
+impl Duration {
+    /// Return the delay in whole seconds for a `Retry-After` header, with
+    /// calendar components resolved at `anchor`, usually the response time.
+    ///
+    /// The value is rounded up, so clients never retry too early.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let duration: Duration = "PT1M0.2S".parse().unwrap();
+    /// let now = datetime!(2023-01-31 00:00:00 UTC);
+    /// assert_eq!(duration.to_retry_after_at(now), 61);
+    /// ```
+    pub fn to_retry_after_at(&self, anchor: OffsetDateTime) -> u64 {
+        let span = anchor + *self - anchor;
+        let seconds = span.whole_seconds().max(0) as u64;
+
+        if span.subsec_nanoseconds() > 0 {
+            seconds + 1
+        } else {
+            seconds
+        }
+    }
+
+    /// Return the lifetime in whole seconds for a `Cache-Control: max-age`
+    /// directive, with calendar components resolved at `anchor`, usually
+    /// the response time.
+    ///
+    /// The value is rounded down, so responses are never cached for
+    /// longer than configured. `P1M` served in February gives a shorter
+    /// `max-age` than in March.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let duration: Duration = "P1M".parse().unwrap();
+    /// let now = datetime!(2023-02-01 00:00:00 UTC);
+    /// assert_eq!(duration.to_cache_control_max_age(now), 28 * 86400);
+    /// ```
+    pub fn to_cache_control_max_age(&self, anchor: OffsetDateTime) -> u64 {
+        (anchor + *self - anchor).whole_seconds().max(0) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn header_seconds() {
+        let anchor = datetime!(2023-03-31 12:00:00 UTC);
+
+        let duration: Duration = "P1MT0.5S".parse().unwrap();
+        assert_eq!(duration.to_retry_after_at(anchor), 30 * 86400 + 1);
+        assert_eq!(duration.to_cache_control_max_age(anchor), 30 * 86400);
+
+        let duration: Duration = "PT30S".parse().unwrap();
+        assert_eq!(duration.to_retry_after_at(anchor), 30);
+        assert_eq!(duration.to_cache_control_max_age(anchor), 30);
+    }
+}
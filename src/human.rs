@@ -0,0 +1,249 @@
+use std::fmt::Write;
+
+use crate::{Duration, English, Unit};
+
+/// A table of unit names for [`Duration::format_human`].
+///
+/// Implement this trait to format durations in another language:
+///
+/// ```rust
+/// use iso8601_duration::{Duration, HumanFormat, Unit, UnitNames};
+///
+/// struct French;
+///
+/// impl UnitNames for French {
+///     fn name(&self, unit: Unit, value: f32) -> &str {
+///         let plural = value.abs() >= 2.;
+///         match unit {
+///             Unit::Year if plural => "ans",
+///             Unit::Year => "an",
+///             Unit::Month => "mois",
+///             Unit::Day if plural => "jours",
+///             Unit::Day => "jour",
+///             Unit::Hour if plural => "heures",
+///             Unit::Hour => "heure",
+///             Unit::Minute if plural => "minutes",
+///             Unit::Minute => "minute",
+///             _ if plural => "secondes",
+///             _ => "seconde",
+///         }
+///     }
+///
+///     fn abbreviation(&self, unit: Unit) -> &str {
+///         match unit {
+///             Unit::Year => "a",
+///             Unit::Month => "mois",
+///             Unit::Day => "j",
+///             Unit::Hour => "h",
+///             Unit::Minute => "min",
+///             _ => "s",
+///         }
+///     }
+/// }
+///
+/// let duration: Duration = "P1Y2M3D".parse().unwrap();
+/// let format = HumanFormat::new().with_names(&French);
+/// assert_eq!(duration.format_human(&format), "1 an, 2 mois, 3 jours");
+/// ```
+pub trait UnitNames {
+    /// Return the name of `unit` when following `value`, as in "3 days"
+    fn name(&self, unit: Unit, value: f32) -> &str;
+
+    /// Return the abbreviation of `unit`, as in "3d"
+    fn abbreviation(&self, unit: Unit) -> &str;
+
+    /// Return the text between components written with [`UnitNames::name`]
+    fn separator(&self) -> &str {
+        ", "
+    }
+}
+
+impl UnitNames for English {
+    fn name(&self, unit: Unit, value: f32) -> &str {
+        let singular = value == 1. || value == -1.;
+        match unit {
+            Unit::Year if singular => "year",
+            Unit::Year => "years",
+            #[cfg(feature = "extensions")]
+            Unit::Quarter if singular => "quarter",
+            #[cfg(feature = "extensions")]
+            Unit::Quarter => "quarters",
+            Unit::Month if singular => "month",
+            Unit::Month => "months",
+            Unit::Day if singular => "day",
+            Unit::Day => "days",
+            Unit::Hour if singular => "hour",
+            Unit::Hour => "hours",
+            Unit::Minute if singular => "minute",
+            Unit::Minute => "minutes",
+            Unit::Second if singular => "second",
+            Unit::Second => "seconds",
+        }
+    }
+
+    fn abbreviation(&self, unit: Unit) -> &str {
+        match unit {
+            Unit::Year => "y",
+            #[cfg(feature = "extensions")]
+            Unit::Quarter => "q",
+            Unit::Month => "mo",
+            Unit::Day => "d",
+            Unit::Hour => "h",
+            Unit::Minute => "m",
+            Unit::Second => "s",
+        }
+    }
+}
+
+/// Options of [`Duration::format_human`].
+#[derive(Clone, Copy)]
+pub struct HumanFormat<'a> {
+    /// Write at most this many components, the largest ones
+    pub max_units: Option<usize>,
+    /// Write "1y 2mo" instead of "1 year, 2 months"
+    pub abbreviated: bool,
+    /// The unit names, [`English`] by default
+    pub names: &'a dyn UnitNames,
+}
+
+impl Default for HumanFormat<'_> {
+    fn default() -> Self {
+        HumanFormat::new()
+    }
+}
+
+impl<'a> HumanFormat<'a> {
+    /// Write every component with its English name
+    pub const fn new() -> Self {
+        HumanFormat {
+            max_units: None,
+            abbreviated: false,
+            names: &English,
+        }
+    }
+
+    /// Write at most `max_units` components
+    pub const fn with_max_units(self, max_units: usize) -> Self {
+        HumanFormat {
+            max_units: Some(max_units),
+            ..self
+        }
+    }
+
+    /// Write abbreviated units
+    pub const fn abbreviated(self) -> Self {
+        HumanFormat {
+            abbreviated: true,
+            ..self
+        }
+    }
+
+    /// Use `names` for the units
+    pub const fn with_names<'b>(self, names: &'b dyn UnitNames) -> HumanFormat<'b> {
+        HumanFormat {
+            max_units: self.max_units,
+            abbreviated: self.abbreviated,
+            names,
+        }
+    }
+}
+
+impl Duration {
+    /// Format as English text, such as "1 year, 2 months, 3 days", see
+    /// [`Duration::format_human`]
+    pub fn humanize(&self) -> String {
+        self.format_human(&HumanFormat::new())
+    }
+
+    /// Format as text for people, skipping the zero components.
+    ///
+    /// With `max_units`, the smaller components are dropped, not rounded.
+    /// An empty duration is written as zero seconds.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, HumanFormat};
+    ///
+    /// let duration: Duration = "P1Y2M3DT1H".parse().unwrap();
+    /// assert_eq!(duration.humanize(), "1 year, 2 months, 3 days, 1 hour");
+    ///
+    /// let format = HumanFormat::new().abbreviated().with_max_units(3);
+    /// assert_eq!(duration.format_human(&format), "1y 2mo 3d");
+    ///
+    /// assert_eq!(Duration::ZERO.humanize(), "0 seconds");
+    /// assert_eq!("PT1.5S".parse::<Duration>().unwrap().humanize(), "1.5 seconds");
+    /// ```
+    pub fn format_human(&self, format: &HumanFormat<'_>) -> String {
+        let components = self.components();
+        let mut components: Vec<(Unit, f32)> = components
+            .iter()
+            .copied()
+            .filter(|(_, value)| *value != 0.)
+            .take(format.max_units.unwrap_or(usize::MAX))
+            .collect();
+        if components.is_empty() {
+            components.push((Unit::Second, 0.));
+        }
+
+        let mut output = String::new();
+        for (i, (unit, value)) in components.into_iter().enumerate() {
+            // writing to a `String` can't fail
+            if format.abbreviated {
+                if i > 0 {
+                    output.push(' ');
+                }
+                write_value(&mut output, value);
+                output.push_str(format.names.abbreviation(unit));
+            } else {
+                if i > 0 {
+                    output.push_str(format.names.separator());
+                }
+                write_value(&mut output, value);
+                output.push(' ');
+                output.push_str(format.names.name(unit, value));
+            }
+        }
+        output
+    }
+}
+
+fn write_value(output: &mut String, value: f32) {
+    if value.abs() < 16_777_216. && value == value as i32 as f32 {
+        let _ = write!(output, "{}", value as i32);
+    } else {
+        let _ = write!(output, "{}", value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human() {
+        let duration = Duration::new(0., 1., 0., 0., 1., 30.);
+        assert_eq!(duration.humanize(), "1 month, 1 minute, 30 seconds");
+        assert_eq!(
+            duration.format_human(&HumanFormat::new().with_max_units(2)),
+            "1 month, 1 minute"
+        );
+        assert_eq!(
+            duration.format_human(&HumanFormat::new().abbreviated()),
+            "1mo 1m 30s"
+        );
+        assert_eq!(
+            Duration::ZERO.format_human(&HumanFormat::new().abbreviated()),
+            "0s"
+        );
+        assert_eq!(
+            Duration::ZERO.format_human(&HumanFormat::new().with_max_units(0)),
+            "0 seconds"
+        );
+
+        let duration: Duration = "-P1DT2H".parse().unwrap();
+        assert_eq!(duration.humanize(), "-1 day, -2 hours");
+        assert_eq!(
+            Duration::new(0., 0., 0.5, 0., 0., 0.).humanize(),
+            "0.5 days"
+        );
+    }
+}
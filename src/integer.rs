@@ -0,0 +1,282 @@
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+/// A duration whose components are whole numbers.
+///
+/// This type only uses integer operations and `core`, so with the
+/// default features disabled and the `core` feature enabled, it's all the
+/// crate compiles to: a small parser and formatter for `no_std` targets
+/// such as eBPF programs or WebAssembly filters, which only need to
+/// validate and compare durations.
+///
+/// Fractions and signs are rejected. Weeks are accepted on their own, as
+/// in `P3W`, and stored as days.
+///
+/// Durations are ordered by their nominal length in seconds, with 365
+/// days per year and 30 days per month, see
+/// [`IntegerDuration::nominal_seconds`], then by their components,
+/// largest unit first.
+///
+/// ```rust
+/// use iso8601_duration::IntegerDuration;
+///
+/// let day: IntegerDuration = "P1D".parse().unwrap();
+/// let hours: IntegerDuration = "PT25H".parse().unwrap();
+/// assert!(day < hours);
+/// assert_eq!(day.to_string(), "P1D");
+/// assert!(IntegerDuration::parse("PT1.5S").is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntegerDuration {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+/// Error returned when parsing an [`IntegerDuration`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerParseError {
+    /// Byte offset of the offending character, or the length of the input
+    /// when it ends too early
+    pub position: usize,
+}
+
+impl fmt::Display for IntegerParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid duration at position {}", self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IntegerParseError {}
+
+impl IntegerDuration {
+    /// Create a new duration
+    pub const fn new(year: u32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Self {
+        IntegerDuration {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Parse given string into an `IntegerDuration`
+    pub fn parse(input: &str) -> Result<IntegerDuration, IntegerParseError> {
+        let bytes = input.as_bytes();
+        let error = |position| Err(IntegerParseError { position });
+
+        if bytes.first() != Some(&b'P') {
+            return error(0);
+        }
+
+        let mut duration = IntegerDuration::default();
+        let mut position = 1;
+        let mut in_time = false;
+        // index of the next designator allowed in the current part
+        let mut next = 0;
+        let mut components = 0;
+        let mut week = None;
+
+        while position < bytes.len() {
+            if bytes[position] == b'T' && !in_time {
+                in_time = true;
+                next = 0;
+                position += 1;
+                if position == bytes.len() {
+                    return error(position);
+                }
+                continue;
+            }
+
+            let start = position;
+            let mut value: u32 = 0;
+            while let Some(digit) = bytes.get(position).filter(|b| b.is_ascii_digit()) {
+                value = match value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add((digit - b'0') as u32))
+                {
+                    Some(value) => value,
+                    None => return error(start),
+                };
+                position += 1;
+            }
+            if position == start || position == bytes.len() {
+                return error(position);
+            }
+
+            let designators: &[u8] = if in_time { b"HMS" } else { b"YMWD" };
+            let index = match designators[next..]
+                .iter()
+                .position(|d| *d == bytes[position])
+            {
+                Some(offset) => next + offset,
+                None => return error(position),
+            };
+            let field = match (in_time, designators[index]) {
+                (false, b'Y') => &mut duration.year,
+                (false, b'M') => &mut duration.month,
+                (false, b'W') => {
+                    week = Some(position);
+                    value = match value.checked_mul(7) {
+                        Some(value) => value,
+                        None => return error(start),
+                    };
+                    &mut duration.day
+                }
+                (false, _) => &mut duration.day,
+                (true, b'H') => &mut duration.hour,
+                (true, b'M') => &mut duration.minute,
+                (true, _) => &mut duration.second,
+            };
+            *field = value;
+
+            next = index + 1;
+            components += 1;
+            position += 1;
+        }
+
+        match week {
+            _ if components == 0 => error(bytes.len()),
+            Some(position) if components > 1 => error(position),
+            _ => Ok(duration),
+        }
+    }
+
+    /// Return the nominal number of seconds in the duration, counting 365
+    /// days per year and 30 days per month
+    pub const fn nominal_seconds(&self) -> u64 {
+        let days = self.year as u64 * 365 + self.month as u64 * 30 + self.day as u64;
+
+        days * 86400 + self.hour as u64 * 3600 + self.minute as u64 * 60 + self.second as u64
+    }
+}
+
+impl PartialOrd for IntegerDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IntegerDuration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |d: &IntegerDuration| {
+            (
+                d.nominal_seconds(),
+                d.year,
+                d.month,
+                d.day,
+                d.hour,
+                d.minute,
+                d.second,
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+impl FromStr for IntegerDuration {
+    type Err = IntegerParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IntegerDuration::parse(s)
+    }
+}
+
+impl fmt::Display for IntegerDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_component(
+            f: &mut fmt::Formatter<'_>,
+            value: u32,
+            designator: char,
+        ) -> fmt::Result {
+            if value == 0 {
+                return Ok(());
+            }
+            write!(f, "{}{}", value, designator)
+        }
+
+        if *self == IntegerDuration::default() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+        write_component(f, self.year, 'Y')?;
+        write_component(f, self.month, 'M')?;
+        write_component(f, self.day, 'D')?;
+        if self.hour != 0 || self.minute != 0 || self.second != 0 {
+            f.write_str("T")?;
+        }
+        write_component(f, self.hour, 'H')?;
+        write_component(f, self.minute, 'M')?;
+        write_component(f, self.second, 'S')
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<IntegerDuration> for crate::Duration {
+    fn from(duration: IntegerDuration) -> Self {
+        crate::Duration::new(
+            duration.year as f32,
+            duration.month as f32,
+            duration.day as f32,
+            duration.hour as f32,
+            duration.minute as f32,
+            duration.second as f32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        for input in ["PT0S", "P1Y2M3DT4H5M6S", "P1M", "PT1M", "P3DT12H"] {
+            let duration = IntegerDuration::parse(input).unwrap();
+            assert_eq!(std::format!("{}", duration), input);
+        }
+        assert_eq!(
+            IntegerDuration::parse("P2W"),
+            Ok(IntegerDuration::new(0, 0, 14, 0, 0, 0))
+        );
+
+        let position = |input: &str| IntegerDuration::parse(input).unwrap_err().position;
+        assert_eq!(position("1D"), 0);
+        assert_eq!(position("P"), 1);
+        assert_eq!(position("PT"), 2);
+        assert_eq!(position("P1"), 2);
+        assert_eq!(position("P1.5D"), 2);
+        assert_eq!(position("P1D2Y"), 4);
+        assert_eq!(position("P1Y1Y"), 4);
+        assert_eq!(position("P1DT2D"), 5);
+        assert_eq!(position("P1Y2W"), 4);
+        assert_eq!(position("PT1HT1M"), 4);
+        assert_eq!(position("P99999999999D"), 1);
+    }
+
+    #[test]
+    fn nominal_order() {
+        let parse = |input: &str| IntegerDuration::parse(input).unwrap();
+
+        assert_eq!(
+            parse("P1Y1M1DT1H1M1S").nominal_seconds(),
+            396 * 86400 + 3661
+        );
+        assert!(parse("P1M") < parse("P31D"));
+        assert!(parse("P1M") > parse("P29D"));
+        assert!(parse("P30D") < parse("P1M"));
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::Duration::from(parse("P1DT2H")),
+            crate::Duration::new(0., 0., 1., 2., 0., 0.)
+        );
+    }
+}
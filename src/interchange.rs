@@ -0,0 +1,394 @@
+use crate::{Duration, ParseDurationError};
+
+const NANOS_PER_SECOND: f64 = 1_000_000_000.;
+const NANOS_PER_DAY: f64 = 24. * 60. * 60. * NANOS_PER_SECOND;
+
+/// A duration split into whole months, whole days and nanoseconds.
+///
+/// This is the representation of interval values used by Arrow
+/// (`IntervalMonthDayNano`), and close to the ones of PostgreSQL, Avro
+/// and Cassandra, which only differ in the precision of the last field.
+/// It keeps the three kinds of lengths apart: months and days have no
+/// fixed length in seconds, while nanoseconds do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MonthDayNano {
+    pub months: i32,
+    pub days: i32,
+    pub nanoseconds: i64,
+}
+
+impl MonthDayNano {
+    /// Create a new month-day-nano triple
+    pub const fn new(months: i32, days: i32, nanoseconds: i64) -> Self {
+        MonthDayNano {
+            months,
+            days,
+            nanoseconds,
+        }
+    }
+}
+
+impl MonthDayNano {
+    /// Encode as the binary representation of a PostgreSQL `interval`:
+    /// microseconds as `i64`, then days and months as `i32`, big-endian.
+    ///
+    /// Nanoseconds are rounded to the nearest microsecond, halfway values
+    /// away from zero.
+    pub fn to_pg_interval(&self) -> [u8; 16] {
+        let microseconds = self.nanoseconds / 1000 + self.nanoseconds % 1000 / 500;
+
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&microseconds.to_be_bytes());
+        bytes[8..12].copy_from_slice(&self.days.to_be_bytes());
+        bytes[12..].copy_from_slice(&self.months.to_be_bytes());
+        bytes
+    }
+
+    /// Decode the binary representation of a PostgreSQL `interval`
+    ///
+    /// This method will return `None` if `bytes` isn't 16 bytes long, or if
+    /// the microseconds overflow as nanoseconds.
+    pub fn from_pg_interval(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 16 {
+            return None;
+        }
+
+        let mut microseconds = [0; 8];
+        microseconds.copy_from_slice(&bytes[..8]);
+        let mut days = [0; 4];
+        days.copy_from_slice(&bytes[8..12]);
+        let mut months = [0; 4];
+        months.copy_from_slice(&bytes[12..]);
+
+        Some(MonthDayNano::new(
+            i32::from_be_bytes(months),
+            i32::from_be_bytes(days),
+            i64::from_be_bytes(microseconds).checked_mul(1000)?,
+        ))
+    }
+}
+
+impl Duration {
+    /// Convert duration to its canonical `(months, days, nanoseconds)` triple.
+    ///
+    /// `year` is counted as 12 months, and a fractional `day` carries its
+    /// fraction over as 24 hours worth of nanoseconds. This method will
+    /// return `None` if `year` and `month` don't add up to a whole number
+    /// of months, or if a field overflows.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, MonthDayNano};
+    ///
+    /// let duration: Duration = "P1Y2M3DT4H5M6.5S".parse().unwrap();
+    /// assert_eq!(
+    ///     duration.to_month_day_nano(),
+    ///     Some(MonthDayNano::new(14, 3, 14_706_500_000_000))
+    /// );
+    /// assert_eq!(Duration::parse("P0.5M").unwrap().to_month_day_nano(), None);
+    /// ```
+    pub fn to_month_day_nano(&self) -> Option<MonthDayNano> {
+        let months = self.year as f64 * 12. + self.month as f64;
+        if months.fract() != 0. || months.abs() > i32::MAX as f64 {
+            return None;
+        }
+
+        let days = (self.day as f64).trunc();
+        if days.abs() > i32::MAX as f64 {
+            return None;
+        }
+
+        let nanoseconds = (self.day as f64).fract() * NANOS_PER_DAY
+            + (self.hour as f64 * 3600. + self.minute as f64 * 60. + self.second as f64)
+                * NANOS_PER_SECOND;
+        if !nanoseconds.is_finite() || nanoseconds.abs() > i64::MAX as f64 {
+            return None;
+        }
+
+        Some(MonthDayNano::new(
+            months as i32,
+            days as i32,
+            nanoseconds.round() as i64,
+        ))
+    }
+
+    /// Create a duration from its canonical `(months, days, nanoseconds)` triple.
+    ///
+    /// Months are split into `year` and `month`, and nanoseconds into
+    /// `hour`, `minute` and `second`. Note that `second` is stored as
+    /// `f32`, so sub-millisecond precision may be lost.
+    pub fn from_month_day_nano(value: MonthDayNano) -> Self {
+        let nanos_per_second = NANOS_PER_SECOND as i64;
+        let seconds = value.nanoseconds / nanos_per_second;
+        let nanoseconds = value.nanoseconds % nanos_per_second;
+
+        Duration::new(
+            (value.months / 12) as f32,
+            (value.months % 12) as f32,
+            value.days as f32,
+            (seconds / 3600) as f32,
+            (seconds % 3600 / 60) as f32,
+            (seconds % 60) as f32 + (nanoseconds as f64 / NANOS_PER_SECOND) as f32,
+        )
+    }
+}
+
+impl Duration {
+    /// Encode the duration as a PostgreSQL `interval` in the binary
+    /// protocol, see [`MonthDayNano::to_pg_interval`]
+    ///
+    /// This is what a driver sends for a bound `interval` parameter in the
+    /// binary format. In the text format, PostgreSQL accepts the ISO8601
+    /// string itself, and writes it back with `IntervalStyle` set to
+    /// `iso_8601`. This method will return `None` in the same cases as
+    /// [`Duration::to_month_day_nano`].
+    ///
+    /// With the `sqlx-postgres` feature, `Duration` implements the `Type`,
+    /// `Encode` and `Decode` traits of sqlx with this encoding, so it can
+    /// be bound and fetched directly as an `interval`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration: Duration = "P1Y2M3DT4H5M6.5S".parse().unwrap();
+    /// let bytes = duration.to_pg_interval().unwrap();
+    /// assert_eq!(bytes[12..], 14i32.to_be_bytes());
+    /// assert_eq!(Duration::from_pg_interval(&bytes), Some(duration));
+    /// ```
+    pub fn to_pg_interval(&self) -> Option<[u8; 16]> {
+        self.to_month_day_nano().map(|value| value.to_pg_interval())
+    }
+
+    /// Decode a PostgreSQL `interval` in the binary protocol, see
+    /// [`MonthDayNano::from_pg_interval`]
+    pub fn from_pg_interval(bytes: &[u8]) -> Option<Duration> {
+        MonthDayNano::from_pg_interval(bytes).map(Duration::from_month_day_nano)
+    }
+}
+
+impl Duration {
+    /// Split duration into the strings of a `java.time.Period` and of a
+    /// `java.time.Duration`, the way JVM services keep calendar and exact
+    /// lengths apart.
+    ///
+    /// The period holds `year`, `month` and the whole days, the duration
+    /// holds the time components, a fractional `day` carrying its fraction
+    /// over as hours. An empty part is written `P0D` or `PT0S`, as Java
+    /// does. This method will return `None` if `year`, `month` or `day`
+    /// isn't finite, or if `year` or `month` isn't whole, since `Period`
+    /// only has integer fields.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration: Duration = "P1Y2M3DT4H5M6S".parse().unwrap();
+    /// assert_eq!(
+    ///     duration.to_java_parts(),
+    ///     Some(("P1Y2M3D".to_string(), "PT4H5M6S".to_string()))
+    /// );
+    ///
+    /// let duration: Duration = "P1.5D".parse().unwrap();
+    /// assert_eq!(
+    ///     duration.to_java_parts(),
+    ///     Some(("P1D".to_string(), "PT12H".to_string()))
+    /// );
+    /// ```
+    pub fn to_java_parts(&self) -> Option<(String, String)> {
+        let calendar = [self.year, self.month, self.day];
+        if calendar.iter().any(|value| !value.is_finite())
+            || self.year.fract() != 0.
+            || self.month.fract() != 0.
+        {
+            return None;
+        }
+
+        let period = Duration::new(self.year, self.month, self.day.trunc(), 0., 0., 0.);
+        let period = if period == Duration::ZERO {
+            "P0D".to_string()
+        } else {
+            period.to_string()
+        };
+
+        let time = Duration::new(
+            0.,
+            0.,
+            0.,
+            self.hour + self.day.fract() * 24.,
+            self.minute,
+            self.second,
+        );
+
+        Some((period, time.to_string()))
+    }
+
+    /// Create a duration from the strings of a `java.time.Period` and of a
+    /// `java.time.Duration`, adding them up component-wise.
+    ///
+    /// The period may be written in weeks, as `Period.toString` does for
+    /// `Period.ofWeeks`, and the duration may hold days, as accepted by
+    /// `Duration.parse`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert_eq!(
+    ///     Duration::from_java_parts("P1Y2W", "PT36H").unwrap(),
+    ///     Duration::new(1., 0., 14., 36., 0., 0.)
+    /// );
+    /// ```
+    pub fn from_java_parts(period: &str, duration: &str) -> Result<Duration, ParseDurationError> {
+        let period = Duration::parse_lenient(period)?;
+        let duration = Duration::parse_lenient(duration)?;
+
+        Ok(Duration::new(
+            period.year + duration.year,
+            period.month + duration.month,
+            period.day + duration.day,
+            period.hour + duration.hour,
+            period.minute + duration.minute,
+            period.second + duration.second,
+        ))
+    }
+}
+
+impl Duration {
+    /// Encode the duration as 24 bytes, the bits of each component from
+    /// `year` to `second` in big-endian order.
+    ///
+    /// The encoding is the same on every platform and keeps the bits as
+    /// they are, `-0.0` and NaN payloads included, so
+    /// [`Duration::from_canonical_bytes`] gives back a bit-identical
+    /// duration. It is meant for hashing records in content-addressed or
+    /// reproducible pipelines.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration: Duration = "PT1.5S".parse().unwrap();
+    /// let bytes = duration.to_canonical_bytes();
+    /// assert_eq!(bytes[20..], [0x3f, 0xc0, 0x00, 0x00]);
+    /// assert_eq!(Duration::from_canonical_bytes(bytes), duration);
+    /// ```
+    pub fn to_canonical_bytes(&self) -> [u8; 24] {
+        let mut bytes = [0; 24];
+        for (chunk, (_, value)) in bytes.chunks_exact_mut(4).zip(self.components().iter()) {
+            chunk.copy_from_slice(&value.to_bits().to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a duration encoded with [`Duration::to_canonical_bytes`]
+    pub fn from_canonical_bytes(bytes: [u8; 24]) -> Duration {
+        let component = |i: usize| {
+            f32::from_bits(u32::from_be_bytes([
+                bytes[i * 4],
+                bytes[i * 4 + 1],
+                bytes[i * 4 + 2],
+                bytes[i * 4 + 3],
+            ]))
+        };
+
+        Duration::new(
+            component(0),
+            component(1),
+            component(2),
+            component(3),
+            component(4),
+            component(5),
+        )
+    }
+}
+
+impl From<MonthDayNano> for Duration {
+    fn from(value: MonthDayNano) -> Self {
+        Duration::from_month_day_nano(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let triple = MonthDayNano::new(27, 45, 3_723_500_000_000);
+        let duration = Duration::from_month_day_nano(triple);
+
+        assert_eq!(duration, Duration::new(2., 3., 45., 1., 2., 3.5));
+        assert_eq!(duration.to_month_day_nano(), Some(triple));
+    }
+
+    #[test]
+    fn fractional_components() {
+        let duration: Duration = "P0.5YT0.5S".parse().unwrap();
+        assert_eq!(
+            duration.to_month_day_nano(),
+            Some(MonthDayNano::new(6, 0, 500_000_000))
+        );
+
+        let duration: Duration = "P1.5D".parse().unwrap();
+        assert_eq!(
+            duration.to_month_day_nano(),
+            Some(MonthDayNano::new(0, 1, 12 * 3600 * 1_000_000_000))
+        );
+    }
+
+    #[test]
+    fn java_parts() {
+        assert_eq!(
+            Duration::ZERO.to_java_parts(),
+            Some(("P0D".to_string(), "PT0S".to_string()))
+        );
+        assert_eq!(Duration::parse("P0.5Y").unwrap().to_java_parts(), None);
+
+        let duration: Duration = "P1Y2M3DT4H5M6.5S".parse().unwrap();
+        let (period, time) = duration.to_java_parts().unwrap();
+        assert_eq!(Duration::from_java_parts(&period, &time), Ok(duration));
+
+        assert!(Duration::from_java_parts("P1Y", "1H").is_err());
+    }
+
+    #[test]
+    fn pg_interval() {
+        // SELECT interval '-1 mon 2 days -00:00:01.5'
+        let bytes = [
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xe9, 0x1c, 0xa0, 0, 0, 0, 2, 0xff, 0xff, 0xff, 0xff,
+        ];
+        let value = MonthDayNano::from_pg_interval(&bytes).unwrap();
+        assert_eq!(value, MonthDayNano::new(-1, 2, -1_500_000_000));
+        assert_eq!(value.to_pg_interval(), bytes);
+
+        assert_eq!(
+            MonthDayNano::new(0, 0, 1_500).to_pg_interval()[..8],
+            2i64.to_be_bytes()
+        );
+        assert_eq!(
+            MonthDayNano::new(0, 0, -1_499).to_pg_interval()[..8],
+            (-1i64).to_be_bytes()
+        );
+        assert_eq!(MonthDayNano::from_pg_interval(&bytes[1..]), None);
+        assert_eq!(
+            MonthDayNano::from_pg_interval(&[0x7f; 16]).map(|value| value.months),
+            None
+        );
+        assert_eq!(Duration::parse("P0.5M").unwrap().to_pg_interval(), None);
+    }
+
+    #[test]
+    fn canonical_bytes() {
+        let duration = Duration::new(-0., f32::NAN, 1., f32::MAX, f32::MIN_POSITIVE, 0.1);
+        let decoded = Duration::from_canonical_bytes(duration.to_canonical_bytes());
+
+        for ((_, a), (_, b)) in decoded
+            .components()
+            .iter()
+            .zip(duration.components().iter())
+        {
+            assert_eq!(a.to_bits(), b.to_bits());
+        }
+        assert_eq!(Duration::ZERO.to_canonical_bytes(), [0; 24]);
+        assert_eq!(
+            Duration::years(1).to_canonical_bytes()[..4],
+            [0x3f, 0x80, 0, 0]
+        );
+    }
+}
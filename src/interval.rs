@@ -0,0 +1,518 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{Duration, ParseDurationError};
+
+/// Error returned when parsing an [`Interval`] or a [`RepeatingInterval`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseIntervalError {
+    /// The input isn't `<start>/<end>`, `<start>/<duration>` or
+    /// `<duration>/<end>`, optionally preceded by `R[n]/` for repeating
+    /// intervals.
+    Format,
+    /// The number of repetitions after `R` isn't a number.
+    Repetitions,
+    /// A datetime isn't valid RFC 3339.
+    Datetime(time::error::Parse),
+    /// The duration isn't valid.
+    Duration(ParseDurationError),
+}
+
+impl fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIntervalError::Format => {
+                f.write_str("expected <start>/<end>, <start>/<duration> or <duration>/<end>")
+            }
+            ParseIntervalError::Repetitions => {
+                f.write_str("expected a number of repetitions after R")
+            }
+            ParseIntervalError::Datetime(err) => write!(f, "invalid datetime: {}", err),
+            ParseIntervalError::Duration(err) => write!(f, "invalid duration: {}", err),
+        }
+    }
+}
+
+impl Error for ParseIntervalError {}
+
+/// One side of an interval, as written.
+enum Part {
+    Datetime(OffsetDateTime),
+    Duration(Duration),
+}
+
+fn parse_part(input: &str) -> Result<Part, ParseIntervalError> {
+    if input.starts_with('P') || input.starts_with("-P") {
+        Duration::parse(input)
+            .map(Part::Duration)
+            .map_err(ParseIntervalError::Duration)
+    } else {
+        OffsetDateTime::parse(input, &Rfc3339)
+            .map(Part::Datetime)
+            .map_err(ParseIntervalError::Datetime)
+    }
+}
+
+/// Parse `<start>/<end>`, `<start>/<duration>` or `<duration>/<end>`
+fn parse_parts(start: &str, end: &str) -> Result<(Part, Part), ParseIntervalError> {
+    match (parse_part(start)?, parse_part(end)?) {
+        (Part::Duration(_), Part::Duration(_)) => Err(ParseIntervalError::Format),
+        parts => Ok(parts),
+    }
+}
+
+/// A time interval between two datetimes.
+///
+/// The end may be before the start, as when an interval is built from a
+/// negative duration to express a look-back window. [`Interval::normalized`]
+/// swaps such endpoints.
+///
+/// ```rust
+/// use iso8601_duration::{Duration, Interval};
+/// use time::macros::datetime;
+///
+/// let now = datetime!(2023-03-15 12:00:00 UTC);
+/// let last_week = Interval::from_start(now, "-P7D".parse().unwrap());
+/// assert_eq!(last_week.end, datetime!(2023-03-08 12:00:00 UTC));
+/// assert!(last_week.is_reversed());
+///
+/// let window = last_week.normalized();
+/// assert_eq!(window.start, datetime!(2023-03-08 12:00:00 UTC));
+/// assert_eq!(window.end, now);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+}
+
+impl Interval {
+    /// Create an interval between `start` and `end`
+    pub const fn new(start: OffsetDateTime, end: OffsetDateTime) -> Self {
+        Interval { start, end }
+    }
+
+    /// Create the interval covered by `duration` from `start`.
+    ///
+    /// The end is `start + duration`, so a negative duration gives an end
+    /// before the start.
+    pub fn from_start(start: OffsetDateTime, duration: Duration) -> Self {
+        Interval::new(start, start + duration)
+    }
+
+    /// Parse an ISO 8601 time interval, written `<start>/<end>`,
+    /// `<start>/<duration>` or `<duration>/<end>`, datetimes being RFC 3339.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Interval;
+    /// use time::macros::datetime;
+    ///
+    /// let interval = Interval::parse("P1M/2023-03-01T00:00:00Z").unwrap();
+    /// assert_eq!(
+    ///     interval.bounds(),
+    ///     (datetime!(2023-02-01 00:00:00 UTC), datetime!(2023-03-01 00:00:00 UTC))
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Interval, ParseIntervalError> {
+        let (start, end) = input.split_once('/').ok_or(ParseIntervalError::Format)?;
+
+        Ok(match parse_parts(start, end)? {
+            (Part::Datetime(start), Part::Datetime(end)) => Interval::new(start, end),
+            (Part::Datetime(start), Part::Duration(duration)) => {
+                Interval::from_start(start, duration)
+            }
+            (Part::Duration(duration), Part::Datetime(end)) => Interval::new(end - duration, end),
+            (Part::Duration(_), Part::Duration(_)) => unreachable!(),
+        })
+    }
+
+    /// Return the start and the end
+    pub fn bounds(&self) -> (OffsetDateTime, OffsetDateTime) {
+        (self.start, self.end)
+    }
+
+    /// Return whether the end is before the start
+    pub fn is_reversed(&self) -> bool {
+        self.end < self.start
+    }
+
+    /// Return the interval with its endpoints swapped if the end is
+    /// before the start
+    pub fn normalized(&self) -> Self {
+        if self.is_reversed() {
+            Interval::new(self.end, self.start)
+        } else {
+            *self
+        }
+    }
+
+    /// Return the exact span from the start to the end, negative if the
+    /// interval is reversed
+    pub fn length(&self) -> time::Duration {
+        self.end - self.start
+    }
+
+    /// Return whether `datetime` is within the normalized interval, the
+    /// start being included and the end excluded
+    pub fn contains(&self, datetime: OffsetDateTime) -> bool {
+        let interval = self.normalized();
+        interval.start <= datetime && datetime < interval.end
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Interval::parse(s)
+    }
+}
+
+/// Formats as `<start>/<end>`.
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.start.format(&Rfc3339).map_err(|_| fmt::Error)?;
+        let end = self.end.format(&Rfc3339).map_err(|_| fmt::Error)?;
+        write!(f, "{}/{}", start, end)
+    }
+}
+
+/// An ISO 8601 repeating interval, such as `R5/2023-01-01T00:00:00Z/P1D`.
+///
+/// The occurrences are the consecutive periods of `duration` from
+/// `anchor`. An interval written `<duration>/<end>` repeats backwards
+/// from its end, which is kept as a negative `duration` anchored at the
+/// end.
+///
+/// ```rust
+/// use iso8601_duration::RepeatingInterval;
+/// use time::macros::datetime;
+///
+/// let monthly = RepeatingInterval::parse("R3/2023-01-31T00:00:00Z/P1M").unwrap();
+/// let starts: Vec<_> = monthly.occurrences().map(|interval| interval.start).collect();
+/// assert_eq!(
+///     starts,
+///     [
+///         datetime!(2023-01-31 00:00:00 UTC),
+///         datetime!(2023-02-28 00:00:00 UTC),
+///         datetime!(2023-03-31 00:00:00 UTC),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RepeatingInterval {
+    /// The number of occurrences, or `None` for `R/`, which repeats
+    /// without end
+    pub repetitions: Option<u32>,
+    pub anchor: OffsetDateTime,
+    pub duration: Duration,
+}
+
+impl RepeatingInterval {
+    /// Parse an ISO 8601 repeating interval, written `R[n]/<interval>`
+    pub fn parse(input: &str) -> Result<RepeatingInterval, ParseIntervalError> {
+        let rest = input.strip_prefix('R').ok_or(ParseIntervalError::Format)?;
+        let (repetitions, rest) = rest.split_once('/').ok_or(ParseIntervalError::Format)?;
+        let repetitions = match repetitions {
+            "" => None,
+            n => Some(n.parse().map_err(|_| ParseIntervalError::Repetitions)?),
+        };
+        let (start, end) = rest.split_once('/').ok_or(ParseIntervalError::Format)?;
+
+        let (anchor, duration) = match parse_parts(start, end)? {
+            (Part::Datetime(start), Part::Datetime(end)) => (start, Duration::between(start, end)),
+            (Part::Datetime(start), Part::Duration(duration)) => (start, duration),
+            (Part::Duration(duration), Part::Datetime(end)) => (end, -duration),
+            (Part::Duration(_), Part::Duration(_)) => unreachable!(),
+        };
+
+        Ok(RepeatingInterval {
+            repetitions,
+            anchor,
+            duration,
+        })
+    }
+
+    /// Return the `n`-th occurrence, `0` being the first one
+    pub fn nth(&self, n: u32) -> Interval {
+        Interval::new(
+            self.duration.nth_from(self.anchor, n),
+            self.duration.nth_from(self.anchor, n.saturating_add(1)),
+        )
+        .normalized()
+    }
+
+    /// Return an iterator over the occurrences
+    pub fn occurrences(&self) -> Occurrences {
+        Occurrences {
+            interval: *self,
+            n: 0,
+        }
+    }
+
+    /// Return the earliest occurrence overlapping `window`, along with its
+    /// index, or `None` if no occurrence does.
+    ///
+    /// Occurrences are found by bisecting their indices, so a window years
+    /// after the anchor doesn't require walking the series from the start.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Interval, RepeatingInterval};
+    /// use time::macros::datetime;
+    ///
+    /// let weekly = RepeatingInterval::parse("R/2000-01-03T09:00:00Z/P7D").unwrap();
+    /// let march = Interval::new(
+    ///     datetime!(2024-03-01 00:00:00 UTC),
+    ///     datetime!(2024-04-01 00:00:00 UTC),
+    /// );
+    ///
+    /// let (n, first) = weekly.first_in(march).unwrap();
+    /// assert_eq!(n, 1260);
+    /// assert_eq!(first.start, datetime!(2024-02-26 09:00:00 UTC));
+    /// assert_eq!(weekly.last_in(march).unwrap().1.start, datetime!(2024-03-25 09:00:00 UTC));
+    /// ```
+    pub fn first_in(&self, window: Interval) -> Option<(u32, Interval)> {
+        let window = window.normalized();
+        let limit = self.repetitions.unwrap_or(u32::MAX);
+
+        let n = if self.is_forward() {
+            partition_point(limit, |n| self.nth(n).end > window.start)
+        } else {
+            partition_point(limit, |n| self.nth(n).end <= window.start).checked_sub(1)?
+        };
+        self.overlapping(n, window)
+    }
+
+    /// Return the latest occurrence overlapping `window`, along with its
+    /// index, or `None` if no occurrence does.
+    ///
+    /// See [`first_in`](RepeatingInterval::first_in).
+    pub fn last_in(&self, window: Interval) -> Option<(u32, Interval)> {
+        let window = window.normalized();
+        let limit = self.repetitions.unwrap_or(u32::MAX);
+
+        let n = if self.is_forward() {
+            partition_point(limit, |n| self.nth(n).start >= window.end).checked_sub(1)?
+        } else {
+            partition_point(limit, |n| self.nth(n).start < window.end)
+        };
+        self.overlapping(n, window)
+    }
+
+    /// Return whether the occurrences go forward in time
+    fn is_forward(&self) -> bool {
+        self.duration.nth_from(self.anchor, 1) >= self.anchor
+    }
+
+    /// Return the `n`-th occurrence if it exists and overlaps `window`
+    fn overlapping(&self, n: u32, window: Interval) -> Option<(u32, Interval)> {
+        let occurrence = self.nth(n);
+        let limit = self.repetitions.unwrap_or(u32::MAX);
+
+        if n < limit && occurrence.end > window.start && occurrence.start < window.end {
+            Some((n, occurrence))
+        } else {
+            None
+        }
+    }
+}
+
+/// Return the first `n` below `limit` for which `predicate` holds, or
+/// `limit` if there's none, `predicate` being false then true.
+fn partition_point(limit: u32, predicate: impl Fn(u32) -> bool) -> u32 {
+    let (mut low, mut high) = (0, limit);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    low
+}
+
+impl FromStr for RepeatingInterval {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RepeatingInterval::parse(s)
+    }
+}
+
+/// Iterator returned by [`RepeatingInterval::occurrences`].
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    interval: RepeatingInterval,
+    n: u32,
+}
+
+impl Iterator for Occurrences {
+    type Item = Interval;
+
+    fn next(&mut self) -> Option<Interval> {
+        let limit = self.interval.repetitions.unwrap_or(u32::MAX);
+        if self.n >= limit {
+            return None;
+        }
+
+        let interval = self.interval.nth(self.n);
+        self.n += 1;
+        Some(interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn boundary_occurrences() {
+        let window = |start, end| Interval::new(start, end);
+        let brute_force = |series: &RepeatingInterval, window: Interval| {
+            let window = window.normalized();
+            let overlapping: Vec<_> = series
+                .occurrences()
+                .take(1000)
+                .enumerate()
+                .filter(|(_, occurrence)| {
+                    occurrence.end > window.start && occurrence.start < window.end
+                })
+                .map(|(n, occurrence)| (n as u32, occurrence))
+                .collect();
+            (overlapping.first().copied(), overlapping.last().copied())
+        };
+
+        let windows = [
+            window(
+                datetime!(2023-02-15 00:00:00 UTC),
+                datetime!(2023-05-01 00:00:00 UTC),
+            ),
+            window(
+                datetime!(2023-03-31 00:00:00 UTC),
+                datetime!(2023-03-31 00:00:00 UTC),
+            ),
+            window(
+                datetime!(2020-01-01 00:00:00 UTC),
+                datetime!(2021-01-01 00:00:00 UTC),
+            ),
+            window(
+                datetime!(2025-01-01 00:00:00 UTC),
+                datetime!(2022-01-01 00:00:00 UTC),
+            ),
+        ];
+        for input in [
+            "R/2023-01-31T00:00:00Z/P1M",
+            "R5/2023-01-31T00:00:00Z/P1M",
+            "R/P1M/2023-06-30T00:00:00Z",
+            "R3/P1M/2023-06-30T00:00:00Z",
+            "R/2023-01-01T00:00:00Z/PT36H",
+        ] {
+            let series = RepeatingInterval::parse(input).unwrap();
+            for window in windows {
+                let (first, last) = brute_force(&series, window);
+                let (first, last) = match series.is_forward() {
+                    true => (first, last),
+                    false => (last, first),
+                };
+                assert_eq!(series.first_in(window), first, "{} {}", input, window);
+                assert_eq!(series.last_in(window), last, "{} {}", input, window);
+            }
+        }
+    }
+
+    #[test]
+    fn negative_duration() {
+        let start = datetime!(2023-03-31 00:00:00 UTC);
+        let interval = Interval::from_start(start, "-P1M".parse().unwrap());
+
+        assert_eq!(interval.end, datetime!(2023-02-28 00:00:00 UTC));
+        assert_eq!(interval.length(), -time::Duration::days(31));
+        assert_eq!(interval.normalized().length(), time::Duration::days(31));
+        assert_eq!(interval.normalized().normalized(), interval.normalized());
+        assert!(interval.contains(datetime!(2023-03-01 00:00:00 UTC)));
+        assert!(!interval.contains(start));
+
+        let forward = Interval::from_start(start, "P1D".parse().unwrap());
+        assert!(!forward.is_reversed());
+        assert_eq!(forward.normalized(), forward);
+    }
+
+    #[test]
+    fn parse_intervals() {
+        let start = datetime!(2023-01-01 00:00:00 UTC);
+        let end = datetime!(2023-02-01 00:00:00 UTC);
+
+        for input in [
+            "2023-01-01T00:00:00Z/2023-02-01T00:00:00Z",
+            "2023-01-01T00:00:00Z/P1M",
+            "P1M/2023-02-01T00:00:00Z",
+            "P31D/2023-02-01T01:00:00+01:00",
+        ] {
+            let interval: Interval = input.parse().unwrap();
+            assert_eq!(interval.bounds(), (start, end), "{}", input);
+        }
+        assert_eq!(
+            Interval::new(start, end).to_string(),
+            "2023-01-01T00:00:00Z/2023-02-01T00:00:00Z"
+        );
+
+        assert_eq!(Interval::parse("P1D"), Err(ParseIntervalError::Format));
+        assert_eq!(Interval::parse("P1D/P2D"), Err(ParseIntervalError::Format));
+        assert!(matches!(
+            Interval::parse("2023-01-01/P1D"),
+            Err(ParseIntervalError::Datetime(_))
+        ));
+        assert!(matches!(
+            Interval::parse("2023-01-01T00:00:00Z/P1X"),
+            Err(ParseIntervalError::Duration(_))
+        ));
+    }
+
+    #[test]
+    fn parse_repeating_intervals() {
+        let interval = RepeatingInterval::parse("R5/2023-01-01T00:00:00Z/P1D").unwrap();
+        assert_eq!(interval.repetitions, Some(5));
+        assert_eq!(interval.occurrences().count(), 5);
+        assert_eq!(
+            interval.occurrences().last(),
+            Some(Interval::new(
+                datetime!(2023-01-05 00:00:00 UTC),
+                datetime!(2023-01-06 00:00:00 UTC)
+            ))
+        );
+
+        let backwards = RepeatingInterval::parse("R2/PT1H/2023-01-01T00:00:00Z").unwrap();
+        let occurrences: Vec<_> = backwards.occurrences().collect();
+        assert_eq!(
+            occurrences,
+            [
+                Interval::new(
+                    datetime!(2022-12-31 23:00:00 UTC),
+                    datetime!(2023-01-01 00:00:00 UTC)
+                ),
+                Interval::new(
+                    datetime!(2022-12-31 22:00:00 UTC),
+                    datetime!(2022-12-31 23:00:00 UTC)
+                ),
+            ]
+        );
+
+        let endless =
+            RepeatingInterval::parse("R/2023-01-01T00:00:00Z/2023-01-08T00:00:00Z").unwrap();
+        assert_eq!(endless.repetitions, None);
+        assert_eq!(endless.duration, Duration::days(7));
+        assert_eq!(endless.occurrences().nth(1000).unwrap().start.year(), 2042);
+
+        assert_eq!(
+            RepeatingInterval::parse("Rx/2023-01-01T00:00:00Z/P1D"),
+            Err(ParseIntervalError::Repetitions)
+        );
+        assert_eq!(
+            RepeatingInterval::parse("2023-01-01T00:00:00Z/P1D"),
+            Err(ParseIntervalError::Format)
+        );
+    }
+}
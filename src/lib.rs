@@ -5,12 +5,14 @@
 //! # Installation
 //!
 //! ```toml
-//! iso8601-duration = "0.2.0"
+//! iso8601-duration = "0.3.0"
 //! ```
 //!
 //! # Usage
 //!
 //! ```rust
+//! # #[cfg(feature = "std")]
+//! # {
 //! use iso8601_duration::Duration;
 //!
 //!  assert_eq!(
@@ -26,6 +28,7 @@
 //!  assert!("P12WT12H30M5S".parse::<Duration>().is_err());
 //!  assert!("P0.5S0.5M".parse::<Duration>().is_err());
 //!  assert!("P0.5A".parse::<Duration>().is_err());
+//! # }
 //! ```
 //!
 //! # `year` and `month`
@@ -33,16 +36,24 @@
 //! `Duration` can be converted to either `std::time::Duration` or
 //! `chrono::Duration` by calling `to_std` or `to_chrono`.
 //!
-//! Both `to_std` and `to_chrono` will return `None` if the duration
-//! includes `year` and `month`. Because ISO8601 duration format allows
-//! the usage of `year` and `month`, and these durations are non-standard.
-//! Since months can have 28, 29 30, 31 days, and years can have either
-//! 365 or 366 days.
+//! `to_std` will return `Err(ToStdError::CalendarComponent)` and
+//! `to_chrono` will return `None` if the duration includes `year` and
+//! `month`. Because ISO8601 duration format allows the usage of `year` and
+//! `month`, and these durations are non-standard. Since months can have 28,
+//! 29 30, 31 days, and years can have either 365 or 366 days.
+//!
+//! **Breaking change in 0.3.0:** `to_std` and `to_std_checked` used to return
+//! an `Option`. They now return a `Result` whose `ToStdError` tells a
+//! calendar component apart from a negative or out of range duration. Code
+//! matching on `None` should match on `Err(_)` instead, or call `.ok()`. See
+//! the changelog for the other changes and how to migrate.
 //!
 //! To perform a lossless conversion, a starting date must be specified:
 //!
 //! ```rust
 //! // requires `chrono` feature
+//! # #[cfg(feature = "chrono")]
+//! # {
 //!
 //! use iso8601_duration::Duration;
 //! use chrono::DateTime;
@@ -53,14 +64,296 @@
 //!     one_month.to_chrono_at_datetime(date).num_days(),
 //!     29 // 2000 is a leap year
 //! );
+//! # }
+//! ```
+//!
+//! # Logging
+//!
+//! `Duration` implements `Display` by writing each component straight to
+//! the formatter, without any intermediate allocation, so it can be
+//! recorded as a structured field with the `%` sigil of `tracing`:
+//!
+//! ```rust
+//! # #[cfg(feature = "std")]
+//! # {
+//! use iso8601_duration::Duration;
+//!
+//! let retry_interval: Duration = "PT30S".parse().unwrap();
+//! tracing::info!(%retry_interval, "scheduling retry");
+//! # }
 //! ```
+//!
+//! The `tracing` feature adds `Duration::as_value`, which returns the same
+//! field value for use without the sigil, e.g. in
+//! `tracing::info!(retry_interval = retry_interval.as_value())`.
+//!
+//! # Validation
+//!
+//! The `validator` feature lets the `validator` crate check `Duration`
+//! fields against a range, comparing nominal lengths like `DurationRange`,
+//! and string fields with `ValidateIso8601Duration`. `ParseDurationError`
+//! and `RangeError` convert to `ValidationError`, for custom validators:
+//!
+//! ```rust
+//! // requires `validator` feature
+//! # #[cfg(feature = "validator")]
+//! # {
+//!
+//! use iso8601_duration::Duration;
+//! use validator::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Settings {
+//!     #[validate(range(min = Duration::seconds(1), max = Duration::hours(1)))]
+//!     timeout: Duration,
+//! }
+//!
+//! let settings = Settings { timeout: "PT2H".parse().unwrap() };
+//! assert!(settings.validate().is_err());
+//! # }
+//! ```
+//!
+//! # Migrating to `ExactDuration`
+//!
+//! `Duration` stores its components as `f32`, which rounds large values
+//! and most decimal fractions of a second. `ExactDuration` stores whole
+//! components and nanoseconds instead. Both are kept, under the
+//! `DurationV1` and `DurationV2` aliases, so code can move over one module
+//! at a time: `to_v2` converts without loss or fails, and `to_v1` hands
+//! values back to code which isn't migrated yet.
+//!
+//! # `no_std`
+//!
+//! Without the `std` feature, enabled by default, the crate is `no_std`
+//! and only has `Duration` with its arithmetic operators, `Display` and
+//! `Duration::parse_no_alloc`, which parses without allocating:
+//!
+//! ```toml
+//! iso8601-duration = { version = "0.3.0", default-features = false }
+//! ```
+//!
+//! Enabling `core` adds `IntegerDuration`, whose parser and formatter
+//! don't use floating-point numbers, to keep code size down on targets
+//! such as eBPF.
+//!
+//! `num-traits` works with or without `std`, and adds
+//! `Duration::get_as` and `Duration::set_from` to read and write
+//! components as any numeric type, failing on lossy conversions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(feature = "chrono")]
+#[cfg(all(feature = "std", any(feature = "chrono", feature = "time_03")))]
+mod add_str;
+#[cfg(feature = "time_03")]
+mod age;
+#[cfg(feature = "time_03")]
+mod align;
+#[cfg(feature = "time_03")]
+mod anchored;
+#[cfg(feature = "std")]
+mod apply;
+mod arithmetic;
+#[cfg(all(feature = "std", feature = "axum"))]
+mod axum;
+#[cfg(feature = "std")]
+mod backoff;
+#[cfg(feature = "std")]
+mod breakdown;
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(feature = "time_03")]
+mod business;
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(all(feature = "std", feature = "chrono"))]
 mod chrono;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+mod const_parse;
+#[cfg(feature = "time_03")]
+mod day_count;
+#[cfg(feature = "time_03")]
+mod deadline;
 mod duration;
-#[cfg(feature = "serde")]
-mod serde;
+#[cfg(feature = "std")]
+mod error_code;
+#[cfg(feature = "std")]
+mod exact;
+#[cfg(feature = "time_03")]
+mod explain;
+#[cfg(feature = "time_03")]
+mod fiscal;
+#[cfg(feature = "fixed_point")]
+mod fixed;
+#[cfg(feature = "std")]
+mod format;
+#[cfg(feature = "time_03")]
+mod http;
+#[cfg(feature = "std")]
+mod human;
+#[cfg(feature = "core")]
+mod integer;
+#[cfg(feature = "std")]
+mod interchange;
+#[cfg(feature = "time_03")]
+mod interval;
+#[cfg(feature = "std")]
+mod list;
+#[cfg(feature = "std")]
+mod messages;
+#[cfg(feature = "std")]
+mod migration;
+mod no_alloc;
+#[cfg(feature = "std")]
+mod nominal;
+#[cfg(feature = "std")]
+mod normalize;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "std")]
+mod pattern;
+#[cfg(feature = "time_03")]
+mod rate;
+#[cfg(feature = "time_03")]
+mod redis;
+#[cfg(feature = "std")]
+mod relative;
+#[cfg(feature = "time_03")]
+mod rounding;
+#[cfg(all(feature = "std", feature = "rust_decimal"))]
+mod rust_decimal;
+#[cfg(feature = "std")]
+mod sanitize;
+#[cfg(feature = "std")]
+mod scale;
+#[cfg(feature = "time_03")]
+mod schedule;
+#[cfg(feature = "std")]
+mod seconds;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub mod serde;
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod template;
+#[cfg(feature = "test-support")]
+pub mod test_support;
 #[cfg(feature = "time_03")]
 mod time_03;
+#[cfg(feature = "time_03")]
+mod time_scale;
+#[cfg(feature = "std")]
+mod token;
+#[cfg(feature = "tracing")]
+mod tracing;
+mod unit;
+#[cfg(feature = "extensions")]
+mod unspecified;
+#[cfg(feature = "std")]
+mod validate;
+#[cfg(all(feature = "std", feature = "validator"))]
+mod validator;
+#[cfg(all(feature = "std", feature = "serde"))]
+mod web;
 
-pub use crate::duration::{Duration, ParseDurationError};
+#[cfg(all(feature = "std", any(feature = "chrono", feature = "time_03")))]
+pub use crate::add_str::{AddIso8601, AddIso8601Error};
+#[cfg(feature = "time_03")]
+pub use crate::age::LeapDayRule;
+#[cfg(feature = "time_03")]
+pub use crate::align::{Stepping, WeekStart};
+#[cfg(feature = "time_03")]
+pub use crate::anchored::AnchoredDuration;
+#[cfg(feature = "std")]
+pub use crate::apply::{ApplyDuration, ApplyError, EomPolicy, OverflowPolicy};
+#[cfg(all(feature = "std", feature = "axum"))]
+pub use crate::axum::DurationRejection;
+#[cfg(feature = "std")]
+pub use crate::backoff::{Backoff, BackoffIter, Growth};
+#[cfg(feature = "std")]
+pub use crate::breakdown::Breakdown;
+#[cfg(feature = "std")]
+pub use crate::builder::{BuildError, DurationBuilder};
+#[cfg(feature = "time_03")]
+pub use crate::business::{BusinessCalendar, BusinessDayError, HolidayProvider, WorkingHours};
+#[cfg(feature = "time_03")]
+pub use crate::day_count::DayCount;
+#[cfg(feature = "time_03")]
+pub use crate::deadline::Deadline;
+pub use crate::duration::Duration;
+#[cfg(feature = "std")]
+pub use crate::duration::{ParseDurationError, StrictParseError};
+#[cfg(feature = "std")]
+pub use crate::error_code::ErrorCode;
+#[cfg(feature = "std")]
+pub use crate::exact::{ExactDuration, ExactDurationError};
+#[cfg(feature = "time_03")]
+pub use crate::explain::{AddExplanation, AddStep};
+#[cfg(feature = "time_03")]
+pub use crate::fiscal::FiscalCalendar;
+#[cfg(feature = "fixed_point")]
+pub use crate::fixed::{FixedDuration, FIXED_SCALE};
+#[cfg(feature = "std")]
+pub use crate::format::FormatError;
+#[cfg(feature = "std")]
+pub use crate::human::{HumanFormat, UnitNames};
+#[cfg(feature = "core")]
+pub use crate::integer::{IntegerDuration, IntegerParseError};
+#[cfg(feature = "std")]
+pub use crate::interchange::MonthDayNano;
+#[cfg(feature = "time_03")]
+pub use crate::interval::{Interval, Occurrences, ParseIntervalError, RepeatingInterval};
+#[cfg(feature = "std")]
+pub use crate::list::{ListItemError, ParseListError};
+#[cfg(feature = "std")]
+pub use crate::messages::{English, ErrorReason, MessageCatalog};
+#[cfg(feature = "std")]
+pub use crate::migration::{DurationV1, DurationV2};
+pub use crate::no_alloc::NoAllocParseError;
+#[cfg(feature = "std")]
+pub use crate::nominal::OrdNominal;
+#[cfg(feature = "num-traits")]
+pub use crate::num::NumConversionError;
+#[cfg(feature = "std")]
+pub use crate::pattern::{ComponentPattern, DurationPattern};
+#[cfg(feature = "time_03")]
+pub use crate::rate::RatePer;
+#[cfg(feature = "time_03")]
+pub use crate::redis::RedisTtl;
+#[cfg(feature = "std")]
+pub use crate::relative::Direction;
+#[cfg(all(feature = "std", feature = "rust_decimal"))]
+pub use crate::rust_decimal::DecimalDuration;
+#[cfg(feature = "std")]
+pub use crate::sanitize::{NonFiniteError, NonFinitePolicy, SanitizeReport};
+#[cfg(feature = "std")]
+pub use crate::scale::ScaleError;
+#[cfg(feature = "time_03")]
+pub use crate::schedule::Periods;
+#[cfg(feature = "std")]
+pub use crate::seconds::{CalendarPolicy, Rounding, ToStdError};
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use crate::serde::DurationOrSeconds;
+#[cfg(feature = "std")]
+pub use crate::stats::DurationStats;
+#[cfg(feature = "std")]
+pub use crate::template::{DurationTemplate, TemplateError};
+#[cfg(feature = "time_03")]
+pub use crate::time_03::NonInvertibleError;
+#[cfg(feature = "time_03")]
+pub use crate::time_scale::TimeScale;
+#[cfg(feature = "std")]
+pub use crate::token::{tokenize, DurationToken, TokenKind, Tokens};
+pub use crate::unit::Unit;
+#[cfg(feature = "extensions")]
+pub use crate::unspecified::PartialDuration;
+#[cfg(feature = "std")]
+pub use crate::validate::{
+    validate_iso8601_duration, ComponentLimitError, ComponentLimits, DurationRange, RangeError,
+};
+#[cfg(all(feature = "std", feature = "validator"))]
+pub use crate::validator::ValidateIso8601Duration;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use crate::web::{Bounded, DurationParam, ErrorBody};
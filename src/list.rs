@@ -0,0 +1,117 @@
+use std::{error::Error, fmt, ops::Range};
+
+use crate::{Duration, ParseDurationError};
+
+/// A list item which failed to parse, see [`Duration::parse_list`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct ListItemError {
+    /// Index of the item in the list
+    pub index: usize,
+    /// Byte range of the item in the whole input, surrounding whitespace excluded
+    pub span: Range<usize>,
+    /// The error for the item alone
+    pub error: ParseDurationError,
+}
+
+/// Error returned by [`Duration::parse_list`], holding every invalid item.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseListError {
+    pub errors: Vec<ListItemError>,
+}
+
+impl fmt::Display for ParseListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(
+                f,
+                "item {} at {}..{}: {}",
+                item.index, item.span.start, item.span.end, item.error
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseListError {}
+
+impl Duration {
+    /// Parse a list of durations separated by `sep`.
+    ///
+    /// Whitespace around items is ignored. With a whitespace separator,
+    /// items are split on runs of any whitespace, otherwise an empty item
+    /// is an error. All invalid items are reported, with their position in the
+    /// input.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let retries = Duration::parse_list("PT5S, PT30S, PT5M", ',').unwrap();
+    /// assert_eq!(retries.len(), 3);
+    ///
+    /// let err = Duration::parse_list("PT5S, 30S, PT5M, 5M", ',').unwrap_err();
+    /// assert_eq!(err.errors.len(), 2);
+    /// assert_eq!(err.errors[0].index, 1);
+    /// assert_eq!(err.errors[0].span, 6..9);
+    /// ```
+    pub fn parse_list(input: &str, sep: char) -> Result<Vec<Duration>, ParseListError> {
+        let mut durations = Vec::new();
+        let mut errors = Vec::new();
+        let whitespace = sep.is_whitespace();
+
+        let items = input
+            .split(|c: char| c == sep || whitespace && c.is_whitespace())
+            .map(str::trim)
+            .filter(|item| !(whitespace && item.is_empty()))
+            .map(|item| (item.as_ptr() as usize - input.as_ptr() as usize, item));
+
+        for (index, (offset, item)) in items.enumerate() {
+            match Duration::parse(item) {
+                Ok(duration) => durations.push(duration),
+                Err(error) => errors.push(ListItemError {
+                    index,
+                    span: offset..offset + item.len(),
+                    error,
+                }),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(durations)
+        } else {
+            Err(ParseListError { errors })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_whitespace_separated() {
+        assert_eq!(
+            Duration::parse_list("  PT5S   PT30S\tPT5M ", ' ').map(|list| list.len()),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn report_item_errors() {
+        let input = "PT5S,,P1X";
+        let err = Duration::parse_list(input, ',').unwrap_err();
+
+        assert_eq!(err.errors.len(), 2);
+        assert_eq!(err.errors[0].index, 1);
+        assert_eq!(err.errors[0].span, 5..5);
+        assert_eq!(err.errors[1].index, 2);
+        assert_eq!(&input[err.errors[1].span.clone()], "P1X");
+        assert_eq!(
+            err.to_string(),
+            "item 1 at 5..5: duration must start with 'P'; \
+             item 2 at 6..9: unknown designator 'X' in the component at position 1"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt;
+
+use crate::{duration::designator_of, ParseDurationError, StrictParseError};
+
+/// What went wrong while parsing a duration, independently of how it's
+/// worded.
+///
+/// New reasons may be added as the parser learns to tell more failures
+/// apart, so matches need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorReason {
+    /// The input doesn't start with the `P` designator.
+    MissingPrefix,
+    /// The input has no component after `P` (or `PT`).
+    Empty,
+    /// A component has an invalid number.
+    InvalidComponent,
+    /// A number isn't followed by a designator, as in `P1`.
+    MissingDesignator,
+    /// A number is followed by a letter which isn't a designator, as in
+    /// `P1X`.
+    UnknownDesignator,
+    /// A component appears twice, as in `P1Y2Y`.
+    DuplicateComponent,
+    /// A component comes after one it should precede, as in `P1D2Y` or
+    /// `PT1H2D`.
+    OutOfOrder,
+    /// The input goes on after the last valid component.
+    UnexpectedCharacter,
+    /// A component other than the last one has a fraction, which
+    /// [`Duration::parse_strict`](crate::Duration::parse_strict) rejects.
+    ///
+    /// Only returned by [`StrictParseError::reason`].
+    FractionNotLast,
+    /// Fractions use both `.` and `,` as decimal separator, which
+    /// [`Duration::parse_strict`](crate::Duration::parse_strict) rejects.
+    ///
+    /// Only returned by [`StrictParseError::reason`].
+    MixedDecimalSeparators,
+}
+
+/// A table of user-facing texts for parse errors.
+///
+/// `ParseDurationError` implements `Display` with the built-in
+/// [`English`] catalog, implement this trait to show errors in another
+/// language:
+///
+/// ```rust
+/// use iso8601_duration::{Duration, ErrorReason, MessageCatalog, ParseDurationError};
+///
+/// struct French;
+///
+/// impl MessageCatalog for French {
+///     fn message(&self, error: &ParseDurationError) -> String {
+///         match error.reason() {
+///             ErrorReason::MissingPrefix => "la durée doit commencer par « P »".into(),
+///             ErrorReason::Empty => "la durée est vide".into(),
+///             _ => format!("caractère invalide à la position {}", error.position),
+///         }
+///     }
+/// }
+///
+/// let error = Duration::parse("1D").unwrap_err();
+/// assert_eq!(error.localized(&French), "la durée doit commencer par « P »");
+/// assert_eq!(error.to_string(), "duration must start with 'P'");
+/// ```
+pub trait MessageCatalog {
+    /// Return the text describing `error`
+    fn message(&self, error: &ParseDurationError) -> String;
+}
+
+/// The built-in English messages, used by `Display`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct English;
+
+impl MessageCatalog for English {
+    fn message(&self, error: &ParseDurationError) -> String {
+        match error.reason() {
+            ErrorReason::MissingPrefix => "duration must start with 'P'".to_string(),
+            ErrorReason::Empty => "duration must contain at least one component".to_string(),
+            ErrorReason::InvalidComponent => {
+                format!("invalid component at position {}", error.position)
+            }
+            ErrorReason::MissingDesignator => format!(
+                "missing designator after the number at position {}",
+                error.position
+            ),
+            ErrorReason::UnknownDesignator => format!(
+                "unknown designator {:?} in the component at position {}",
+                error.designator().unwrap_or_default(),
+                error.position
+            ),
+            ErrorReason::DuplicateComponent => format!(
+                "duplicate component {:?} at position {}",
+                error.designator().unwrap_or_default(),
+                error.position
+            ),
+            ErrorReason::OutOfOrder => format!(
+                "component {:?} at position {} is out of order",
+                error.designator().unwrap_or_default(),
+                error.position
+            ),
+            ErrorReason::UnexpectedCharacter => format!(
+                "unexpected character {:?} at position {}",
+                error.rest().chars().next().unwrap_or_default(),
+                error.position
+            ),
+            ErrorReason::FractionNotLast | ErrorReason::MixedDecimalSeparators => {
+                strict_message(error.reason(), error.position)
+            }
+        }
+    }
+}
+
+/// Describe an error which only `Duration::parse_strict` reports.
+fn strict_message(reason: ErrorReason, position: usize) -> String {
+    if reason == ErrorReason::FractionNotLast {
+        format!(
+            "only the last component can have a fraction, found one at position {}",
+            position
+        )
+    } else {
+        format!(
+            "decimal separator at position {} differs from the previous ones",
+            position
+        )
+    }
+}
+
+impl ParseDurationError {
+    /// Return the reason of the error, as found by the parser
+    pub fn reason(&self) -> ErrorReason {
+        self.reason
+    }
+
+    /// Return the input from `position`, or an empty string when
+    /// `position` is out of bounds or not on a character boundary
+    fn rest(&self) -> &str {
+        self.input.get(self.position..).unwrap_or_default()
+    }
+
+    /// Return the letter following the number at `position`, if any
+    pub(crate) fn designator(&self) -> Option<char> {
+        designator_of(self.rest())
+    }
+
+    /// Render the error over three lines: the message, `input` and a
+    /// caret under the offending character, for printing from CLIs.
+    ///
+    /// `input` is usually [`input`](ParseDurationError::input), but can
+    /// be any text the error position refers to. The caret is placed by
+    /// counting characters rather than bytes.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let input = "PT1H2D";
+    /// let error = Duration::parse(input).unwrap_err();
+    /// assert_eq!(
+    ///     error.render_with_source(input),
+    ///     "component 'D' at position 4 is out of order\n\
+    ///      PT1H2D\n\
+    ///      \x20   ^"
+    /// );
+    /// ```
+    pub fn render_with_source(&self, input: &str) -> String {
+        let column = input
+            .char_indices()
+            .take_while(|(i, _)| *i < self.position)
+            .count();
+
+        format!("{}\n{}\n{:>width$}", self, input, "^", width = column + 1)
+    }
+
+    /// Return the text describing the error from `catalog`
+    pub fn localized(&self, catalog: &dyn MessageCatalog) -> String {
+        catalog.message(self)
+    }
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&English.message(self))
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+impl StrictParseError {
+    /// Return the reason of the error
+    pub fn reason(&self) -> ErrorReason {
+        match self {
+            StrictParseError::Parse(err) => err.reason(),
+            StrictParseError::FractionNotLast { .. } => ErrorReason::FractionNotLast,
+            StrictParseError::MixedDecimalSeparators { .. } => ErrorReason::MixedDecimalSeparators,
+        }
+    }
+}
+
+impl fmt::Display for StrictParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrictParseError::Parse(err) => err.fmt(f),
+            _ => f.write_str(&strict_message(self.reason(), self.position())),
+        }
+    }
+}
+
+impl std::error::Error for StrictParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StrictParseError::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Duration;
+
+    use super::*;
+
+    #[test]
+    fn reasons() {
+        let reason = |input: &str| Duration::parse(input).unwrap_err().reason();
+
+        assert_eq!(reason("1D"), ErrorReason::MissingPrefix);
+        assert_eq!(reason(""), ErrorReason::MissingPrefix);
+        assert_eq!(reason("-1D"), ErrorReason::MissingPrefix);
+        assert_eq!(reason("P"), ErrorReason::Empty);
+        assert_eq!(reason("PT"), ErrorReason::Empty);
+        assert_eq!(reason("P1.5.5D"), ErrorReason::InvalidComponent);
+        assert_eq!(reason("Pé1D"), ErrorReason::InvalidComponent);
+        assert_eq!(reason("P1"), ErrorReason::MissingDesignator);
+        assert_eq!(reason("P1YT2"), ErrorReason::MissingDesignator);
+        assert_eq!(reason("P1X"), ErrorReason::UnknownDesignator);
+        assert_eq!(reason("P1Y2X"), ErrorReason::UnknownDesignator);
+        assert_eq!(reason("P1Y2Y"), ErrorReason::DuplicateComponent);
+        assert_eq!(reason("PT1M2M"), ErrorReason::DuplicateComponent);
+        assert_eq!(reason("P1D2Y"), ErrorReason::OutOfOrder);
+        assert_eq!(reason("PT1H2D"), ErrorReason::OutOfOrder);
+        assert_eq!(reason("P1D "), ErrorReason::UnexpectedCharacter);
+        assert_eq!(reason("P1DT1H/"), ErrorReason::UnexpectedCharacter);
+        assert_eq!(reason("-P1D2D"), ErrorReason::DuplicateComponent);
+    }
+
+    #[test]
+    fn position_out_of_bounds() {
+        let error = |position| ParseDurationError {
+            input: "P1é".to_string(),
+            position,
+            reason: ErrorReason::UnexpectedCharacter,
+        };
+
+        // past the end, and inside the two bytes of 'é'
+        for position in [10, 3] {
+            assert_eq!(error(position).designator(), None);
+            assert!(error(position).to_string().contains("unexpected character"));
+        }
+    }
+
+    #[test]
+    fn english_messages() {
+        let message = |input: &str| Duration::parse(input).unwrap_err().to_string();
+
+        assert_eq!(
+            message("PT"),
+            "duration must contain at least one component"
+        );
+        assert_eq!(
+            message("P1X"),
+            "unknown designator 'X' in the component at position 1"
+        );
+        assert_eq!(message("P1D "), "unexpected character ' ' at position 3");
+        assert_eq!(message("P1Y2Y"), "duplicate component 'Y' at position 3");
+        assert_eq!(
+            message("PT1H2D"),
+            "component 'D' at position 4 is out of order"
+        );
+    }
+
+    #[test]
+    fn render_caret() {
+        let render = |input: &str| {
+            Duration::parse(input)
+                .unwrap_err()
+                .render_with_source(input)
+        };
+
+        assert_eq!(render("1D"), "duration must start with 'P'\n1D\n^");
+        assert_eq!(
+            render("PT"),
+            "duration must contain at least one component\nPT\n  ^"
+        );
+        assert_eq!(render("Pé1D"), "invalid component at position 1\nPé1D\n ^");
+        assert_eq!(
+            render("P1Dé"),
+            "unexpected character 'é' at position 3\nP1Dé\n   ^"
+        );
+    }
+}
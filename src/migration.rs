@@ -0,0 +1,107 @@
+use std::convert::TryFrom;
+
+use crate::{Duration, ExactDuration, ExactDurationError};
+
+/// The first representation of durations, with `f32` components.
+///
+/// Naming the version lets code state which representation it relies on
+/// while a codebase moves to [`DurationV2`] one module at a time.
+pub type DurationV1 = Duration;
+
+/// The lossless representation of durations, with integer components and
+/// nanoseconds, see [`ExactDuration`].
+pub type DurationV2 = ExactDuration;
+
+impl Duration {
+    /// Convert to [`DurationV2`], failing instead of rounding.
+    ///
+    /// Each component is read as the shortest decimal that displays as
+    /// its `f32` value, and the conversion fails exactly when that
+    /// decimal has no [`DurationV2`] equivalent:
+    ///
+    /// - a fraction of a month, including one left over from a fraction
+    ///   of a year that isn't a whole number of months, e.g. `P0.5M` or
+    ///   `P0.1Y`, fails with [`ExactDurationError::Inexact`], since a
+    ///   month has no fixed length,
+    /// - a fraction of a nanosecond, e.g. `PT0.0000000001S`, fails with
+    ///   [`ExactDurationError::Inexact`],
+    /// - a component that isn't finite, or that doesn't fit in an `i64`
+    ///   once fractions are carried to the smaller units, e.g. 10^19 days
+    ///   or `Duration::MAX`, fails with [`ExactDurationError::OutOfRange`].
+    ///
+    /// This is why there's no `From<DurationV1>` for [`DurationV2`]: it
+    /// would have to round, which is what the migration is meant to stop.
+    /// Every other duration converts, including all durations parsed from
+    /// a string with whole numbers, so migrated code can take over values
+    /// produced by code which isn't migrated yet.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{DurationV1, DurationV2};
+    ///
+    /// let v1: DurationV1 = "P1Y2M3DT4H5M6.5S".parse().unwrap();
+    /// let v2: DurationV2 = v1.to_v2().unwrap();
+    /// assert_eq!(v2.to_string(), "P1Y2M3DT4H5M6.5S");
+    /// assert_eq!(v2.to_v1(), v1);
+    ///
+    /// let v1: DurationV1 = "P0.5M".parse().unwrap();
+    /// assert!(v1.to_v2().is_err());
+    /// ```
+    pub fn to_v2(&self) -> Result<DurationV2, ExactDurationError> {
+        ExactDuration::try_from(*self)
+    }
+}
+
+impl ExactDuration {
+    /// Convert to [`DurationV1`], for code which isn't migrated yet
+    ///
+    /// Components above 2^24 and nanoseconds may be rounded to the nearest
+    /// `f32`.
+    pub fn to_v1(&self) -> DurationV1 {
+        Duration::from(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unit;
+
+    #[test]
+    fn round_trip_between_versions() {
+        for input in &["PT0S", "-P1DT12H", "P0.5Y", "PT0.001S", "P1W"] {
+            let v1: DurationV1 = input.parse().unwrap();
+            let v2 = v1.to_v2().unwrap();
+            assert_eq!(v2.to_v1().to_v2(), Ok(v2), "{}", input);
+        }
+
+        let fails = |v1: DurationV1| v1.to_v2().unwrap_err();
+        assert_eq!(
+            fails(Duration::new(0., 0.5, 0., 0., 0., 0.)),
+            ExactDurationError::Inexact(Unit::Month)
+        );
+        assert_eq!(
+            fails(Duration::new(0.1, 0., 0., 0., 0., 0.)),
+            ExactDurationError::Inexact(Unit::Month)
+        );
+        assert_eq!(
+            fails(Duration::new(0., 0., 0., 0., 0., 1e-10)),
+            ExactDurationError::Inexact(Unit::Second)
+        );
+        assert_eq!(
+            fails(Duration::new(0., 0., 1e19, 0., 0., 0.)),
+            ExactDurationError::OutOfRange(Unit::Day)
+        );
+        assert!(matches!(
+            fails(Duration::new(0., 0., 0., 0., 0., f32::NAN)),
+            ExactDurationError::OutOfRange(_)
+        ));
+        assert!(matches!(
+            fails(Duration::MAX),
+            ExactDurationError::OutOfRange(_)
+        ));
+
+        let v2: DurationV2 = "PT123456789.123456789S".parse().unwrap();
+        assert_eq!(v2.second, 123_456_789);
+        assert_eq!(v2.to_v1().second, 123_456_792.);
+    }
+}
@@ -0,0 +1,230 @@
+use core::{fmt, str::FromStr};
+
+use crate::Duration;
+
+/// Error returned by [`Duration::parse_no_alloc`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAllocParseError {
+    /// Byte offset of the offending character, or the length of the input
+    /// when it ends too early
+    pub position: usize,
+}
+
+impl fmt::Display for NoAllocParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid duration at position {}", self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoAllocParseError {}
+
+/// Longest number with a decimal comma, which is copied to the stack to
+/// be parsed.
+const MAX_COMMA_NUMBER: usize = 64;
+
+impl Duration {
+    /// Parse given string into Duration, without allocating and without
+    /// `std`, for embedded and WebAssembly targets.
+    ///
+    /// It accepts the same durations as [`Duration::parse`], except the
+    /// alternative format such as `P0003-06-04T12:30:05`, numbers with an
+    /// exponent, and a `T` with no time component after it. The error only
+    /// tells where parsing stopped.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert_eq!(
+    ///     Duration::parse_no_alloc("P1Y2M3DT4H5M6,5S"),
+    ///     Ok(Duration::new(1., 2., 3., 4., 5., 6.5))
+    /// );
+    /// assert_eq!(Duration::parse_no_alloc("-P2W").unwrap().day, -14.);
+    /// assert_eq!(Duration::parse_no_alloc("P1D2Y").unwrap_err().position, 3);
+    /// ```
+    pub fn parse_no_alloc(input: &str) -> Result<Duration, NoAllocParseError> {
+        let bytes = input.as_bytes();
+        let error = |position| Err(NoAllocParseError { position });
+
+        let negative = bytes.first() == Some(&b'-');
+        let mut position = negative as usize;
+        if bytes.get(position) != Some(&b'P') {
+            return error(position);
+        }
+        position += 1;
+
+        let mut duration = Duration::ZERO;
+        let mut in_time = false;
+        // index of the next designator allowed in the current part
+        let mut next = 0;
+        let mut components = 0;
+        let mut week = false;
+
+        while position < bytes.len() {
+            if bytes[position] == b'T' && !in_time {
+                in_time = true;
+                next = 0;
+                position += 1;
+                if position == bytes.len() {
+                    return error(position);
+                }
+                continue;
+            }
+
+            let start = position;
+            let end = number_end(bytes, start);
+            if end == start || end == bytes.len() {
+                return error(end);
+            }
+            let value = match parse_number(&input[start..end]) {
+                Some(value) => value,
+                None => return error(start),
+            };
+            position = end;
+
+            let designators: &[u8] = if in_time { b"HMS" } else { b"YMWD" };
+            let index = match designators[next..]
+                .iter()
+                .position(|d| *d == bytes[position])
+            {
+                Some(offset) => next + offset,
+                None => return error(start),
+            };
+            match (in_time, designators[index]) {
+                (false, b'Y') => duration.year = value,
+                (false, b'M') => duration.month = value,
+                (false, b'W') => {
+                    week = true;
+                    duration.day = value * 7.;
+                }
+                (false, _) => duration.day = value,
+                (true, b'H') => duration.hour = value,
+                (true, b'M') => duration.minute = value,
+                (true, _) => duration.second = value,
+            }
+
+            // weeks can't be combined with other components
+            if week && components > 0 {
+                return error(start);
+            }
+            next = if week { designators.len() } else { index + 1 };
+            components += 1;
+            position += 1;
+        }
+
+        if components == 0 {
+            return error(bytes.len());
+        }
+
+        Ok(if negative { -duration } else { duration })
+    }
+}
+
+/// Return the end of the number starting at `start`: an optional sign,
+/// digits, then an optional fraction.
+fn number_end(bytes: &[u8], start: usize) -> usize {
+    let digits = |from: usize| {
+        from + bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut end = start;
+    if matches!(bytes.get(end), Some(b'-') | Some(b'+')) {
+        end += 1;
+    }
+    let integer_end = digits(end);
+    if integer_end == end {
+        return start;
+    }
+    end = integer_end;
+
+    if matches!(bytes.get(end), Some(b'.') | Some(b',')) {
+        let fraction_end = digits(end + 1);
+        if fraction_end > end + 1 {
+            end = fraction_end;
+        }
+    }
+    end
+}
+
+fn parse_number(number: &str) -> Option<f32> {
+    if !number.contains(',') {
+        return f32::from_str(number).ok();
+    }
+
+    let mut buffer = [0; MAX_COMMA_NUMBER];
+    let buffer = buffer.get_mut(..number.len())?;
+    buffer.copy_from_slice(number.as_bytes());
+    for byte in buffer.iter_mut().filter(|b| **b == b',') {
+        *byte = b'.';
+    }
+    f32::from_str(core::str::from_utf8(buffer).ok()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn same_as_parse() {
+        for input in [
+            "P1Y2M3DT4H5M6S",
+            "PT0.5S",
+            "P1,5D",
+            "-P1DT12H",
+            "P1DT-12H",
+            "P-1M1D",
+            "P+1D",
+            "P12W",
+            "-P0.5W",
+            "PT36H",
+            "P0D",
+            "P1Y1W",
+            "P12WT12H",
+            "P1D2Y",
+            "PT1H2D",
+            "P1Y1Y",
+            "P",
+            "PT",
+            "1D",
+            "P1",
+            "P1X",
+            "P1.5.5D",
+            "Pé1D",
+            "P1D ",
+            "",
+        ] {
+            assert_eq!(
+                Duration::parse_no_alloc(input).ok(),
+                Duration::parse(input).ok(),
+                "{}",
+                input
+            );
+        }
+
+        let long = "PT0,000000000000000000000000000000000000000000000000000000000000001S";
+        assert_eq!(
+            Duration::parse_no_alloc(&long.replace(',', ".")).ok(),
+            Duration::parse(long).ok()
+        );
+    }
+
+    #[test]
+    fn error_positions() {
+        let position = |input: &str| Duration::parse_no_alloc(input).unwrap_err().position;
+        assert_eq!(position("1D"), 0);
+        assert_eq!(position("-1D"), 1);
+        assert_eq!(position("P"), 1);
+        assert_eq!(position("PT"), 2);
+        assert_eq!(position("P1DT"), 4);
+        assert_eq!(position("P1Y2W"), 3);
+        assert_eq!(position("P1X"), 1);
+        assert_eq!(position("P1e3D"), 1);
+
+        let long = "PT0,000000000000000000000000000000000000000000000000000000000000001S";
+        assert_eq!(position(long), 2);
+    }
+}
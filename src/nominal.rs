@@ -0,0 +1,191 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::{CalendarPolicy, Duration};
+
+/// A `Duration` with a total order, usable as a `BTreeMap` key or in a
+/// sorted index.
+///
+/// Durations are ordered by their nominal length in seconds, with years
+/// and months counted with their average Gregorian length (see
+/// [`CalendarPolicy::Average`]). Durations of the same length, such as
+/// `P1D` and `PT24H`, are ordered by their components, largest unit
+/// first, so they stay distinct keys. Non-finite components are ordered
+/// as `f64::total_cmp` does.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use iso8601_duration::{Duration, OrdNominal};
+///
+/// let mut retention = BTreeMap::new();
+/// for (input, tier) in [("P1Y", "cold"), ("PT12H", "hot"), ("P1M", "warm")] {
+///     retention.insert(OrdNominal(input.parse().unwrap()), tier);
+/// }
+///
+/// let tiers: Vec<_> = retention.values().copied().collect();
+/// assert_eq!(tiers, ["hot", "warm", "cold"]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OrdNominal(pub Duration);
+
+impl OrdNominal {
+    /// Return the wrapped duration
+    pub fn into_inner(self) -> Duration {
+        self.0
+    }
+
+    fn length(&self) -> f64 {
+        self.0
+            .to_seconds_f64(CalendarPolicy::Average)
+            .unwrap_or_default()
+    }
+}
+
+impl Deref for OrdNominal {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+impl From<Duration> for OrdNominal {
+    fn from(duration: Duration) -> Self {
+        OrdNominal(duration)
+    }
+}
+
+impl From<OrdNominal> for Duration {
+    fn from(nominal: OrdNominal) -> Self {
+        nominal.0
+    }
+}
+
+impl PartialEq for OrdNominal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrdNominal {}
+
+impl PartialOrd for OrdNominal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdNominal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.length().total_cmp(&other.length()).then_with(|| {
+            self.0
+                .components()
+                .iter()
+                .zip(other.0.components().iter())
+                .map(|((_, a), (_, b))| a.total_cmp(b))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+/// Consistent with `Eq`, which only holds for identical components.
+impl Hash for OrdNominal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (_, value) in self.0.components().iter() {
+            value.to_bits().hash(state);
+        }
+    }
+}
+
+/// Compares durations made of `hour`, `minute` and `second` only, whose
+/// length is fixed.
+///
+/// Durations with a `year`, `month` or `day` are incomparable, as days
+/// may be 23 or 25 hours long across daylight saving time changes, as are
+/// different durations of the same length such as `PT1M` and `PT60S`,
+/// which aren't equal. Use [`OrdNominal`] for a total order, or
+/// `Duration::cmp_at` (with the `time_03` feature) to compare at a given
+/// datetime.
+///
+/// ```rust
+/// use iso8601_duration::Duration;
+///
+/// let short: Duration = "PT90S".parse().unwrap();
+/// let long: Duration = "PT1H".parse().unwrap();
+/// assert!(short < long);
+///
+/// let day: Duration = "P1D".parse().unwrap();
+/// assert_eq!(day.partial_cmp(&long), None);
+/// assert_eq!(short.partial_cmp(&"PT1M30S".parse().unwrap()), None);
+/// ```
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let seconds = |duration: &Duration| {
+            if duration.year != 0. || duration.month != 0. || duration.day != 0. {
+                return None;
+            }
+
+            Some(
+                duration.hour as f64 * 3600.
+                    + duration.minute as f64 * 60.
+                    + duration.second as f64,
+            )
+        };
+
+        match seconds(self)?.partial_cmp(&seconds(other)?)? {
+            Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nominal(input: &str) -> OrdNominal {
+        OrdNominal(input.parse().unwrap())
+    }
+
+    #[test]
+    fn total_order() {
+        assert!(nominal("P1M") < nominal("P31D"));
+        assert!(nominal("P1M") > nominal("P30D"));
+        assert!(nominal("PT24H") < nominal("P1D"));
+        assert_ne!(nominal("PT24H"), nominal("P1D"));
+        assert_eq!(nominal("P1D"), nominal("P1D"));
+
+        let mut durations = vec![nominal("P1D"), nominal("-PT1S"), nominal("PT1H")];
+        durations.sort();
+        assert_eq!(
+            durations,
+            [nominal("-PT1S"), nominal("PT1H"), nominal("P1D")]
+        );
+
+        let nan = OrdNominal(Duration::new(0., 0., 0., 0., 0., f32::NAN));
+        assert_eq!(nan.cmp(&nan), Ordering::Equal);
+        assert!(nan > OrdNominal(Duration::MAX));
+    }
+
+    #[test]
+    fn partial_order() {
+        let duration = |input: &str| input.parse::<Duration>().unwrap();
+
+        assert!(duration("PT59M60S") < duration("PT1H0.5S"));
+        assert!(duration("-PT1S") < Duration::ZERO);
+        assert_eq!(
+            duration("PT1H").partial_cmp(&duration("PT1H")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(duration("PT1H").partial_cmp(&duration("PT60M")), None);
+        assert_eq!(duration("P0DT1H").partial_cmp(&duration("P1D")), None);
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 0., f32::NAN).partial_cmp(&Duration::ZERO),
+            None
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::seconds::decimal_parts;
+use crate::Duration;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_MINUTE: i128 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: i128 = 24 * NANOS_PER_HOUR;
+
+impl Duration {
+    /// Carry overflow between components which convert exactly: months
+    /// into years, seconds into minutes and minutes into hours, and push
+    /// fractions of years, days, hours and minutes down to the next unit.
+    ///
+    /// Hours aren't carried into days, since a day lasts 23 or 25 hours
+    /// across a DST transition, and months aren't converted to days. Each
+    /// part keeps the sign of its total, so `PT1H-15M` becomes `PT45M`.
+    /// Components are read as their decimal value, so `PT0.1H` is exactly
+    /// `PT6M`. A duration with a non-finite or huge component is returned
+    /// unchanged.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let normalize = |input: &str| Duration::parse(input).unwrap().normalize().to_string();
+    ///
+    /// assert_eq!(normalize("PT90M"), "PT1H30M");
+    /// assert_eq!(normalize("P14M"), "P1Y2M");
+    /// assert_eq!(normalize("PT1.5H"), "PT1H30M");
+    /// assert_eq!(normalize("P1.5DT36H"), "P1DT48H");
+    /// ```
+    pub fn normalize(&self) -> Duration {
+        self.normalize_with(false).unwrap_or(*self)
+    }
+
+    /// Return a canonical form of the duration, such that durations of the
+    /// same nominal length compare equal, for deduplication or as a cache
+    /// key.
+    ///
+    /// This is [`normalize`](Duration::normalize), also carrying hours into
+    /// days of 24 hours, as [`num_hours`](Duration::num_hours) counts them.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let canonical = |input: &str| Duration::parse(input).unwrap().canonical();
+    ///
+    /// assert_eq!(canonical("PT36H"), canonical("P1DT720M"));
+    /// assert_eq!(canonical("P1Y0.5M").to_string(), "P1Y0.5M");
+    /// assert_ne!(canonical("P1M"), canonical("P30D"));
+    /// ```
+    pub fn canonical(&self) -> Duration {
+        self.normalize_with(true).unwrap_or(*self)
+    }
+
+    fn normalize_with(&self, carry_days: bool) -> Option<Duration> {
+        let (year, year_scale) = decimal_parts(self.year)?;
+        let (month, month_scale) = decimal_parts(self.month)?;
+        let scale = year_scale.max(month_scale);
+        let months = year
+            .checked_mul(12)?
+            .checked_mul(10i128.checked_pow(scale - year_scale)?)?
+            .checked_add(month.checked_mul(10i128.checked_pow(scale - month_scale)?)?)?;
+        let months_per_year = 12 * 10i128.checked_pow(scale)?;
+
+        // `day` is left out when it isn't carried, so that only its
+        // fraction is pushed down
+        let whole_day = self.day.trunc();
+        let day = if carry_days {
+            self.day
+        } else {
+            self.day - whole_day
+        };
+        let (nanos, denominator) =
+            Duration::new(0., 0., day, self.hour, self.minute, self.second).exact_nanos()?;
+        let denominator = denominator as i128;
+
+        let mut rest = nanos;
+        let mut take = |unit: i128| {
+            let whole = rest / (unit * denominator);
+            rest -= whole * unit * denominator;
+            whole as f32
+        };
+        let days = if carry_days {
+            take(NANOS_PER_DAY)
+        } else {
+            whole_day
+        };
+        let hours = take(NANOS_PER_HOUR);
+        let minutes = take(NANOS_PER_MINUTE);
+
+        Some(Duration::new(
+            (months / months_per_year) as f32,
+            (months % months_per_year) as f32 / 10f32.powi(scale as i32),
+            days,
+            hours,
+            minutes,
+            (rest as f64 / denominator as f64 / NANOS_PER_SECOND as f64) as f32,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        let normalize = |input: &str| Duration::parse(input).unwrap().normalize().to_string();
+
+        assert_eq!(normalize("PT0S"), "PT0S");
+        assert_eq!(normalize("PT3661S"), "PT1H1M1S");
+        assert_eq!(normalize("PT0.1H"), "PT6M");
+        assert_eq!(normalize("PT61.5S"), "PT1M1.5S");
+        assert_eq!(normalize("P1.5Y"), "P1Y6M");
+        assert_eq!(normalize("P0.1Y"), "P1.2M");
+        assert_eq!(normalize("P-1Y14M"), "P2M");
+        assert_eq!(normalize("-PT90M"), "-PT1H30M");
+        assert_eq!(normalize("PT1H-15M"), "PT45M");
+        assert_eq!(normalize("P1DT-12H"), "P1DT-12H");
+        assert_eq!(normalize("P1Y2M3DT4H5M6S"), "P1Y2M3DT4H5M6S");
+
+        let huge = Duration::new(0., 0., 0., f32::MAX, 0., 0.);
+        assert_eq!(huge.normalize(), huge);
+
+        let canonical = |input: &str| Duration::parse(input).unwrap().canonical().to_string();
+        assert_eq!(canonical("PT36H"), "P1DT12H");
+        assert_eq!(canonical("P1DT-12H"), "PT12H");
+        assert_eq!(canonical("P0.25D"), "PT6H");
+        assert_eq!(canonical("P2W"), "P14D");
+    }
+}
@@ -0,0 +1,128 @@
+use core::fmt;
+
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{Duration, Unit};
+
+/// Error returned by [`Duration::set_from`] when the value can't be stored
+/// as `f32` without loss.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumConversionError {
+    pub unit: Unit,
+}
+
+impl fmt::Display for NumConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value of the {:?} component can't be represented exactly",
+            self.unit
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumConversionError {}
+
+impl Duration {
+    fn component(&mut self, unit: Unit) -> Option<&mut f32> {
+        match unit {
+            Unit::Year => Some(&mut self.year),
+            #[cfg(feature = "extensions")]
+            Unit::Quarter => None,
+            Unit::Month => Some(&mut self.month),
+            Unit::Day => Some(&mut self.day),
+            Unit::Hour => Some(&mut self.hour),
+            Unit::Minute => Some(&mut self.minute),
+            Unit::Second => Some(&mut self.second),
+        }
+    }
+
+    /// Return the `unit` component converted to `T`
+    ///
+    /// This method will return `None` if the value doesn't convert to `T`
+    /// exactly, e.g. a fraction or a negative value to an unsigned integer.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, Unit};
+    ///
+    /// let duration: Duration = "P3DT1.5H".parse().unwrap();
+    /// assert_eq!(duration.get_as::<u32>(Unit::Day), Some(3));
+    /// assert_eq!(duration.get_as::<u32>(Unit::Hour), None);
+    /// assert_eq!(duration.get_as::<f64>(Unit::Hour), Some(1.5));
+    /// ```
+    pub fn get_as<T: NumCast + ToPrimitive>(&self, unit: Unit) -> Option<T> {
+        let value = *self.clone().component(unit)?;
+        let converted = T::from(value)?;
+
+        let back = converted.to_f32()?;
+        if back == value || (back.is_nan() && value.is_nan()) {
+            Some(converted)
+        } else {
+            None
+        }
+    }
+
+    /// Set the `unit` component from `value`
+    ///
+    /// The component is left unchanged if `value` can't be stored as `f32`
+    /// exactly, e.g. an integer above 2^24.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, Unit};
+    ///
+    /// let mut duration = Duration::ZERO;
+    /// duration.set_from(Unit::Minute, 90u64).unwrap();
+    /// duration.set_from(Unit::Second, 0.25f64).unwrap();
+    /// assert_eq!(duration, Duration::new(0., 0., 0., 0., 90., 0.25));
+    ///
+    /// assert!(duration.set_from(Unit::Second, 0.1f64).is_err());
+    /// assert!(duration.set_from(Unit::Day, u64::MAX).is_err());
+    /// ```
+    pub fn set_from<T: NumCast + ToPrimitive>(
+        &mut self,
+        unit: Unit,
+        value: T,
+    ) -> Result<(), NumConversionError> {
+        let error = NumConversionError { unit };
+
+        let converted = value.to_f32().ok_or(error)?;
+
+        // converting back gives the same number only if nothing was lost
+        let back = T::from(converted).ok_or(error)?;
+        let same_float = match (back.to_f64(), value.to_f64()) {
+            (Some(a), Some(b)) => a == b || (a.is_nan() && b.is_nan()),
+            _ => false,
+        };
+        if !same_float || back.to_i128() != value.to_i128() {
+            return Err(error);
+        }
+
+        *self.component(unit).ok_or(error)? = converted;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_conversions() {
+        let mut duration = Duration::new(0., 0., -2., f32::NAN, 0., 0.);
+        assert_eq!(duration.get_as::<i8>(Unit::Day), Some(-2));
+        assert_eq!(duration.get_as::<u8>(Unit::Day), None);
+        assert_eq!(duration.get_as::<u32>(Unit::Hour), None);
+        assert!(duration.get_as::<f64>(Unit::Hour).unwrap().is_nan());
+
+        assert_eq!(duration.set_from(Unit::Year, 16_777_216u32), Ok(()));
+        assert_eq!(
+            duration.set_from(Unit::Year, 16_777_217u32),
+            Err(NumConversionError { unit: Unit::Year })
+        );
+        assert_eq!(duration.year, 16_777_216.);
+        assert_eq!(duration.set_from(Unit::Month, f64::INFINITY), Ok(()));
+        assert_eq!(duration.month, f32::INFINITY);
+        assert!(duration.set_from(Unit::Month, f64::MAX).is_err());
+    }
+}
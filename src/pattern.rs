@@ -0,0 +1,174 @@
+use crate::{Duration, Unit};
+
+/// A condition on a single component of a duration, see [`DurationPattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ComponentPattern {
+    /// Any value, NaN included
+    #[default]
+    Any,
+    /// Zero
+    Zero,
+    /// Any value but zero and NaN
+    NonZero,
+    /// Exactly this value
+    Exactly(f32),
+    /// A value between two bounds, both included, either of them optional
+    Range { min: Option<f32>, max: Option<f32> },
+}
+
+impl ComponentPattern {
+    /// Match values at least `min`
+    pub const fn at_least(min: f32) -> Self {
+        ComponentPattern::Range {
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    /// Match values at most `max`
+    pub const fn at_most(max: f32) -> Self {
+        ComponentPattern::Range {
+            min: None,
+            max: Some(max),
+        }
+    }
+
+    /// Match values between `min` and `max`, both included
+    pub const fn between(min: f32, max: f32) -> Self {
+        ComponentPattern::Range {
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+
+    /// Return `true` if `value` matches the pattern
+    pub fn matches(&self, value: f32) -> bool {
+        match *self {
+            ComponentPattern::Any => true,
+            ComponentPattern::Zero => value == 0.,
+            ComponentPattern::NonZero => value != 0. && !value.is_nan(),
+            ComponentPattern::Exactly(expected) => value == expected,
+            ComponentPattern::Range { min, max } => {
+                !value.is_nan()
+                    && min.is_none_or(|min| value >= min)
+                    && max.is_none_or(|max| value <= max)
+            }
+        }
+    }
+}
+
+/// A pattern on the components of a duration, each component matching
+/// anything by default.
+///
+/// ```rust
+/// use iso8601_duration::{ComponentPattern, Duration, DurationPattern, Unit};
+///
+/// // any duration with years
+/// let long_term = DurationPattern::ANY.with(Unit::Year, ComponentPattern::NonZero);
+/// assert!(Duration::parse("P2Y3D").unwrap().matches_pattern(&long_term));
+/// assert!(!Duration::parse("P36M").unwrap().matches_pattern(&long_term));
+///
+/// // up to a day, written without calendar components
+/// let short = DurationPattern::TIME_ONLY.with(Unit::Hour, ComponentPattern::at_most(24.));
+/// assert!(Duration::parse("PT12H30M").unwrap().matches_pattern(&short));
+/// assert!(!Duration::parse("P1DT1H").unwrap().matches_pattern(&short));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DurationPattern {
+    pub year: ComponentPattern,
+    pub month: ComponentPattern,
+    pub day: ComponentPattern,
+    pub hour: ComponentPattern,
+    pub minute: ComponentPattern,
+    pub second: ComponentPattern,
+}
+
+impl DurationPattern {
+    /// Match every duration
+    pub const ANY: DurationPattern = DurationPattern {
+        year: ComponentPattern::Any,
+        month: ComponentPattern::Any,
+        day: ComponentPattern::Any,
+        hour: ComponentPattern::Any,
+        minute: ComponentPattern::Any,
+        second: ComponentPattern::Any,
+    };
+
+    /// Match durations without `year`, `month` and `day`
+    pub const TIME_ONLY: DurationPattern = DurationPattern {
+        year: ComponentPattern::Zero,
+        month: ComponentPattern::Zero,
+        day: ComponentPattern::Zero,
+        ..DurationPattern::ANY
+    };
+
+    /// Replace the pattern of the `unit` component
+    pub fn with(mut self, unit: Unit, pattern: ComponentPattern) -> Self {
+        match unit {
+            Unit::Year => self.year = pattern,
+            #[cfg(feature = "extensions")]
+            Unit::Quarter => self.month = pattern,
+            Unit::Month => self.month = pattern,
+            Unit::Day => self.day = pattern,
+            Unit::Hour => self.hour = pattern,
+            Unit::Minute => self.minute = pattern,
+            Unit::Second => self.second = pattern,
+        }
+        self
+    }
+
+    /// Return `true` if every component of `duration` matches its pattern
+    pub fn matches(&self, duration: &Duration) -> bool {
+        let patterns = [
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        ];
+
+        duration
+            .components()
+            .iter()
+            .zip(&patterns)
+            .all(|((_, value), pattern)| pattern.matches(*value))
+    }
+}
+
+impl Duration {
+    /// Return `true` if the duration matches `pattern`, see
+    /// [`DurationPattern`]
+    pub fn matches_pattern(&self, pattern: &DurationPattern) -> bool {
+        pattern.matches(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_components() {
+        let nan = Duration::new(0., 0., f32::NAN, 0., 0., 0.);
+        assert!(nan.matches_pattern(&DurationPattern::ANY));
+        assert!(
+            !nan.matches_pattern(&DurationPattern::ANY.with(Unit::Day, ComponentPattern::NonZero))
+        );
+        assert!(!nan.matches_pattern(
+            &DurationPattern::ANY.with(Unit::Day, ComponentPattern::at_least(0.))
+        ));
+
+        let pattern = DurationPattern::ANY
+            .with(Unit::Month, ComponentPattern::between(1., 3.))
+            .with(Unit::Second, ComponentPattern::Exactly(0.5));
+        assert!(Duration::new(1., 3., 0., 0., 0., 0.5).matches_pattern(&pattern));
+        assert!(!Duration::new(0., 0.5, 0., 0., 0., 0.5).matches_pattern(&pattern));
+        assert!(!Duration::new(0., 1., 0., 0., 0., 0.).matches_pattern(&pattern));
+
+        assert!(Duration::ZERO.matches_pattern(&DurationPattern::TIME_ONLY));
+        assert!(!Duration::ZERO.matches_pattern(
+            &DurationPattern::ANY.with(Unit::Hour, ComponentPattern::at_most(-1.))
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use time::OffsetDateTime;
+
+use crate::Duration;
+
+/// A rate of `count` events per `per`, such as "1000 requests per `P1D`".
+///
+/// Windows start at an anchor and follow each other with calendar
+/// arithmetic, see [`Duration::nth_from`], so a daily quota resets at the
+/// same time of day and a monthly quota on the same day of the month,
+/// instead of every 86400 seconds or every 30 days.
+///
+/// ```rust
+/// use iso8601_duration::RatePer;
+/// use time::macros::datetime;
+///
+/// let rate = RatePer::new(1000, "P1M".parse().unwrap());
+/// let start = datetime!(2024-01-31 00:00:00 UTC);
+///
+/// // windows start on January 31, February 29 and March 31
+/// assert_eq!(rate.allowance_between(start, datetime!(2024-03-01 00:00:00 UTC)), Some(2000));
+/// assert_eq!(rate.allowance_between(start, datetime!(2024-03-31 00:00:00 UTC)), Some(2000));
+/// assert_eq!(rate.allowance_between(start, datetime!(2024-03-31 00:00:01 UTC)), Some(3000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RatePer {
+    pub count: u32,
+    pub per: Duration,
+}
+
+impl RatePer {
+    /// Create a rate of `count` events per `per`
+    pub const fn new(count: u32, per: Duration) -> Self {
+        RatePer { count, per }
+    }
+
+    /// Return how many events are allowed from `start` to `end`, which is
+    /// `count` for each window starting in this range, the first one
+    /// starting at `start`.
+    ///
+    /// This method will return `None` if the duration is empty, since the
+    /// number of windows would be unbounded.
+    pub fn allowance_between(&self, start: OffsetDateTime, end: OffsetDateTime) -> Option<u64> {
+        let windows = self.windows_between(start, end)?;
+
+        Some(windows * self.count as u64)
+    }
+
+    /// Return the window containing `now`, for windows following each
+    /// other from `anchor`, or `None` if the duration is empty or `now` is
+    /// before `anchor`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::RatePer;
+    /// use time::macros::datetime;
+    ///
+    /// let rate = RatePer::new(100, "P1D".parse().unwrap());
+    /// let anchor = datetime!(2024-03-01 06:00:00 UTC);
+    /// assert_eq!(
+    ///     rate.window_at(anchor, datetime!(2024-03-03 05:00:00 UTC)),
+    ///     Some((datetime!(2024-03-02 06:00:00 UTC), datetime!(2024-03-03 06:00:00 UTC)))
+    /// );
+    /// ```
+    pub fn window_at(
+        &self,
+        anchor: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Option<(OffsetDateTime, OffsetDateTime)> {
+        if now < anchor {
+            return None;
+        }
+
+        let n = self.per.count_between(anchor, now)?;
+        Some((
+            self.per.nth_from(anchor, n),
+            self.per.nth_from(anchor, n.saturating_add(1)),
+        ))
+    }
+
+    fn windows_between(&self, start: OffsetDateTime, end: OffsetDateTime) -> Option<u64> {
+        let n = self.per.count_between(start, end)?;
+        if end <= start {
+            return Some(0);
+        }
+
+        // the window starting exactly at `end` isn't included
+        if self.per.nth_from(start, n) == end {
+            Some(n as u64)
+        } else {
+            Some(n as u64 + 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn allowance() {
+        let daily = RatePer::new(1000, "P1D".parse().unwrap());
+        let start = datetime!(2024-03-30 12:00:00 UTC);
+
+        assert_eq!(daily.allowance_between(start, start), Some(0));
+        assert_eq!(
+            daily.allowance_between(start, datetime!(2024-03-29 00:00:00 UTC)),
+            Some(0)
+        );
+        assert_eq!(
+            daily.allowance_between(start, datetime!(2024-03-30 12:00:01 UTC)),
+            Some(1000)
+        );
+        assert_eq!(
+            daily.allowance_between(start, datetime!(2024-03-31 12:00:00 UTC)),
+            Some(1000)
+        );
+        assert_eq!(
+            daily.allowance_between(start, datetime!(2024-04-02 00:00:00 UTC)),
+            Some(3000)
+        );
+
+        let yearly = RatePer::new(u32::MAX, "P1Y".parse().unwrap());
+        let leap_day = datetime!(2024-02-29 00:00:00 UTC);
+        assert_eq!(
+            yearly.allowance_between(leap_day, datetime!(2025-02-28 00:00:01 UTC)),
+            Some(2 * u32::MAX as u64)
+        );
+
+        let empty = RatePer::new(1, Duration::ZERO);
+        assert_eq!(empty.allowance_between(start, start), None);
+        assert_eq!(empty.window_at(start, start), None);
+    }
+}
@@ -0,0 +1,112 @@
+use std::convert::TryFrom;
+
+use time::OffsetDateTime;
+
+use crate::{Duration, Rounding};
+
+/// A time to live for Redis, see [`Duration::to_redis_ttl_at`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RedisTtl {
+    /// Whole seconds, for `EXPIRE` or `SET ... EX`
+    pub seconds: u64,
+    /// Whole milliseconds, for `PEXPIRE` or `SET ... PX`
+    pub milliseconds: u64,
+}
+
+impl Duration {
+    /// Return the time to live of a key set at `anchor`, with calendar
+    /// components resolved at `anchor`, so `P1M` set in February expires
+    /// sooner than in March.
+    ///
+    /// Seconds and milliseconds are both rounded with `rounding`. A
+    /// timeout of zero deletes the key, which may happen for sub-second
+    /// durations rounded down, `Rounding::Ceil` never gives zero. This
+    /// method will return `None` if the duration isn't positive at
+    /// `anchor`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, RedisTtl, Rounding};
+    /// use time::macros::datetime;
+    ///
+    /// let duration: Duration = "P1MT0.5S".parse().unwrap();
+    /// let ttl = duration.to_redis_ttl_at(datetime!(2023-02-01 00:00 UTC), Rounding::Floor);
+    /// assert_eq!(
+    ///     ttl,
+    ///     Some(RedisTtl { seconds: 28 * 86400, milliseconds: 28 * 86400_000 + 500 })
+    /// );
+    ///
+    /// let ttl = duration.to_redis_ttl_at(datetime!(2023-03-01 00:00 UTC), Rounding::Ceil);
+    /// assert_eq!(ttl.unwrap().seconds, 31 * 86400 + 1);
+    /// ```
+    pub fn to_redis_ttl_at(&self, anchor: OffsetDateTime, rounding: Rounding) -> Option<RedisTtl> {
+        let nanos = self.checked_add_to(anchor)? - anchor;
+        let nanos = nanos.whole_nanoseconds();
+        if nanos <= 0 {
+            return None;
+        }
+
+        let round = |unit: i128| {
+            let (quotient, remainder) = (nanos / unit, nanos % unit);
+            let up = match rounding {
+                Rounding::Floor => false,
+                Rounding::Ceil => remainder > 0,
+                Rounding::Nearest => remainder * 2 >= unit,
+            };
+            u64::try_from(quotient + up as i128).ok()
+        };
+
+        Some(RedisTtl {
+            seconds: round(1_000_000_000)?,
+            milliseconds: round(1_000_000)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn redis_ttl() {
+        let anchor = datetime!(2024-01-31 12:00 UTC);
+
+        let duration: Duration = "PT0.0015S".parse().unwrap();
+        assert_eq!(
+            duration.to_redis_ttl_at(anchor, Rounding::Floor),
+            Some(RedisTtl {
+                seconds: 0,
+                milliseconds: 1
+            })
+        );
+        assert_eq!(
+            duration.to_redis_ttl_at(anchor, Rounding::Nearest),
+            Some(RedisTtl {
+                seconds: 0,
+                milliseconds: 2
+            })
+        );
+        assert_eq!(
+            duration.to_redis_ttl_at(anchor, Rounding::Ceil),
+            Some(RedisTtl {
+                seconds: 1,
+                milliseconds: 2
+            })
+        );
+
+        let month: Duration = "P1M".parse().unwrap();
+        assert_eq!(
+            month
+                .to_redis_ttl_at(anchor, Rounding::Nearest)
+                .map(|ttl| ttl.seconds),
+            Some(29 * 86400)
+        );
+
+        assert_eq!(Duration::ZERO.to_redis_ttl_at(anchor, Rounding::Ceil), None);
+        assert_eq!((-month).to_redis_ttl_at(anchor, Rounding::Ceil), None);
+        assert_eq!(
+            Duration::new(0., 0., f32::NAN, 0., 0., 0.).to_redis_ttl_at(anchor, Rounding::Ceil),
+            None
+        );
+    }
+}
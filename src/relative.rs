@@ -0,0 +1,83 @@
+use crate::{Duration, Unit};
+
+/// Which side of the reference point a relative duration is on, see
+/// [`Duration::to_relative_string`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// After the reference point: "in 3 days".
+    Future,
+    /// Before the reference point: "3 days ago".
+    Past,
+}
+
+fn unit_names(unit: Unit) -> (&'static str, &'static str) {
+    match unit {
+        Unit::Year => ("year", "years"),
+        #[cfg(feature = "extensions")]
+        Unit::Quarter => ("quarter", "quarters"),
+        Unit::Month => ("month", "months"),
+        Unit::Day => ("day", "days"),
+        Unit::Hour => ("hour", "hours"),
+        Unit::Minute => ("minute", "minutes"),
+        Unit::Second => ("second", "seconds"),
+    }
+}
+
+impl Duration {
+    /// Render the duration as an English phrase relative to now, such as
+    /// "in 3 days" or "2 months ago".
+    ///
+    /// Only the largest non-zero component is rendered, so `P1DT12H` is
+    /// "in 1 day". A negative component counts in the other direction, and
+    /// an empty duration is "now".
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Direction, Duration};
+    ///
+    /// let duration: Duration = "P3DT4H".parse().unwrap();
+    /// assert_eq!(duration.to_relative_string(Direction::Future), "in 3 days");
+    /// assert_eq!(duration.to_relative_string(Direction::Past), "3 days ago");
+    ///
+    /// let duration = Duration::new(0., -2., 0., 0., 0., 0.);
+    /// assert_eq!(duration.to_relative_string(Direction::Future), "2 months ago");
+    /// ```
+    pub fn to_relative_string(&self, direction: Direction) -> String {
+        let (unit, value) = match self.components().iter().find(|(_, value)| *value != 0.0) {
+            Some(component) => *component,
+            None => return "now".to_string(),
+        };
+
+        let (singular, plural) = unit_names(unit);
+        let name = if value.abs() == 1.0 { singular } else { plural };
+        let phrase = format!("{} {}", value.abs(), name);
+
+        match (direction, value < 0.0) {
+            (Direction::Future, false) | (Direction::Past, true) => format!("in {}", phrase),
+            (Direction::Past, false) | (Direction::Future, true) => format!("{} ago", phrase),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_phrases() {
+        let parse = |s: &str| s.parse::<Duration>().unwrap();
+
+        assert_eq!(
+            parse("PT1H30M").to_relative_string(Direction::Future),
+            "in 1 hour"
+        );
+        assert_eq!(
+            parse("P1.5Y").to_relative_string(Direction::Past),
+            "1.5 years ago"
+        );
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 0., -1.).to_relative_string(Direction::Past),
+            "in 1 second"
+        );
+        assert_eq!(Duration::ZERO.to_relative_string(Direction::Past), "now");
+    }
+}
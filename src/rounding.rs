@@ -0,0 +1,137 @@
+#[cfg(feature = "extensions")]
+use std::convert::TryFrom;
+
+use time::{Date, Month, OffsetDateTime, Time};
+
+use crate::{Duration, Unit};
+
+/// Return the start of the `unit` containing `datetime`.
+pub(crate) fn truncate(datetime: OffsetDateTime, unit: Unit) -> OffsetDateTime {
+    let date = datetime.date();
+    let (hour, minute, second) = datetime.time().as_hms();
+
+    let start_of = |month: Month| {
+        Date::from_calendar_date(date.year(), month, 1)
+            .map(|date| datetime.replace_date(date).replace_time(Time::MIDNIGHT))
+            .unwrap_or(datetime)
+    };
+    let time_of = |hour, minute, second| {
+        Time::from_hms(hour, minute, second)
+            .map(|time| datetime.replace_time(time))
+            .unwrap_or(datetime)
+    };
+
+    match unit {
+        Unit::Year => start_of(Month::January),
+        #[cfg(feature = "extensions")]
+        Unit::Quarter => {
+            let month = (date.month() as u8 - 1) / 3 * 3 + 1;
+            start_of(Month::try_from(month).unwrap_or(Month::January))
+        }
+        Unit::Month => start_of(date.month()),
+        Unit::Day => datetime.replace_time(Time::MIDNIGHT),
+        Unit::Hour => time_of(hour, 0, 0),
+        Unit::Minute => time_of(hour, minute, 0),
+        Unit::Second => time_of(hour, minute, second),
+    }
+}
+
+/// Return a duration of exactly one `unit`.
+fn one(unit: Unit) -> Duration {
+    match unit {
+        Unit::Year => Duration::new(1., 0., 0., 0., 0., 0.),
+        #[cfg(feature = "extensions")]
+        Unit::Quarter => Duration::new(0., 3., 0., 0., 0., 0.),
+        Unit::Month => Duration::new(0., 1., 0., 0., 0., 0.),
+        Unit::Day => Duration::new(0., 0., 1., 0., 0., 0.),
+        Unit::Hour => Duration::new(0., 0., 0., 1., 0., 0.),
+        Unit::Minute => Duration::new(0., 0., 0., 0., 1., 0.),
+        Unit::Second => Duration::new(0., 0., 0., 0., 0., 1.),
+    }
+}
+
+impl Duration {
+    /// Add the duration to `datetime`, then round the result to the
+    /// nearest `unit` boundary.
+    ///
+    /// Halfway values are rounded up.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, Unit};
+    /// use time::macros::datetime;
+    ///
+    /// let duration: Duration = "PT1H40M".parse().unwrap();
+    /// assert_eq!(
+    ///     duration.add_and_round(datetime!(2023-03-15 10:00:00 UTC), Unit::Hour),
+    ///     datetime!(2023-03-15 12:00:00 UTC)
+    /// );
+    /// ```
+    pub fn add_and_round(&self, datetime: OffsetDateTime, unit: Unit) -> OffsetDateTime {
+        let result = datetime + *self;
+        let floor = truncate(result, unit);
+        if floor == result {
+            return result;
+        }
+
+        let ceil = floor + one(unit);
+        if result - floor < ceil - result {
+            floor
+        } else {
+            ceil
+        }
+    }
+
+    /// Add the duration to `datetime`, then truncate the result to the
+    /// start of its `unit`.
+    pub fn add_and_truncate(&self, datetime: OffsetDateTime, unit: Unit) -> OffsetDateTime {
+        truncate(datetime + *self, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn round_to_unit() {
+        let start = datetime!(2023-03-15 10:20:30 UTC);
+        let duration: Duration = "P16DT2H".parse().unwrap();
+
+        assert_eq!(
+            duration.add_and_round(start, Unit::Month),
+            datetime!(2023-04-01 00:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_and_round(start, Unit::Day),
+            datetime!(2023-04-01 00:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_and_round(start, Unit::Hour),
+            datetime!(2023-03-31 12:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_and_round(start, Unit::Minute),
+            datetime!(2023-03-31 12:21:00 UTC)
+        );
+    }
+
+    #[test]
+    fn truncate_to_unit() {
+        let start = datetime!(2023-03-15 10:20:30 UTC);
+        let duration: Duration = "P16DT2H".parse().unwrap();
+
+        assert_eq!(
+            duration.add_and_truncate(start, Unit::Year),
+            datetime!(2023-01-01 00:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_and_truncate(start, Unit::Day),
+            datetime!(2023-03-31 00:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_and_truncate(start, Unit::Hour),
+            datetime!(2023-03-31 12:00:00 UTC)
+        );
+    }
+}
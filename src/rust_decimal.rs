@@ -0,0 +1,330 @@
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+
+use crate::{
+    exact::{self, from_decimals, read_components},
+    seconds::decimal_parts,
+    Duration, ExactDuration, ExactDurationError, Unit,
+};
+
+/// A duration whose components are `rust_decimal::Decimal`, for exact
+/// decimal arithmetic.
+///
+/// Every component keeps the digits it was written with, up to the 28
+/// fractional digits and 96-bit mantissa of `Decimal`. Unlike
+/// [`ExactDuration`], fractions aren't pushed down to smaller units, so
+/// `P0.5M` or `PT0.0000000001S` are kept as they are, and additions,
+/// subtractions and conversions to seconds are exact or return `None`.
+///
+/// ```rust
+/// use iso8601_duration::DecimalDuration;
+/// use rust_decimal::Decimal;
+///
+/// let a: DecimalDuration = "PT0.1S".parse().unwrap();
+/// let b: DecimalDuration = "PT0.2S".parse().unwrap();
+/// let sum = a.checked_add(&b).unwrap();
+/// assert_eq!(sum.to_string(), "PT0.3S");
+/// assert_eq!(sum.total_seconds(), Some(Decimal::new(3, 1)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalDuration {
+    pub year: Decimal,
+    pub month: Decimal,
+    pub day: Decimal,
+    pub hour: Decimal,
+    pub minute: Decimal,
+    pub second: Decimal,
+}
+
+impl DecimalDuration {
+    /// Parse given string into a `DecimalDuration`, accepting the same
+    /// syntax as [`Duration::parse`].
+    ///
+    /// This method will return [`ExactDurationError::Inexact`] if a
+    /// component has more than 28 fractional digits, and
+    /// [`ExactDurationError::OutOfRange`] if it has too many digits for
+    /// `Decimal`.
+    pub fn parse(input: &str) -> Result<DecimalDuration, ExactDurationError> {
+        let (negative, components) = read_components(input)?;
+
+        let mut duration = DecimalDuration::default();
+        for ((unit, component), digits) in
+            IntoIterator::into_iter(duration.components_mut()).zip(components)
+        {
+            *component = to_decimal(unit, digits)?;
+        }
+
+        Ok(if negative { -duration } else { duration })
+    }
+
+    fn components(&self) -> [(Unit, Decimal); 6] {
+        [
+            (Unit::Year, self.year),
+            (Unit::Month, self.month),
+            (Unit::Day, self.day),
+            (Unit::Hour, self.hour),
+            (Unit::Minute, self.minute),
+            (Unit::Second, self.second),
+        ]
+    }
+
+    fn components_mut(&mut self) -> [(Unit, &mut Decimal); 6] {
+        [
+            (Unit::Year, &mut self.year),
+            (Unit::Month, &mut self.month),
+            (Unit::Day, &mut self.day),
+            (Unit::Hour, &mut self.hour),
+            (Unit::Minute, &mut self.minute),
+            (Unit::Second, &mut self.second),
+        ]
+    }
+
+    /// Add two durations component-wise, returning `None` on overflow
+    pub fn checked_add(&self, other: &DecimalDuration) -> Option<DecimalDuration> {
+        Some(DecimalDuration {
+            year: self.year.checked_add(other.year)?,
+            month: self.month.checked_add(other.month)?,
+            day: self.day.checked_add(other.day)?,
+            hour: self.hour.checked_add(other.hour)?,
+            minute: self.minute.checked_add(other.minute)?,
+            second: self.second.checked_add(other.second)?,
+        })
+    }
+
+    /// Subtract two durations component-wise, returning `None` on overflow
+    pub fn checked_sub(&self, other: &DecimalDuration) -> Option<DecimalDuration> {
+        self.checked_add(&-*other)
+    }
+
+    /// Multiply each component by `n`, returning `None` on overflow
+    pub fn checked_mul(&self, n: Decimal) -> Option<DecimalDuration> {
+        Some(DecimalDuration {
+            year: self.year.checked_mul(n)?,
+            month: self.month.checked_mul(n)?,
+            day: self.day.checked_mul(n)?,
+            hour: self.hour.checked_mul(n)?,
+            minute: self.minute.checked_mul(n)?,
+            second: self.second.checked_mul(n)?,
+        })
+    }
+
+    /// Return the exact number of seconds in the duration, a day counting
+    /// as 24 hours.
+    ///
+    /// This method will return `None` if the duration contains `year` or
+    /// `month`, or on overflow.
+    pub fn total_seconds(&self) -> Option<Decimal> {
+        if !self.year.is_zero() || !self.month.is_zero() {
+            return None;
+        }
+
+        self.day
+            .checked_mul(Decimal::from(86_400))?
+            .checked_add(self.hour.checked_mul(Decimal::from(3_600))?)?
+            .checked_add(self.minute.checked_mul(Decimal::from(60))?)?
+            .checked_add(self.second)
+    }
+}
+
+/// Convert the digits of a component to `Decimal`.
+fn to_decimal(unit: Unit, (digits, scale): exact::Decimal) -> Result<Decimal, ExactDurationError> {
+    Decimal::try_from_i128_with_scale(digits, scale).map_err(|_| {
+        if scale > Decimal::MAX_SCALE {
+            ExactDurationError::Inexact(unit)
+        } else {
+            ExactDurationError::OutOfRange(unit)
+        }
+    })
+}
+
+impl std::ops::Neg for DecimalDuration {
+    type Output = DecimalDuration;
+
+    fn neg(self) -> DecimalDuration {
+        DecimalDuration {
+            year: -self.year,
+            month: -self.month,
+            day: -self.day,
+            hour: -self.hour,
+            minute: -self.minute,
+            second: -self.second,
+        }
+    }
+}
+
+impl FromStr for DecimalDuration {
+    type Err = ExactDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DecimalDuration::parse(s)
+    }
+}
+
+/// Formats as the shortest exact ISO8601 string, without trailing zeros,
+/// in the same layout as `Duration`.
+impl fmt::Display for DecimalDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components = self.components();
+        if components.iter().all(|(_, value)| value.is_zero()) {
+            return f.write_str("PT0S");
+        }
+
+        // write `-P1D` rather than `P-1D` when every component is negative
+        let negative = components
+            .iter()
+            .all(|(_, value)| value.is_zero() || value.is_sign_negative());
+        let duration = if negative { -*self } else { *self };
+
+        f.write_str(if negative { "-P" } else { "P" })?;
+        let components = duration.components();
+        for (i, (unit, value)) in components.iter().enumerate() {
+            if i == 3 && components[3..].iter().any(|(_, value)| !value.is_zero()) {
+                f.write_str("T")?;
+            }
+            if !value.is_zero() {
+                write!(f, "{}{}", value.normalize(), unit.designator())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ExactDuration> for DecimalDuration {
+    fn from(exact: ExactDuration) -> Self {
+        DecimalDuration {
+            year: Decimal::from(exact.year),
+            month: Decimal::from(exact.month),
+            day: Decimal::from(exact.day),
+            hour: Decimal::from(exact.hour),
+            minute: Decimal::from(exact.minute),
+            second: Decimal::from(exact.second) + Decimal::new(exact.nanosecond as i64, 9),
+        }
+    }
+}
+
+/// Fails like [`ExactDuration::parse`] on the same digits, e.g. on a
+/// fraction of a month or of a nanosecond.
+impl TryFrom<DecimalDuration> for ExactDuration {
+    type Error = ExactDurationError;
+
+    fn try_from(duration: DecimalDuration) -> Result<Self, Self::Error> {
+        let components = duration
+            .components()
+            .map(|(_, value)| (value.mantissa(), value.scale()));
+
+        from_decimals(components)
+    }
+}
+
+/// Converts each component from its shortest decimal form, the one
+/// `Display` writes, so `PT0.1S` gives exactly `0.1` seconds.
+impl TryFrom<Duration> for DecimalDuration {
+    type Error = ExactDurationError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        let mut decimal = DecimalDuration::default();
+        for ((unit, component), value) in IntoIterator::into_iter(decimal.components_mut())
+            .zip(duration.components().map(|(_, value)| value))
+        {
+            let digits = decimal_parts(value).ok_or(ExactDurationError::OutOfRange(unit))?;
+            *component = to_decimal(unit, digits)?;
+        }
+
+        Ok(decimal)
+    }
+}
+
+/// Rounds each component to the nearest `f32`.
+impl From<DecimalDuration> for Duration {
+    fn from(decimal: DecimalDuration) -> Self {
+        // any `Decimal` is within the range of `f32`
+        let to_f32 = |value: Decimal| f32::try_from(value).unwrap_or(f32::NAN);
+
+        Duration::new(
+            to_f32(decimal.year),
+            to_f32(decimal.month),
+            to_f32(decimal.day),
+            to_f32(decimal.hour),
+            to_f32(decimal.minute),
+            to_f32(decimal.second),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        for input in [
+            "PT0S",
+            "P1Y2M3DT4H5M6S",
+            "P0.5M",
+            "PT0.0000000001S",
+            "PT123456789.123456789S",
+            "-P1DT0.5S",
+            "P1DT-12H",
+        ] {
+            assert_eq!(DecimalDuration::parse(input).unwrap().to_string(), input);
+        }
+
+        let parse = |input: &str| DecimalDuration::parse(input).map(|d| d.to_string());
+        assert_eq!(parse("P2W").unwrap(), "P14D");
+        assert_eq!(parse("PT1,50S").unwrap(), "PT1.5S");
+        assert_eq!(
+            parse("P0003-06-04T12:30:05.5").unwrap(),
+            "P3Y6M4DT12H30M5.5S"
+        );
+        assert_eq!(
+            parse("PT0.00000000000000000000000000001S"),
+            Err(ExactDurationError::Inexact(Unit::Second))
+        );
+        assert_eq!(
+            parse("P99999999999999999999999999999D"),
+            Err(ExactDurationError::OutOfRange(Unit::Day))
+        );
+        assert!(matches!(parse("P1X"), Err(ExactDurationError::Parse(_))));
+    }
+
+    #[test]
+    fn exact_arithmetic() {
+        let tenth: DecimalDuration = "PT0.1S".parse().unwrap();
+        let sum = (0..10).try_fold(DecimalDuration::default(), |sum, _| sum.checked_add(&tenth));
+        assert_eq!(sum, Some("PT1S".parse().unwrap()));
+        assert_eq!(
+            tenth.checked_sub(&tenth.checked_mul(Decimal::from(3)).unwrap()),
+            Some("-PT0.2S".parse().unwrap())
+        );
+
+        let duration: DecimalDuration = "P1DT1H0.000000000001S".parse().unwrap();
+        assert_eq!(
+            duration.total_seconds(),
+            Some(Decimal::from_str("90000.000000000001").unwrap())
+        );
+        assert_eq!(DecimalDuration::parse("P1M").unwrap().total_seconds(), None);
+    }
+
+    #[test]
+    fn conversions() {
+        let exact = ExactDuration::parse("P1DT1.000000001S").unwrap();
+        let decimal = DecimalDuration::from(exact);
+        assert_eq!(decimal.to_string(), "P1DT1.000000001S");
+        assert_eq!(ExactDuration::try_from(decimal), Ok(exact));
+        assert_eq!(
+            ExactDuration::try_from(DecimalDuration::parse("P0.5M").unwrap()),
+            Err(ExactDurationError::Inexact(Unit::Month))
+        );
+
+        let duration: Duration = "P1DT0.1S".parse().unwrap();
+        let decimal = DecimalDuration::try_from(duration).unwrap();
+        assert_eq!(decimal.second, Decimal::new(1, 1));
+        assert_eq!(Duration::from(decimal), duration);
+        assert_eq!(
+            DecimalDuration::try_from(Duration::new(0., 0., f32::NAN, 0., 0., 0.)),
+            Err(ExactDurationError::OutOfRange(Unit::Day))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::{error::Error, fmt};
+
+use crate::{Duration, Unit};
+
+/// What to do with NaN and infinite components in [`Duration::sanitize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonFinitePolicy {
+    /// Fail on the first non-finite component.
+    #[default]
+    Reject,
+    /// Replace NaN with zero and infinities with the largest finite value
+    /// of the same sign.
+    Clamp,
+}
+
+/// The components [`Duration::sanitize`] changed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SanitizeReport {
+    /// Components that were `-0.0` and are now `0.0`.
+    pub negative_zeros: Vec<Unit>,
+    /// Components that were NaN or infinite and have been clamped.
+    pub clamped: Vec<Unit>,
+}
+
+impl SanitizeReport {
+    /// Return `true` if nothing was changed
+    pub fn is_clean(&self) -> bool {
+        self.negative_zeros.is_empty() && self.clamped.is_empty()
+    }
+}
+
+/// Error returned by [`Duration::sanitize`] when a component isn't finite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFiniteError {
+    /// The first non-finite component
+    pub unit: Unit,
+}
+
+impl fmt::Display for NonFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} component is not a finite number", self.unit)
+    }
+}
+
+impl Error for NonFiniteError {}
+
+impl Duration {
+    /// Make the duration safe for arithmetic, reporting what was fixed.
+    ///
+    /// `-0.0` components become `0.0`, and NaN or infinite components are
+    /// handled according to `policy`. With [`NonFinitePolicy::Reject`],
+    /// the duration is left untouched on error.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, NonFinitePolicy, Unit};
+    ///
+    /// let mut duration = Duration::new(-0., 1., f32::NAN, 0., 0., f32::INFINITY);
+    /// assert!(duration.sanitize(NonFinitePolicy::Reject).is_err());
+    ///
+    /// let report = duration.sanitize(NonFinitePolicy::Clamp).unwrap();
+    /// assert_eq!(report.negative_zeros, vec![Unit::Year]);
+    /// assert_eq!(report.clamped, vec![Unit::Day, Unit::Second]);
+    /// assert_eq!(duration, Duration::new(0., 1., 0., 0., 0., f32::MAX));
+    /// ```
+    pub fn sanitize(&mut self, policy: NonFinitePolicy) -> Result<SanitizeReport, NonFiniteError> {
+        if policy == NonFinitePolicy::Reject {
+            if let Some((unit, _)) = self
+                .components()
+                .iter()
+                .find(|(_, value)| !value.is_finite())
+            {
+                return Err(NonFiniteError { unit: *unit });
+            }
+        }
+
+        let mut report = SanitizeReport::default();
+
+        for (unit, value) in self.components_mut() {
+            if *value == 0.0 && value.is_sign_negative() {
+                *value = 0.0;
+                report.negative_zeros.push(unit);
+            } else if value.is_nan() {
+                *value = 0.0;
+                report.clamped.push(unit);
+            } else if value.is_infinite() {
+                *value = if value.is_sign_positive() {
+                    f32::MAX
+                } else {
+                    f32::MIN
+                };
+                report.clamped.push(unit);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_clean_duration() {
+        let mut duration: Duration = "P1DT2H".parse().unwrap();
+        let report = duration.sanitize(NonFinitePolicy::Reject).unwrap();
+
+        assert!(report.is_clean());
+        assert_eq!(duration, "P1DT2H".parse().unwrap());
+    }
+
+    #[test]
+    fn sanitize_rejects_non_finite() {
+        let mut duration = Duration::new(-0., 0., 0., f32::NEG_INFINITY, f32::NAN, 0.);
+
+        assert_eq!(
+            duration.sanitize(NonFinitePolicy::Reject),
+            Err(NonFiniteError { unit: Unit::Hour })
+        );
+        assert!(duration.year.is_sign_negative());
+
+        let report = duration.sanitize(NonFinitePolicy::Clamp).unwrap();
+        assert_eq!(report.clamped, vec![Unit::Hour, Unit::Minute]);
+        assert_eq!(duration, Duration::new(0., 0., 0., f32::MIN, 0., 0.));
+    }
+}
@@ -0,0 +1,136 @@
+use std::{error::Error, fmt};
+
+use crate::{Duration, Unit};
+
+/// Error returned by [`Duration::scale_exact`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleError {
+    /// The denominator is zero.
+    ZeroDenominator,
+    /// A component of the duration isn't a whole number.
+    NotWhole(Unit),
+    /// A component doesn't scale to a whole number of itself or of the
+    /// smaller units it can be converted to exactly.
+    Inexact(Unit),
+    /// A scaled component is too large to be stored exactly.
+    OutOfRange(Unit),
+}
+
+impl fmt::Display for ScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaleError::ZeroDenominator => f.write_str("denominator is zero"),
+            ScaleError::NotWhole(unit) => write!(f, "{:?} component isn't a whole number", unit),
+            ScaleError::Inexact(unit) => write!(f, "{:?} component doesn't scale exactly", unit),
+            ScaleError::OutOfRange(unit) => {
+                write!(f, "scaled {:?} component is out of range", unit)
+            }
+        }
+    }
+}
+
+impl Error for ScaleError {}
+
+/// Largest integer below which every integer is exact as `f32`.
+const MAX_EXACT: i128 = 1 << 24;
+
+impl Duration {
+    /// Multiply the duration by `num / denom`, only when the result is
+    /// exact, for proration that must refuse lossy scaling.
+    ///
+    /// Components must be whole numbers. A remainder is carried down to
+    /// the next unit when the conversion is exact: years to months, days
+    /// to hours, hours to minutes and minutes to seconds, so `P1Y` scaled
+    /// by 1/2 is `P6M`. A remainder of months can't be carried to days,
+    /// and a remainder of seconds is kept as a fraction only when it's
+    /// exact in binary, such as `0.5` or `0.25`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, ScaleError, Unit};
+    ///
+    /// let scale = |input: &str, num, denom| {
+    ///     Duration::parse(input).unwrap().scale_exact(num, denom).map(|d| d.to_string())
+    /// };
+    ///
+    /// assert_eq!(scale("P1Y", 1, 2), Ok("P6M".to_string()));
+    /// assert_eq!(scale("P1DT1H", 1, 3), Ok("PT8H20M".to_string()));
+    /// assert_eq!(scale("PT1S", 1, 4), Ok("PT0.25S".to_string()));
+    /// assert_eq!(scale("P1M", 1, 2), Err(ScaleError::Inexact(Unit::Month)));
+    /// assert_eq!(scale("PT1S", 1, 3), Err(ScaleError::Inexact(Unit::Second)));
+    /// ```
+    pub fn scale_exact(&self, num: u32, denom: u32) -> Result<Duration, ScaleError> {
+        if denom == 0 {
+            return Err(ScaleError::ZeroDenominator);
+        }
+        let (num, denom) = (num as i128, denom as i128);
+
+        let mut scaled = Duration::ZERO;
+        // remainder of the previous component, in units of the current one
+        // and over `denom`
+        let mut carry = 0;
+        for ((unit, value), (_, result)) in self.components().iter().zip(scaled.components_mut()) {
+            if !value.is_finite() || value.fract() != 0. || value.abs() >= MAX_EXACT as f32 {
+                return Err(ScaleError::NotWhole(*unit));
+            }
+
+            let total = *value as i128 * num + carry;
+            let (quotient, remainder) = (total / denom, total % denom);
+            if quotient.abs() >= MAX_EXACT {
+                return Err(ScaleError::OutOfRange(*unit));
+            }
+            *result = quotient as f32;
+
+            carry = match unit {
+                Unit::Year => remainder * 12,
+                Unit::Day => remainder * 24,
+                Unit::Hour | Unit::Minute => remainder * 60,
+                Unit::Second if remainder != 0 => {
+                    let seconds = total as f64 / denom as f64;
+                    if seconds as f32 as f64 * denom as f64 != total as f64 {
+                        return Err(ScaleError::Inexact(*unit));
+                    }
+                    *result = seconds as f32;
+                    0
+                }
+                _ if remainder != 0 => return Err(ScaleError::Inexact(*unit)),
+                _ => 0,
+            };
+        }
+
+        Ok(scaled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_exact() {
+        let duration: Duration = "P2Y4M6DT8H10M12S".parse().unwrap();
+        assert_eq!(
+            duration.scale_exact(1, 2),
+            Ok("P1Y2M3DT4H5M6S".parse().unwrap())
+        );
+        assert_eq!(duration.scale_exact(3, 1), Ok(duration * 3.));
+        assert_eq!(duration.scale_exact(0, 5), Ok(Duration::ZERO));
+
+        assert_eq!(
+            Duration::parse("-P1Y").unwrap().scale_exact(1, 4),
+            Ok(Duration::new(0., -3., 0., 0., 0., 0.))
+        );
+        assert_eq!(
+            Duration::parse("PT1M").unwrap().scale_exact(1, 7),
+            Err(ScaleError::Inexact(Unit::Second))
+        );
+        assert_eq!(
+            Duration::parse("P1.5D").unwrap().scale_exact(1, 2),
+            Err(ScaleError::NotWhole(Unit::Day))
+        );
+        assert_eq!(
+            Duration::hours(16_000_000).scale_exact(2, 1),
+            Err(ScaleError::OutOfRange(Unit::Hour))
+        );
+        assert_eq!(duration.scale_exact(1, 0), Err(ScaleError::ZeroDenominator));
+    }
+}
@@ -0,0 +1,497 @@
+use time::OffsetDateTime;
+
+use crate::Duration;
+
+/// Iterator returned by [`Duration::periods_between`].
+#[derive(Debug, Clone)]
+pub struct Periods {
+    duration: Duration,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    n: u32,
+    done: bool,
+}
+
+impl Iterator for Periods {
+    type Item = (OffsetDateTime, OffsetDateTime);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let from = self.duration.nth_from(self.start, self.n);
+        let to = self.duration.nth_from(self.start, self.n.saturating_add(1));
+        if from >= self.end || to <= from || self.n == u32::MAX {
+            self.done = true;
+            return None;
+        }
+
+        self.n += 1;
+        Some((from, to.min(self.end)))
+    }
+}
+
+impl Duration {
+    /// Return the `n`-th occurrence of the duration after `anchor`.
+    ///
+    /// The duration is multiplied by `n` and applied once, rather than
+    /// added `n` times, so month-end clamping doesn't accumulate: the third
+    /// monthly occurrence after January 31 is April 30, not April 28.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let monthly: Duration = "P1M".parse().unwrap();
+    /// let anchor = datetime!(2023-01-31 00:00:00 UTC);
+    /// assert_eq!(monthly.nth_from(anchor, 3), datetime!(2023-04-30 00:00:00 UTC));
+    /// ```
+    pub fn nth_from(&self, anchor: OffsetDateTime, n: u32) -> OffsetDateTime {
+        let n = n as f32;
+
+        anchor
+            + Duration::new(
+                self.year * n,
+                self.month * n,
+                self.day * n,
+                self.hour * n,
+                self.minute * n,
+                self.second * n,
+            )
+    }
+
+    /// Return how many whole periods have elapsed between `anchor` and `now`.
+    ///
+    /// This is the largest `n` such that `nth_from(anchor, n) <= now`, or
+    /// zero when `now` is before `anchor`.
+    ///
+    /// This method will return `None` if the duration is empty, since the
+    /// count would be unbounded.
+    pub fn count_between(&self, anchor: OffsetDateTime, now: OffsetDateTime) -> Option<u32> {
+        let period = (anchor + *self - anchor).as_seconds_f64();
+        if period <= 0. {
+            return None;
+        }
+
+        let elapsed = (now - anchor).as_seconds_f64();
+        if elapsed <= 0. {
+            return Some(0);
+        }
+
+        // estimate from the first period, then from the average period
+        // length over the estimate, so only a few steps are left to walk
+        let mut n = (elapsed / period) as u32;
+        if n > 1 {
+            let average = (self.nth_from(anchor, n) - anchor).as_seconds_f64() / n as f64;
+            n = (elapsed / average) as u32;
+        }
+
+        while n > 0 && self.nth_from(anchor, n) > now {
+            n -= 1;
+        }
+        while n < u32::MAX && self.nth_from(anchor, n + 1) <= now {
+            n += 1;
+        }
+
+        Some(n)
+    }
+
+    /// Return how many whole calendar months have elapsed from `start` to
+    /// `end`, or zero when `end` is before `start`.
+    ///
+    /// Months are added with the clamping of `+`, so a month after January
+    /// 31 is complete on February 28, and the count is the same as
+    /// `P1M`'s [`count_between`](Duration::count_between).
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let start = datetime!(2023-01-31 00:00:00 UTC);
+    /// assert_eq!(Duration::whole_months_between(start, datetime!(2023-02-28 00:00:00 UTC)), 1);
+    /// assert_eq!(Duration::whole_months_between(start, datetime!(2023-04-29 23:59:59 UTC)), 2);
+    /// ```
+    pub fn whole_months_between(start: OffsetDateTime, end: OffsetDateTime) -> u32 {
+        Duration::months(1)
+            .count_between(start, end)
+            .unwrap_or_default()
+    }
+
+    /// Return how many whole calendar days have elapsed from `start` to
+    /// `end`, or zero when `end` is before `start`.
+    ///
+    /// A day ends at the same local time on the next date, see
+    /// [`whole_months_between`](Duration::whole_months_between).
+    pub fn whole_days_between(start: OffsetDateTime, end: OffsetDateTime) -> u32 {
+        Duration::days(1)
+            .count_between(start, end)
+            .unwrap_or_default()
+    }
+
+    /// Return the calendar duration from `start` to `end`, broken down into
+    /// years, months, days, hours, minutes and seconds, such that
+    /// `start + Duration::between(start, end) == end`.
+    ///
+    /// Whole months are taken first, with the clamping of `+`, then whole
+    /// days and the remaining time, in the offset of `start`. When `end` is
+    /// before `start`, the result is `-Duration::between(end, start)`.
+    /// Fractions of a second are kept as `f32`, so sub-millisecond
+    /// precision may be lost.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let start = datetime!(2023-01-31 10:00:00 UTC);
+    /// let end = datetime!(2024-03-01 12:30:00 UTC);
+    /// let duration = Duration::between(start, end);
+    /// assert_eq!(duration.to_string(), "P1Y1M1DT2H30M");
+    /// assert_eq!(start + duration, end);
+    /// ```
+    pub fn between(start: OffsetDateTime, end: OffsetDateTime) -> Duration {
+        if end < start {
+            return -Duration::between(end, start);
+        }
+
+        let end = end.to_offset(start.offset());
+        let months_of =
+            |datetime: OffsetDateTime| datetime.year() as i64 * 12 + datetime.month() as i64;
+        let mut months = months_of(end) - months_of(start);
+        while months > 0 && start + Duration::months(months as u32) > end {
+            months -= 1;
+        }
+
+        let rest = end - (start + Duration::months(months as u32));
+        Duration::new(
+            (months / 12) as f32,
+            (months % 12) as f32,
+            rest.whole_days() as f32,
+            (rest.whole_hours() % 24) as f32,
+            (rest.whole_minutes() % 60) as f32,
+            (rest.whole_seconds() % 60) as f32 + rest.subsec_nanoseconds() as f32 / 1e9,
+        )
+    }
+
+    /// Return the bucket containing `datetime`, among the consecutive
+    /// periods of the duration aligned on `anchor`.
+    ///
+    /// The bucket is returned as `(start, end)`, with `start <= datetime <
+    /// end`. Buckets after `anchor` are computed with
+    /// [`nth_from`](Duration::nth_from), and the ones before by subtracting
+    /// the duration `n` times in one go. This is the building block of
+    /// time-window aggregation and rate limiting.
+    ///
+    /// This method will return `None` if the duration is empty.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let window: Duration = "PT15M".parse().unwrap();
+    /// let anchor = datetime!(2023-01-01 00:00:00 UTC);
+    /// assert_eq!(
+    ///     window.bucket_of(datetime!(2023-03-01 10:20:00 UTC), anchor),
+    ///     Some((datetime!(2023-03-01 10:15:00 UTC), datetime!(2023-03-01 10:30:00 UTC)))
+    /// );
+    /// ```
+    pub fn bucket_of(
+        &self,
+        datetime: OffsetDateTime,
+        anchor: OffsetDateTime,
+    ) -> Option<(OffsetDateTime, OffsetDateTime)> {
+        if datetime >= anchor {
+            let n = self.count_between(anchor, datetime)?;
+            return Some((
+                self.nth_from(anchor, n),
+                self.nth_from(anchor, n.saturating_add(1)),
+            ));
+        }
+
+        let period = (anchor + *self - anchor).as_seconds_f64();
+        if period <= 0. {
+            return None;
+        }
+
+        let nth_before = |n: u32| anchor - self.saturating_mul(n as f32);
+
+        let mut n = ((anchor - datetime).as_seconds_f64() / period).ceil() as u32;
+        while n < u32::MAX && nth_before(n) > datetime {
+            n += 1;
+        }
+        while n > 1 && nth_before(n - 1) <= datetime {
+            n -= 1;
+        }
+
+        Some((nth_before(n), nth_before(n - 1)))
+    }
+
+    /// Split `start..end` into consecutive periods of the duration.
+    ///
+    /// Period boundaries are computed with [`nth_from`](Duration::nth_from),
+    /// so month-end clamping doesn't drift. The last period is cut short at
+    /// `end`. The iterator is empty when `end` isn't after `start` or when
+    /// the duration is empty.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let week: Duration = "P1W".parse().unwrap();
+    /// let periods: Vec<_> = week
+    ///     .periods_between(datetime!(2023-03-01 00:00 UTC), datetime!(2023-03-20 00:00 UTC))
+    ///     .collect();
+    /// assert_eq!(periods.len(), 3);
+    /// assert_eq!(
+    ///     periods[2],
+    ///     (datetime!(2023-03-15 00:00 UTC), datetime!(2023-03-20 00:00 UTC))
+    /// );
+    /// ```
+    pub fn periods_between(&self, start: OffsetDateTime, end: OffsetDateTime) -> Periods {
+        Periods {
+            duration: *self,
+            start,
+            end,
+            n: 0,
+            done: false,
+        }
+    }
+
+    /// Split the span covered by the duration at `anchor` into `n`
+    /// consecutive sub-intervals of equal length.
+    ///
+    /// The first sub-interval starts at `anchor` and the last one ends
+    /// exactly at `anchor + self`, any nanosecond left over by the division
+    /// goes to the earliest sub-intervals. Returns an empty `Vec` when `n`
+    /// is zero.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// let parts = month.split_evenly_at(datetime!(2023-02-01 00:00:00 UTC), 4);
+    /// assert_eq!(
+    ///     parts[1],
+    ///     (datetime!(2023-02-08 00:00:00 UTC), datetime!(2023-02-15 00:00:00 UTC))
+    /// );
+    /// ```
+    pub fn split_evenly_at(
+        &self,
+        anchor: OffsetDateTime,
+        n: u32,
+    ) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+        let total = (anchor + *self - anchor).whole_nanoseconds();
+        let n = n as i128;
+
+        // split the length, then put the sign back, so that a negative span
+        // also starts at `anchor` and hands its leftover to the earliest parts
+        let (sign, length) = (total.signum(), total.abs());
+        let boundary = |i: i128| {
+            let offset = length / n * i + (length % n).min(i);
+            anchor + time::Duration::nanoseconds_i128(sign * offset)
+        };
+
+        (0..n).map(|i| (boundary(i), boundary(i + 1))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn nth_occurrence() {
+        let monthly: Duration = "P1M".parse().unwrap();
+        let anchor = datetime!(2023-01-31 00:00:00 UTC);
+
+        assert_eq!(monthly.nth_from(anchor, 0), anchor);
+        assert_eq!(
+            monthly.nth_from(anchor, 1),
+            datetime!(2023-02-28 00:00:00 UTC)
+        );
+        assert_eq!(
+            monthly.nth_from(anchor, 13),
+            datetime!(2024-02-29 00:00:00 UTC)
+        );
+    }
+
+    #[test]
+    fn count_elapsed_periods() {
+        let monthly: Duration = "P1M".parse().unwrap();
+        let anchor = datetime!(2023-01-31 00:00:00 UTC);
+
+        assert_eq!(
+            monthly.count_between(anchor, datetime!(2022-01-01 00:00:00 UTC)),
+            Some(0)
+        );
+        assert_eq!(
+            monthly.count_between(anchor, datetime!(2023-02-28 00:00:00 UTC)),
+            Some(1)
+        );
+        assert_eq!(
+            monthly.count_between(anchor, datetime!(2033-01-30 23:59:59 UTC)),
+            Some(119)
+        );
+
+        let daily: Duration = "PT24H".parse().unwrap();
+        assert_eq!(
+            daily.count_between(anchor, datetime!(2023-03-02 12:00:00 UTC)),
+            Some(30)
+        );
+
+        let empty = Duration::new(0., 0., 0., 0., 0., 0.);
+        assert_eq!(empty.count_between(anchor, anchor), None);
+    }
+
+    #[test]
+    fn periods() {
+        let monthly: Duration = "P1M".parse().unwrap();
+        let start = datetime!(2023-01-31 00:00:00 UTC);
+        let end = datetime!(2023-05-01 00:00:00 UTC);
+
+        let periods: Vec<_> = monthly.periods_between(start, end).collect();
+        assert_eq!(
+            periods,
+            vec![
+                (start, datetime!(2023-02-28 00:00:00 UTC)),
+                (
+                    datetime!(2023-02-28 00:00:00 UTC),
+                    datetime!(2023-03-31 00:00:00 UTC)
+                ),
+                (
+                    datetime!(2023-03-31 00:00:00 UTC),
+                    datetime!(2023-04-30 00:00:00 UTC)
+                ),
+                (datetime!(2023-04-30 00:00:00 UTC), end),
+            ]
+        );
+
+        assert_eq!(monthly.periods_between(end, start).count(), 0);
+        let empty = Duration::new(0., 0., 0., 0., 0., 0.);
+        assert_eq!(empty.periods_between(start, end).count(), 0);
+    }
+
+    #[test]
+    fn between() {
+        use time::macros::offset;
+
+        let start = datetime!(2024-01-31 23:00:00 UTC);
+        for end in [
+            start,
+            datetime!(2024-02-29 22:00:00 UTC),
+            datetime!(2024-02-29 23:00:00 UTC),
+            datetime!(2024-03-01 00:00:00.25 UTC),
+            datetime!(2027-12-31 23:59:59 UTC),
+            datetime!(2024-03-01 00:00:00 +05:00),
+        ] {
+            assert_eq!(start + Duration::between(start, end), end, "{}", end);
+        }
+
+        assert_eq!(
+            Duration::between(start, datetime!(2024-02-29 23:00:00 UTC)),
+            Duration::months(1)
+        );
+        assert_eq!(
+            Duration::between(datetime!(2024-02-29 23:00:00 UTC), start),
+            -Duration::months(1)
+        );
+        assert_eq!(
+            Duration::between(
+                start,
+                datetime!(2024-02-01 00:00:00 UTC).to_offset(offset!(-3))
+            ),
+            Duration::hours(1)
+        );
+    }
+
+    #[test]
+    fn whole_units_between() {
+        let start = datetime!(2024-01-31 12:00:00 UTC);
+
+        assert_eq!(
+            Duration::whole_months_between(start, datetime!(2024-02-29 11:59:59 UTC)),
+            0
+        );
+        assert_eq!(
+            Duration::whole_months_between(start, datetime!(2025-01-31 12:00:00 UTC)),
+            12
+        );
+        assert_eq!(
+            Duration::whole_months_between(start, datetime!(2023-01-01 00:00:00 UTC)),
+            0
+        );
+        assert_eq!(
+            Duration::whole_days_between(start, datetime!(2024-03-01 11:00:00 UTC)),
+            29
+        );
+    }
+
+    #[test]
+    fn buckets() {
+        let monthly: Duration = "P1M".parse().unwrap();
+        let anchor = datetime!(2023-01-31 00:00:00 UTC);
+
+        assert_eq!(
+            monthly.bucket_of(anchor, anchor),
+            Some((anchor, datetime!(2023-02-28 00:00:00 UTC)))
+        );
+        assert_eq!(
+            monthly.bucket_of(datetime!(2023-03-30 12:00:00 UTC), anchor),
+            Some((
+                datetime!(2023-02-28 00:00:00 UTC),
+                datetime!(2023-03-31 00:00:00 UTC)
+            ))
+        );
+        assert_eq!(
+            monthly.bucket_of(datetime!(2022-11-15 00:00:00 UTC), anchor),
+            Some((
+                datetime!(2022-10-31 00:00:00 UTC),
+                datetime!(2022-11-30 00:00:00 UTC)
+            ))
+        );
+        assert_eq!(
+            monthly.bucket_of(datetime!(2022-12-31 00:00:00 UTC), anchor),
+            Some((datetime!(2022-12-31 00:00:00 UTC), anchor))
+        );
+
+        let empty = Duration::new(0., 0., 0., 0., 0., 0.);
+        assert_eq!(empty.bucket_of(anchor, anchor), None);
+    }
+
+    #[test]
+    fn split_evenly() {
+        let duration: Duration = "PT10S".parse().unwrap();
+        let anchor = datetime!(2023-01-31 00:00:00 UTC);
+
+        let parts = duration.split_evenly_at(anchor, 3);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].0, anchor);
+        assert_eq!(
+            parts[0].1 - parts[0].0,
+            time::Duration::nanoseconds(3_333_333_334)
+        );
+        assert_eq!(
+            parts[2].1 - parts[2].0,
+            time::Duration::nanoseconds(3_333_333_333)
+        );
+        assert_eq!(parts[2].1, anchor + duration);
+        assert!(parts.windows(2).all(|w| w[0].1 == w[1].0));
+
+        assert!(duration.split_evenly_at(anchor, 0).is_empty());
+
+        let parts = (-duration).split_evenly_at(anchor, 3);
+        assert_eq!(parts[0].0, anchor);
+        assert_eq!(
+            parts[0].1 - parts[0].0,
+            time::Duration::nanoseconds(-3_333_333_334)
+        );
+        assert_eq!(
+            parts[2].1 - parts[2].0,
+            time::Duration::nanoseconds(-3_333_333_333)
+        );
+        assert_eq!(parts[2].1, anchor - duration);
+        assert!(parts.windows(2).all(|w| w[0].1 == w[1].0));
+    }
+}
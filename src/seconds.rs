@@ -0,0 +1,555 @@
+use std::{error::Error, fmt, time::Duration as StdDuration};
+
+use crate::Duration;
+
+/// How to count `year` and `month` when a duration has to be turned into
+/// a fixed number of seconds without a starting date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalendarPolicy {
+    /// Refuse durations that contain `year` or `month`.
+    #[default]
+    Reject,
+    /// Use the average Gregorian lengths: 365.2425 days per year and
+    /// 30.436875 days per month.
+    Average,
+    /// Use 365 days per year and 30 days per month.
+    Fixed,
+}
+
+/// How to round sub-nanosecond fractions when converting to
+/// `std::time::Duration`, or fractions of the target unit in other
+/// conversions such as `Duration::to_redis_ttl_at`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round down.
+    Floor,
+    /// Round up.
+    Ceil,
+    /// Round to the nearest nanosecond, halfway values away from zero.
+    #[default]
+    Nearest,
+}
+
+/// Error returned when a duration can't be converted to
+/// `std::time::Duration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToStdError {
+    /// The duration has `year` or `month`, which have no fixed length.
+    CalendarComponent,
+    /// The duration is negative.
+    Negative,
+    /// The duration isn't finite or is too large.
+    OutOfRange,
+}
+
+impl fmt::Display for ToStdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ToStdError::CalendarComponent => {
+                "duration has a year or month component, which has no fixed length"
+            }
+            ToStdError::Negative => "duration is negative",
+            ToStdError::OutOfRange => "duration is out of range",
+        })
+    }
+}
+
+impl Error for ToStdError {}
+
+/// Convert a number of seconds, failing on negative or too large values.
+fn seconds_to_std(seconds: f64) -> Result<StdDuration, ToStdError> {
+    if seconds < 0. {
+        return Err(ToStdError::Negative);
+    }
+
+    StdDuration::try_from_secs_f64(seconds).map_err(|_| ToStdError::OutOfRange)
+}
+
+impl CalendarPolicy {
+    /// Return the number of days in a year and in a month, or `None` for `Reject`
+    pub(crate) fn days_per_year_and_month(self) -> Option<(f64, f64)> {
+        match self {
+            CalendarPolicy::Reject => None,
+            CalendarPolicy::Average => Some((365.2425, 30.436875)),
+            CalendarPolicy::Fixed => Some((365., 30.)),
+        }
+    }
+}
+
+impl Duration {
+    /// Return number of seconds in the duration as `f64`, counting `year`
+    /// and `month` according to `policy`.
+    ///
+    /// This is meant for exporting durations to metrics systems, such as
+    /// Prometheus histogram buckets or scrape intervals, which expect plain
+    /// seconds. A day always counts as 86400 seconds.
+    ///
+    /// This method will return `None` if the policy is `Reject` and the
+    /// duration contains `year` or `month`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{CalendarPolicy, Duration};
+    ///
+    /// let duration: Duration = "PT1M30.5S".parse().unwrap();
+    /// assert_eq!(duration.to_seconds_f64(CalendarPolicy::Reject), Some(90.5));
+    ///
+    /// let duration: Duration = "P1M".parse().unwrap();
+    /// assert_eq!(duration.to_seconds_f64(CalendarPolicy::Reject), None);
+    /// assert_eq!(duration.to_seconds_f64(CalendarPolicy::Fixed), Some(2_592_000.));
+    /// ```
+    pub fn to_seconds_f64(&self, policy: CalendarPolicy) -> Option<f64> {
+        let days = if self.year != 0.0 || self.month != 0.0 {
+            let (days_per_year, days_per_month) = policy.days_per_year_and_month()?;
+            self.year as f64 * days_per_year + self.month as f64 * days_per_month
+        } else {
+            0.
+        };
+
+        Some(
+            (days + self.day as f64) * 86400.
+                + self.hour as f64 * 3600.
+                + self.minute as f64 * 60.
+                + self.second as f64,
+        )
+    }
+
+    /// Convert duration to `std::time::Duration`.
+    ///
+    /// This method will return an error if the duration contains `year`
+    /// or `month`, use [`Duration::to_std_approx`] or an anchored
+    /// conversion for those. It also fails if the duration is negative or
+    /// out of range.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, ToStdError};
+    /// use std::time::Duration as StdDuration;
+    ///
+    /// let duration: Duration = "P1DT1M".parse().unwrap();
+    /// assert_eq!(duration.to_std(), Ok(StdDuration::from_secs(86460)));
+    ///
+    /// let duration: Duration = "P1M".parse().unwrap();
+    /// assert_eq!(duration.to_std(), Err(ToStdError::CalendarComponent));
+    /// ```
+    pub fn to_std(&self) -> Result<StdDuration, ToStdError> {
+        let seconds = self
+            .to_seconds_f64(CalendarPolicy::Reject)
+            .ok_or(ToStdError::CalendarComponent)?;
+
+        seconds_to_std(seconds)
+    }
+
+    /// Convert duration to `std::time::Duration`, counting `year` and
+    /// `month` with the given number of days.
+    ///
+    /// This is an approximation for callers that accept one, e.g. with
+    /// 30 days per month and 365 days per year. A day always counts as
+    /// 86400 seconds. This method will return an error if the duration is
+    /// negative or out of range.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use std::time::Duration as StdDuration;
+    ///
+    /// let duration: Duration = "P1Y1M".parse().unwrap();
+    /// assert_eq!(
+    ///     duration.to_std_approx(30., 365.),
+    ///     Ok(StdDuration::from_secs(395 * 86400))
+    /// );
+    /// ```
+    pub fn to_std_approx(
+        &self,
+        days_per_month: f64,
+        days_per_year: f64,
+    ) -> Result<StdDuration, ToStdError> {
+        let days = self.year as f64 * days_per_year + self.month as f64 * days_per_month;
+        let seconds = (days + self.day as f64) * 86400.
+            + self.hour as f64 * 3600.
+            + self.minute as f64 * 60.
+            + self.second as f64;
+
+        seconds_to_std(seconds)
+    }
+
+    /// Convert duration to `std::time::Duration`, rounding fractions of
+    /// nanosecond with `rounding`.
+    ///
+    /// Components are read as the decimal number they were written with,
+    /// so `PT0.1S` is exactly 100 milliseconds rather than the nearest
+    /// `f32`. Like [`Duration::to_std`], this method will return an error
+    /// if the duration contains `year` or `month`, or if it's negative,
+    /// not finite or too large.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, Rounding, ToStdError};
+    /// use std::time::Duration as StdDuration;
+    ///
+    /// let duration: Duration = "PT0.0000000015S".parse().unwrap();
+    /// assert_eq!(duration.to_std_checked(Rounding::Floor), Ok(StdDuration::from_nanos(1)));
+    /// assert_eq!(duration.to_std_checked(Rounding::Ceil), Ok(StdDuration::from_nanos(2)));
+    ///
+    /// let duration: Duration = "-PT1S".parse().unwrap();
+    /// assert_eq!(duration.to_std_checked(Rounding::Floor), Err(ToStdError::Negative));
+    /// ```
+    pub fn to_std_checked(&self, rounding: Rounding) -> Result<StdDuration, ToStdError> {
+        if self.year != 0.0 || self.month != 0.0 {
+            return Err(ToStdError::CalendarComponent);
+        }
+
+        let seconds = shortest_decimal(self.day) * 86400.
+            + shortest_decimal(self.hour) * 3600.
+            + shortest_decimal(self.minute) * 60.;
+        let second = shortest_decimal(self.second);
+
+        let whole = seconds.trunc() + second.trunc();
+        // snap to a picosecond first so that `f64` noise doesn't decide the rounding
+        let nanos = ((seconds.fract() + second.fract()) * 1e9 * 1e3).round() / 1e3;
+        let nanos = match rounding {
+            Rounding::Floor => nanos.floor(),
+            Rounding::Ceil => nanos.ceil(),
+            Rounding::Nearest => nanos.round(),
+        };
+
+        // carry the fraction so that it's within a second, and of the same
+        // sign as the total, e.g. `PT1M-0.5S` is 59.5 seconds
+        let carry = (nanos / 1e9).floor();
+        let (whole, nanos) = (whole + carry, nanos - carry * 1e9);
+
+        if !(whole.is_finite() && nanos.is_finite()) || whole >= u64::MAX as f64 {
+            return Err(ToStdError::OutOfRange);
+        }
+        if whole < 0. {
+            return Err(ToStdError::Negative);
+        }
+
+        StdDuration::from_secs(whole as u64)
+            .checked_add(StdDuration::from_nanos(nanos as u64))
+            .ok_or(ToStdError::OutOfRange)
+    }
+
+    /// Return the exact number of nanoseconds in the duration as a
+    /// reduced fraction `(numerator, denominator)`.
+    ///
+    /// Like [`Duration::to_std_checked`], components are read as the
+    /// decimal number they were written with, and no rounding happens, so
+    /// callers can pick their own rounding. This method will return `None`
+    /// if the duration contains `year` or `month`, or if a component isn't
+    /// finite or has too many digits to be represented.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let duration: Duration = "PT0.0000000015S".parse().unwrap();
+    /// assert_eq!(duration.exact_nanos(), Some((3, 2)));
+    ///
+    /// let duration: Duration = "P0.1DT0.1S".parse().unwrap();
+    /// assert_eq!(duration.exact_nanos(), Some((8_640_100_000_000, 1)));
+    /// ```
+    pub fn exact_nanos(&self) -> Option<(i128, u128)> {
+        if self.year != 0.0 || self.month != 0.0 {
+            return None;
+        }
+
+        let components = [
+            (decimal_parts(self.day)?, 86_400_000_000_000),
+            (decimal_parts(self.hour)?, 3_600_000_000_000),
+            (decimal_parts(self.minute)?, 60_000_000_000),
+            (decimal_parts(self.second)?, 1_000_000_000),
+        ];
+        let scale = components
+            .iter()
+            .map(|((_, scale), _)| *scale)
+            .max()
+            .unwrap_or(0);
+
+        let mut numerator: i128 = 0;
+        for ((digits, digits_scale), nanos_per_unit) in components {
+            let term = digits
+                .checked_mul(nanos_per_unit)?
+                .checked_mul(10i128.checked_pow(scale - digits_scale)?)?;
+            numerator = numerator.checked_add(term)?;
+        }
+        let denominator = 10u128.checked_pow(scale)?;
+
+        let divisor = gcd(numerator.unsigned_abs(), denominator);
+        Some((numerator / divisor as i128, denominator / divisor))
+    }
+
+    /// Create a duration from a whole number of seconds
+    ///
+    /// The seconds are split into `hour`, `minute` and `second`.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(3725).to_string(), "PT1H2M5S");
+    /// assert_eq!(Duration::from_millis(90_500).to_string(), "PT1M30.5S");
+    /// ```
+    pub fn from_secs(secs: u64) -> Duration {
+        Duration::from_nanos(secs as u128 * 1_000_000_000)
+    }
+
+    /// Create a duration from a number of milliseconds
+    pub fn from_millis(millis: u64) -> Duration {
+        Duration::from_nanos(millis as u128 * 1_000_000)
+    }
+
+    /// Create a duration from a number of microseconds
+    pub fn from_micros(micros: u64) -> Duration {
+        Duration::from_nanos(micros as u128 * 1_000)
+    }
+
+    /// Create a duration from a number of nanoseconds
+    ///
+    /// The whole seconds are split into `hour`, `minute` and `second`,
+    /// and the remaining nanoseconds are added as a fraction of `second`.
+    pub fn from_nanos(nanos: u128) -> Duration {
+        let secs = nanos / 1_000_000_000;
+        let subsec_nanos = nanos % 1_000_000_000;
+
+        Duration::new(
+            0.,
+            0.,
+            0.,
+            (secs / 3600) as f32,
+            (secs / 60 % 60) as f32,
+            (secs % 60) as f32 + subsec_nanos as f32 / 1e9,
+        )
+    }
+
+    /// Create a duration from a number of seconds as `f64`
+    ///
+    /// The seconds are split into `hour`, `minute` and `second`, so a
+    /// scrape interval of `90.` gives `PT1M30S`. This method will return
+    /// `None` if `seconds` is negative or not finite.
+    pub fn from_seconds_f64(seconds: f64) -> Option<Duration> {
+        if !seconds.is_finite() || seconds < 0. {
+            return None;
+        }
+
+        let hours = (seconds / 3600.).trunc();
+        let minutes = ((seconds - hours * 3600.) / 60.).trunc();
+        let seconds = seconds - hours * 3600. - minutes * 60.;
+
+        Some(Duration::new(
+            0.,
+            0.,
+            0.,
+            hours as f32,
+            minutes as f32,
+            seconds as f32,
+        ))
+    }
+}
+
+/// Return the shortest decimal number that reads back as `value`, as
+/// `f64`.
+///
+/// This is the number `value` is displayed as, and the one it was parsed
+/// from if that had at most 9 significant digits, e.g. `0.1` rather than
+/// `0.100000001490116`, the exact value of the nearest `f32`. Non-finite
+/// values are returned as they are.
+fn shortest_decimal(value: f32) -> f64 {
+    let exact = value as f64;
+    if !value.is_finite() || value == 0. {
+        return exact;
+    }
+
+    let exponent = exact.abs().log10().floor() as i32;
+    // 9 significant digits are always enough to tell `f32` values apart
+    (1..=9)
+        .map(|digits| {
+            // scale by an exact power of ten, so that only the rounding
+            // to `digits` changes the number
+            let shift = digits - 1 - exponent;
+            if shift >= 0 {
+                let scale = 10f64.powi(shift);
+                (exact * scale).round() / scale
+            } else {
+                let scale = 10f64.powi(-shift);
+                (exact / scale).round() * scale
+            }
+        })
+        .find(|&decimal| decimal as f32 == value)
+        .unwrap_or(exact)
+}
+
+/// Return the digits of `value` as written, and the number of them after
+/// the decimal point.
+pub(crate) fn decimal_parts(value: f32) -> Option<(i128, u32)> {
+    if !value.is_finite() {
+        return None;
+    }
+
+    let written = value.to_string();
+    let (whole, fraction) = written.split_once('.').unwrap_or((&written, ""));
+    let digits = format!("{}{}", whole, fraction).parse().ok()?;
+
+    Some((digits, fraction.len() as u32))
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar_policies() {
+        let duration: Duration = "P1Y1M1D".parse().unwrap();
+
+        assert_eq!(duration.to_seconds_f64(CalendarPolicy::Reject), None);
+        assert_eq!(
+            duration.to_seconds_f64(CalendarPolicy::Fixed),
+            Some(396. * 86400.)
+        );
+        assert_eq!(
+            duration.to_seconds_f64(CalendarPolicy::Average),
+            Some((365.2425 + 30.436875 + 1.) * 86400.)
+        );
+    }
+
+    #[test]
+    fn to_std_checked() {
+        let duration: Duration = "PT0.1S".parse().unwrap();
+        assert_eq!(
+            duration.to_std_checked(Rounding::Floor),
+            Ok(StdDuration::from_millis(100))
+        );
+
+        let duration: Duration = "P1DT0.0000000005S".parse().unwrap();
+        assert_eq!(
+            duration.to_std_checked(Rounding::Nearest),
+            Ok(StdDuration::new(86400, 1))
+        );
+        assert_eq!(
+            duration.to_std_checked(Rounding::Floor),
+            Ok(StdDuration::new(86400, 0))
+        );
+
+        assert_eq!(
+            Duration::new(0., 1., 0., 0., 0., 0.).to_std_checked(Rounding::Floor),
+            Err(ToStdError::CalendarComponent)
+        );
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 0., f32::INFINITY).to_std_checked(Rounding::Floor),
+            Err(ToStdError::OutOfRange)
+        );
+        assert_eq!(
+            Duration::new(0., 0., 0., -1., 0., 0.).to_std_checked(Rounding::Floor),
+            Err(ToStdError::Negative)
+        );
+
+        // components of mixed signs net out
+        let duration: Duration = "PT1M-0.5S".parse().unwrap();
+        assert_eq!(
+            duration.to_std_checked(Rounding::Floor),
+            Ok(StdDuration::from_millis(59_500))
+        );
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 1., -60.5).to_std_checked(Rounding::Floor),
+            Err(ToStdError::Negative)
+        );
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 0., -0.5).to_std_checked(Rounding::Floor),
+            Err(ToStdError::Negative)
+        );
+    }
+
+    #[test]
+    fn shortest_decimal() {
+        assert_eq!(super::shortest_decimal(0.1), 0.1);
+        assert_eq!(super::shortest_decimal(-2.75), -2.75);
+        assert_eq!(super::shortest_decimal(1e20), 1e20);
+        assert_eq!(super::shortest_decimal(16_777_217.), 16_777_216.);
+        assert_eq!(super::shortest_decimal(f32::MAX) as f32, f32::MAX);
+        assert!(super::shortest_decimal(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn from_integer_counts() {
+        assert_eq!(
+            Duration::from_secs(0),
+            Duration::new(0., 0., 0., 0., 0., 0.)
+        );
+        assert_eq!(
+            Duration::from_secs(90_061),
+            Duration::new(0., 0., 0., 25., 1., 1.)
+        );
+        assert_eq!(
+            Duration::from_micros(1_250_000),
+            Duration::new(0., 0., 0., 0., 0., 1.25)
+        );
+        assert_eq!(
+            Duration::from_nanos(3_600_000_000_500),
+            Duration::new(0., 0., 0., 1., 0., 0.0000005)
+        );
+        assert_eq!(Duration::from_secs(u64::MAX).hour, (u64::MAX / 3600) as f32);
+    }
+
+    #[test]
+    fn from_seconds() {
+        assert_eq!(
+            Duration::from_seconds_f64(3725.25),
+            Some(Duration::new(0., 0., 0., 1., 2., 5.25))
+        );
+        assert_eq!(Duration::from_seconds_f64(-1.), None);
+        assert_eq!(Duration::from_seconds_f64(f64::NAN), None);
+        assert_eq!(
+            Duration::from_seconds_f64(15.)
+                .unwrap()
+                .to_seconds_f64(CalendarPolicy::Reject),
+            Some(15.)
+        );
+    }
+
+    #[test]
+    fn exact_nanos() {
+        assert_eq!(Duration::ZERO.exact_nanos(), Some((0, 1)));
+        assert_eq!(
+            "PT1H0.25S".parse::<Duration>().unwrap().exact_nanos(),
+            Some((3_600_250_000_000, 1))
+        );
+        assert_eq!(
+            "-PT0.0000000001S"
+                .parse::<Duration>()
+                .unwrap()
+                .exact_nanos(),
+            Some((-1, 10))
+        );
+        assert_eq!("P1M".parse::<Duration>().unwrap().exact_nanos(), None);
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 0., f32::MIN_POSITIVE).exact_nanos(),
+            None
+        );
+        assert_eq!(Duration::MAX.exact_nanos(), None);
+    }
+
+    #[test]
+    fn to_std() {
+        assert_eq!(
+            Duration::new(0., 0., 1., 2., 3., 4.5).to_std(),
+            Ok(StdDuration::from_secs_f64(93_784.5))
+        );
+        assert_eq!(
+            Duration::years(1).to_std(),
+            Err(ToStdError::CalendarComponent)
+        );
+        assert_eq!(
+            Duration::MAX.to_std_approx(30., 365.),
+            Err(ToStdError::OutOfRange)
+        );
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 0., f32::NAN).to_std(),
+            Err(ToStdError::OutOfRange)
+        );
+        assert_eq!(
+            (-Duration::months(1)).to_std_approx(30., 365.),
+            Err(ToStdError::Negative)
+        );
+    }
+}
@@ -1,15 +1,52 @@
+//! Serde support, serializing `Duration` as its ISO8601 string.
+//!
+//! Requires the `serde` feature. `Duration` implements `Serialize` and
+//! `Deserialize` itself, as the string given by
+//! [`Duration::to_iso8601`], so it can be used in config and API types
+//! without a wrapper:
+//!
+//! ```rust
+//! use iso8601_duration::Duration;
+//!
+//! let timeout: Duration = serde_json::from_str(r#""PT1M30S""#).unwrap();
+//! assert_eq!(timeout, Duration::new(0., 0., 0., 0., 1., 30.));
+//! assert_eq!(serde_json::to_string(&timeout).unwrap(), r#""PT1M30S""#);
+//!
+//! let err = serde_json::from_str::<Duration>(r#""1M30S""#).unwrap_err();
+//! assert_eq!(
+//!     err.to_string(),
+//!     "invalid ISO8601 duration \"1M30S\": duration must start with 'P' at line 1 column 7"
+//! );
+//! ```
+//!
+//! The modules below provide other representations, to be used with
+//! `#[serde(with = "...")]`.
+
 use std::fmt;
 
-use serde::{de::Error, de::Visitor, Deserialize, Deserializer, Serialize};
+use serde::{
+    de::{Error, MapAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::{Backoff, Duration, Growth, ParseDurationError};
 
-use crate::Duration;
+/// Turn a parse error into a deserialization error
+fn parse_error<E: Error>(err: ParseDurationError) -> E {
+    E::custom(format_args!(
+        "invalid ISO8601 duration {:?}: {}",
+        err.input, err
+    ))
+}
 
 impl Serialize for Duration {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        let value = self.try_to_string().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&value)
     }
 }
 
@@ -26,21 +63,21 @@ impl<'de> Visitor<'de> for DurationVisitor {
     where
         E: Error,
     {
-        v.parse().map_err(|err| E::custom(format!("{:?}", err)))
+        v.parse().map_err(parse_error)
     }
 
     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
     where
         E: Error,
     {
-        v.parse().map_err(|err| E::custom(format!("{:?}", err)))
+        v.parse().map_err(parse_error)
     }
 
     fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
     where
         E: Error,
     {
-        v.parse().map_err(|err| E::custom(format!("{:?}", err)))
+        v.parse().map_err(parse_error)
     }
 }
 
@@ -53,13 +90,723 @@ impl<'de> Deserialize<'de> for Duration {
     }
 }
 
+/// A duration given either as an ISO8601 string or as a number of seconds.
+///
+/// It deserializes like an untagged enum, from a string or from a number,
+/// and serializes back in the same shape, so a wire format accepting both
+/// `"PT1M30S"` and `90` round-trips unchanged. Negative and non-finite
+/// numbers are rejected when deserializing.
+///
+/// ```rust
+/// use iso8601_duration::{Duration, DurationOrSeconds};
+///
+/// let value: DurationOrSeconds = serde_json::from_str("90").unwrap();
+/// assert_eq!(value, DurationOrSeconds::Seconds(90.));
+/// assert_eq!(Duration::from(value), Duration::new(0., 0., 0., 0., 1., 30.));
+/// assert_eq!(serde_json::to_string(&value).unwrap(), "90.0");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DurationOrSeconds {
+    Duration(Duration),
+    Seconds(f64),
+}
+
+impl From<DurationOrSeconds> for Duration {
+    /// Convert to a `Duration`, splitting seconds into hours, minutes and
+    /// seconds. Negative or non-finite seconds give a zero duration.
+    fn from(value: DurationOrSeconds) -> Self {
+        match value {
+            DurationOrSeconds::Duration(duration) => duration,
+            DurationOrSeconds::Seconds(seconds) => Duration::from_seconds_f64(seconds)
+                .unwrap_or_else(|| Duration::new(0., 0., 0., 0., 0., 0.)),
+        }
+    }
+}
+
+impl From<Duration> for DurationOrSeconds {
+    fn from(duration: Duration) -> Self {
+        DurationOrSeconds::Duration(duration)
+    }
+}
+
+impl Serialize for DurationOrSeconds {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            DurationOrSeconds::Duration(duration) => duration.serialize(serializer),
+            DurationOrSeconds::Seconds(seconds) => serializer.serialize_f64(*seconds),
+        }
+    }
+}
+
+struct DurationOrSecondsVisitor;
+
+impl<'de> Visitor<'de> for DurationOrSecondsVisitor {
+    type Value = DurationOrSeconds;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an iso8601 duration format or a number of seconds")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        DurationVisitor
+            .visit_str(v)
+            .map(DurationOrSeconds::Duration)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v.is_finite() && v >= 0. {
+            Ok(DurationOrSeconds::Seconds(v))
+        } else {
+            Err(E::custom(format!(
+                "invalid number of seconds {}, expected a finite, non-negative number",
+                v
+            )))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationOrSeconds {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DurationOrSecondsVisitor)
+    }
+}
+
+impl Serialize for Backoff {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut backoff = serializer.serialize_struct("Backoff", 4)?;
+        backoff.serialize_field("base", &self.base)?;
+        backoff.serialize_field("factor", &self.factor)?;
+        backoff.serialize_field("max", &self.max)?;
+        backoff.serialize_field(
+            "growth",
+            match self.growth {
+                Growth::Exponential => "exponential",
+                Growth::Linear => "linear",
+            },
+        )?;
+        backoff.end()
+    }
+}
+
+const BACKOFF_FIELDS: &[&str] = &["base", "factor", "max", "growth"];
+
+struct BackoffVisitor;
+
+impl<'de> Visitor<'de> for BackoffVisitor {
+    type Value = Backoff;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a backoff policy")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut base = None;
+        let mut backoff = Backoff::exponential(Duration::ZERO, 2.);
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "base" => base = Some(map.next_value()?),
+                "factor" => backoff.factor = map.next_value()?,
+                "max" => backoff.max = map.next_value()?,
+                "growth" => {
+                    backoff.growth = match map.next_value::<String>()?.as_str() {
+                        "exponential" => Growth::Exponential,
+                        "linear" => Growth::Linear,
+                        other => {
+                            return Err(A::Error::unknown_variant(
+                                other,
+                                &["exponential", "linear"],
+                            ))
+                        }
+                    }
+                }
+                _ => return Err(A::Error::unknown_field(&key, BACKOFF_FIELDS)),
+            }
+        }
+
+        if !backoff.factor.is_finite() || backoff.factor < 0. {
+            return Err(A::Error::custom(format!(
+                "invalid factor {}, expected a finite, non-negative number",
+                backoff.factor
+            )));
+        }
+
+        Ok(Backoff {
+            base: base.ok_or_else(|| A::Error::missing_field("base"))?,
+            ..backoff
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Backoff {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Backoff", BACKOFF_FIELDS, BackoffVisitor)
+    }
+}
+
+/// (De)serialize `Duration`, rejecting durations which aren't normalized,
+/// see [`ComponentLimits::CANONICAL`](crate::ComponentLimits::CANONICAL).
+///
+/// Use it with `#[serde(with = "iso8601_duration::serde::strict")]`.
+/// Other limits can be enforced with `deserialize_with` and a function
+/// calling [`strict::deserialize_with_limits`]:
+///
+/// ```rust,ignore
+/// fn at_most_a_week<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+///     let limits = ComponentLimits {
+///         day: Some(7.),
+///         ..ComponentLimits::CANONICAL
+///     };
+///     iso8601_duration::serde::strict::deserialize_with_limits(deserializer, &limits)
+/// }
+/// ```
+pub mod strict {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{ComponentLimits, Duration};
+
+    pub fn serialize<S>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_with_limits(deserializer, &ComponentLimits::CANONICAL)
+    }
+
+    /// Deserialize `Duration`, rejecting it if a component reaches its
+    /// bound in `limits`
+    pub fn deserialize_with_limits<'de, D>(
+        deserializer: D,
+        limits: &ComponentLimits,
+    ) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let duration = Duration::deserialize(deserializer)?;
+        limits.validate(&duration).map_err(D::Error::custom)?;
+        Ok(duration)
+    }
+}
+
+/// (De)serialize `Option<Duration>`, treating `null` and empty strings as `None`.
+///
+/// Use it with `#[serde(with = "iso8601_duration::serde::option")]`, and
+/// add `#[serde(default)]` to also accept a missing field:
+///
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(default, with = "iso8601_duration::serde::option")]
+///     timeout: Option<Duration>,
+/// }
+/// ```
+pub mod option {
+    use std::fmt;
+
+    use serde::{de::Error, de::Visitor, Deserializer, Serializer};
+
+    use crate::Duration;
+
+    pub fn serialize<S>(value: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(duration) => serializer.serialize_some(&duration.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+
+    struct OptionVisitor;
+
+    impl<'de> Visitor<'de> for OptionVisitor {
+        type Value = Option<Duration>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an iso8601 duration format, an empty string or null")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            if v.trim().is_empty() {
+                return Ok(None);
+            }
+            v.parse().map(Some).map_err(super::parse_error)
+        }
+    }
+}
+
+/// Deserialize `Duration` from the shapes commonly found in layered
+/// configuration (config-rs, figment, ...):
+///
+/// - an ISO8601 string, `"PT30S"`,
+/// - a number of seconds, `30` or `0.5`,
+/// - a table of components, `{ minutes = 1, seconds = 30 }`, with keys
+///   among `years`, `months`, `weeks`, `days`, `hours`, `minutes` and
+///   `seconds` (or their singular forms).
+///
+/// Use it with `#[serde(with = "iso8601_duration::serde::config")]`.
+/// Errors are reported through the deserializer, so config libraries
+/// attribute them to the offending key. Durations are serialized back as
+/// ISO8601 strings.
+pub mod config {
+    use std::fmt;
+
+    use serde::{
+        de::{Error, MapAccess, Visitor},
+        Deserializer, Serialize, Serializer,
+    };
+
+    use crate::Duration;
+
+    const COMPONENTS: &[&str] = &[
+        "years", "months", "weeks", "days", "hours", "minutes", "seconds",
+    ];
+
+    pub fn serialize<S>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConfigVisitor)
+    }
+
+    struct ConfigVisitor;
+
+    impl<'de> Visitor<'de> for ConfigVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str(
+                "an iso8601 duration format, a number of seconds or a table of components",
+            )
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            v.parse().map_err(super::parse_error)
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_f64(v as f64)
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_f64(v as f64)
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Duration::from_seconds_f64(v).ok_or_else(|| {
+                E::custom(format!(
+                    "invalid number of seconds {}, expected a finite, non-negative number",
+                    v
+                ))
+            })
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut duration = Duration::new(0., 0., 0., 0., 0., 0.);
+
+            while let Some(key) = map.next_key::<String>()? {
+                let value: f32 = map.next_value()?;
+                match key.as_str() {
+                    "year" | "years" => duration.year = value,
+                    "month" | "months" => duration.month = value,
+                    "week" | "weeks" => duration.day += value * 7.,
+                    "day" | "days" => duration.day += value,
+                    "hour" | "hours" => duration.hour = value,
+                    "minute" | "minutes" => duration.minute = value,
+                    "second" | "seconds" => duration.second = value,
+                    _ => return Err(A::Error::unknown_field(&key, COMPONENTS)),
+                }
+            }
+
+            Ok(duration)
+        }
+    }
+}
+
+/// Deserialize `Duration` from either an ISO8601 string, `"PT1H30M"`, or
+/// a humantime-style string, `"1h 30m"`.
+///
+/// The humantime syntax is a sequence of integers each followed by a
+/// unit, optionally separated by spaces, e.g. `"2 days 4h"`. Units are
+/// `y`/`year`/`years`, `M`/`month`/`months`, `w`/`week`/`weeks`,
+/// `d`/`day`/`days`, `h`/`hr`/`hour`/`hours`, `m`/`min`/`minute`/`minutes`,
+/// `s`/`sec`/`second`/`seconds`, `ms`/`msec`, `us`/`usec` and `ns`/`nsec`.
+/// Years and months stay calendar components rather than being converted
+/// to seconds.
+///
+/// Use it with `#[serde(with = "iso8601_duration::serde::human")]`.
+/// Durations are serialized back as ISO8601 strings.
+pub mod human {
+    use std::fmt;
+
+    use serde::{
+        de::{Error, Visitor},
+        Deserializer, Serialize, Serializer,
+    };
+
+    use crate::Duration;
+
+    pub fn serialize<S>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(HumanVisitor)
+    }
+
+    /// Parse a humantime-style duration, returning a description of the
+    /// problem on error.
+    fn parse_human(input: &str) -> Result<Duration, String> {
+        let mut duration = Duration::new(0., 0., 0., 0., 0., 0.);
+        let mut rest = input.trim_start();
+
+        if rest.is_empty() {
+            return Err("empty duration".to_string());
+        }
+
+        while !rest.is_empty() {
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if digits == 0 {
+                return Err(format!("expected a number at {:?}", rest));
+            }
+            let value: f32 = rest[..digits]
+                .parse()
+                .map_err(|_| format!("invalid number {:?}", &rest[..digits]))?;
+            rest = rest[digits..].trim_start();
+
+            let letters = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let unit = &rest[..letters];
+            match unit {
+                "y" | "year" | "years" => duration.year += value,
+                "M" | "month" | "months" => duration.month += value,
+                "w" | "week" | "weeks" => duration.day += value * 7.,
+                "d" | "day" | "days" => duration.day += value,
+                "h" | "hr" | "hour" | "hours" => duration.hour += value,
+                "m" | "min" | "minute" | "minutes" => duration.minute += value,
+                "s" | "sec" | "second" | "seconds" => duration.second += value,
+                "ms" | "msec" => duration.second += value / 1e3,
+                "us" | "usec" => duration.second += value / 1e6,
+                "ns" | "nsec" => duration.second += value / 1e9,
+                "" => return Err(format!("missing unit after {}", value)),
+                _ => return Err(format!("unknown unit {:?}", unit)),
+            }
+            rest = rest[letters..].trim_start();
+        }
+
+        Ok(duration)
+    }
+
+    struct HumanVisitor;
+
+    impl<'de> Visitor<'de> for HumanVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an iso8601 duration format or a duration such as \"1h 30m\"")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            // a sign in front of the `P` still makes it ISO8601, and `+`
+            // is the default the ISO8601 parser doesn't spell out
+            let iso = v.strip_prefix('+').unwrap_or(v);
+            if iso.strip_prefix('-').unwrap_or(iso).starts_with('P') {
+                iso.parse().map_err(super::parse_error)
+            } else {
+                parse_human(v)
+                    .map_err(|err| E::custom(format!("invalid duration {:?}: {}", v, err)))
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn human_syntax() {
+            assert_eq!(
+                parse_human("1h 30m"),
+                Ok(Duration::new(0., 0., 0., 1., 30., 0.))
+            );
+            assert_eq!(
+                parse_human("2weeks 1d 500ms"),
+                Ok(Duration::new(0., 0., 15., 0., 0., 0.5))
+            );
+            assert_eq!(
+                parse_human("1y2M"),
+                Ok(Duration::new(1., 2., 0., 0., 0., 0.))
+            );
+            assert!(parse_human("").is_err());
+            assert!(parse_human("1.5h").is_err());
+            assert!(parse_human("10").is_err());
+            assert!(parse_human("3 fortnights").is_err());
+        }
+    }
+}
+
 #[test]
 fn test_serde() {
     use serde_json::{from_str, to_string};
 
     let s = r#""P3Y6M4DT12H30M5S""#;
 
-    let d: Duration = from_str(&s).unwrap();
+    let d: Duration = from_str(s).unwrap();
 
     assert_eq!(to_string(&d).unwrap(), s);
+
+    for d in [Duration::ZERO, Duration::new(0., 0., 1.5, 0., 0., 0.25)] {
+        assert_eq!(from_str::<Duration>(&to_string(&d).unwrap()).unwrap(), d);
+    }
+    assert_eq!(to_string(&Duration::ZERO).unwrap(), r#""PT0S""#);
+    assert!(to_string(&Duration::new(0., 0., f32::NAN, 0., 0., 0.)).is_err());
+}
+
+#[test]
+fn test_serde_option() {
+    use serde_json::{Deserializer, Serializer};
+
+    let parse = |s: &str| option::deserialize(&mut Deserializer::from_str(s)).unwrap();
+
+    assert_eq!(parse("null"), None);
+    assert_eq!(parse(r#""""#), None);
+    assert_eq!(parse(r#""  ""#), None);
+    assert_eq!(
+        parse(r#""PT5S""#),
+        Some(Duration::new(0., 0., 0., 0., 0., 5.))
+    );
+    assert!(option::deserialize(&mut Deserializer::from_str(r#""5S""#)).is_err());
+
+    let mut out = Vec::new();
+    option::serialize(&None, &mut Serializer::new(&mut out)).unwrap();
+    assert_eq!(out, b"null");
+
+    let mut out = Vec::new();
+    option::serialize(&parse(r#""P1D""#), &mut Serializer::new(&mut out)).unwrap();
+    assert_eq!(out, br#""P1D""#);
+}
+
+#[test]
+fn test_serde_config() {
+    use serde_json::Deserializer;
+
+    let parse = |s: &str| config::deserialize(&mut Deserializer::from_str(s));
+
+    assert_eq!(
+        parse(r#""PT30S""#).unwrap(),
+        Duration::new(0., 0., 0., 0., 0., 30.)
+    );
+    assert_eq!(parse("90").unwrap(), Duration::new(0., 0., 0., 0., 1., 30.));
+    assert_eq!(
+        parse("0.5").unwrap(),
+        Duration::new(0., 0., 0., 0., 0., 0.5)
+    );
+    assert_eq!(
+        parse(r#"{"weeks": 1, "days": 2, "hours": 3}"#).unwrap(),
+        Duration::new(0., 0., 9., 3., 0., 0.)
+    );
+
+    assert!(parse("-1").is_err());
+    assert_eq!(
+        parse(r#"{"fortnights": 1}"#).unwrap_err().to_string(),
+        "unknown field `fortnights`, expected one of `years`, `months`, `weeks`, \
+         `days`, `hours`, `minutes`, `seconds` at line 1 column 17"
+    );
+}
+
+#[test]
+fn test_serde_duration_or_seconds() {
+    use serde_json::{from_str, to_string};
+
+    let value: DurationOrSeconds = from_str(r#""PT1M""#).unwrap();
+    assert_eq!(
+        value,
+        DurationOrSeconds::Duration(Duration::new(0., 0., 0., 0., 1., 0.))
+    );
+    assert_eq!(to_string(&value).unwrap(), r#""PT1M""#);
+
+    let value: DurationOrSeconds = from_str("0.25").unwrap();
+    assert_eq!(
+        Duration::from(value),
+        Duration::new(0., 0., 0., 0., 0., 0.25)
+    );
+
+    assert!(from_str::<DurationOrSeconds>("-5").is_err());
+    assert!(from_str::<DurationOrSeconds>("true").is_err());
+}
+
+#[test]
+fn test_serde_human() {
+    use serde_json::Deserializer;
+
+    let parse = |s: &str| human::deserialize(&mut Deserializer::from_str(s));
+
+    assert_eq!(
+        parse(r#""PT1H30M""#).unwrap(),
+        parse(r#""1h 30m""#).unwrap()
+    );
+    assert_eq!(
+        parse(r#""90 minutes""#).unwrap(),
+        Duration::new(0., 0., 0., 0., 90., 0.)
+    );
+    assert_eq!(
+        parse(r#""90 furlongs""#).unwrap_err().to_string(),
+        "invalid duration \"90 furlongs\": unknown unit \"furlongs\" at line 1 column 13"
+    );
+    assert_eq!(
+        parse(r#""-PT1H""#).unwrap(),
+        Duration::new(0., 0., 0., -1., 0., 0.)
+    );
+    assert_eq!(
+        parse(r#""+PT1H""#).unwrap(),
+        Duration::new(0., 0., 0., 1., 0., 0.)
+    );
+    assert!(parse(r#""-PT1X""#).is_err());
+}
+
+#[test]
+fn test_serde_backoff() {
+    use serde_json::{from_str, to_string};
+
+    let backoff: Backoff = from_str(r#"{"base": "PT1S", "max": "PT1M"}"#).unwrap();
+    assert_eq!(
+        backoff,
+        Backoff::exponential(Duration::new(0., 0., 0., 0., 0., 1.), 2.)
+            .with_max(Duration::new(0., 0., 0., 0., 1., 0.))
+    );
+    assert_eq!(
+        to_string(&backoff).unwrap(),
+        r#"{"base":"PT1S","factor":2.0,"max":"PT1M","growth":"exponential"}"#
+    );
+
+    let backoff: Backoff =
+        from_str(r#"{"base": "PT5S", "factor": 1, "growth": "linear"}"#).unwrap();
+    assert_eq!(backoff.delay(2), Duration::new(0., 0., 0., 0., 0., 15.));
+
+    assert!(from_str::<Backoff>(r#"{"factor": 2}"#).is_err());
+    assert!(from_str::<Backoff>(r#"{"base": "PT1S", "factor": -1}"#).is_err());
+    assert!(from_str::<Backoff>(r#"{"base": "PT1S", "growth": "cubic"}"#).is_err());
+}
+
+#[test]
+fn test_serde_strict() {
+    use serde_json::Deserializer;
+
+    let parse = |s: &str| strict::deserialize(&mut Deserializer::from_str(s));
+
+    assert_eq!(
+        parse(r#""P1Y11MT1M59S""#).unwrap(),
+        Duration::new(1., 11., 0., 0., 1., 59.)
+    );
+    assert_eq!(
+        parse(r#""PT60S""#).unwrap_err().to_string(),
+        "60S is out of range, it must be less than 60S"
+    );
+    assert!(parse(r#""P13M""#).is_err());
 }
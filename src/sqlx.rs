@@ -0,0 +1,85 @@
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{
+        types::PgInterval, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef,
+    },
+    Decode, Encode, Postgres, Type,
+};
+
+use crate::Duration;
+
+/// Binds as a PostgreSQL `interval`, see [`Duration::to_pg_interval`].
+impl Type<Postgres> for Duration {
+    fn type_info() -> PgTypeInfo {
+        <PgInterval as Type<Postgres>>::type_info()
+    }
+}
+
+impl PgHasArrayType for Duration {
+    fn array_type_info() -> PgTypeInfo {
+        <PgInterval as PgHasArrayType>::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Duration {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let bytes = self
+            .to_pg_interval()
+            .ok_or("duration can't be represented as a PostgreSQL interval")?;
+        buf.extend_from_slice(&bytes);
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+/// Decodes the binary format, and the text format when `IntervalStyle` is
+/// set to `iso_8601`.
+impl<'r> Decode<'r, Postgres> for Duration {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(Duration::from_pg_interval(value.as_bytes()?)
+                .ok_or("invalid PostgreSQL interval")?),
+            PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::TypeInfo;
+
+    use super::*;
+
+    #[test]
+    fn encode_interval() {
+        assert_eq!(<Duration as Type<Postgres>>::type_info().name(), "INTERVAL");
+        assert!(<Duration as Type<Postgres>>::compatible(
+            &<PgInterval as Type<Postgres>>::type_info()
+        ));
+
+        let duration: Duration = "P1Y2M3DT4H5M6.5S".parse().unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(duration.encode_by_ref(&mut buf), Ok(IsNull::No)));
+        assert_eq!(&buf[..], &duration.to_pg_interval().unwrap()[..]);
+
+        let mut expected = PgArgumentBuffer::default();
+        let interval = PgInterval {
+            months: 14,
+            days: 3,
+            microseconds: 14_706_500_000,
+        };
+        assert!(matches!(
+            interval.encode_by_ref(&mut expected),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(&buf[..], &expected[..]);
+
+        let mut buf = PgArgumentBuffer::default();
+        let duration = Duration::new(0., 0., 0., 0., 0., f32::NAN);
+        assert!(duration.encode_by_ref(&mut buf).is_err());
+    }
+}
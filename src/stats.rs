@@ -0,0 +1,165 @@
+#[cfg(feature = "time_03")]
+use time::OffsetDateTime;
+
+use crate::{CalendarPolicy, Duration};
+
+/// Summary statistics over a collection of durations.
+///
+/// They are computed either exactly, resolving each duration at an
+/// anchor into a `time::Duration` with [`DurationStats::at`], or
+/// nominally on the components with [`DurationStats::nominal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationStats<T> {
+    pub count: usize,
+    pub sum: T,
+    pub mean: T,
+    pub min: T,
+    pub max: T,
+}
+
+impl DurationStats<Duration> {
+    /// Compute the statistics without an anchor.
+    ///
+    /// The sum and mean are taken component-wise, so the mean of `P1M` and
+    /// `P2M` is `P1.5M`, and components aren't carried over: the mean of
+    /// `PT1H` and `PT0S` is `PT0.5H`. The minimum and maximum compare
+    /// durations with the average Gregorian year and month lengths, see
+    /// [`CalendarPolicy::Average`], and are one of the input durations.
+    ///
+    /// This method will return `None` if `durations` is empty.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, DurationStats};
+    ///
+    /// let durations: Vec<Duration> = ["PT30S", "PT2M", "PT1M30S"]
+    ///     .iter()
+    ///     .map(|s| s.parse().unwrap())
+    ///     .collect();
+    ///
+    /// let stats = DurationStats::nominal(&durations).unwrap();
+    /// assert_eq!(stats.count, 3);
+    /// assert_eq!(stats.sum.to_string(), "PT3M60S");
+    /// assert_eq!(stats.min.to_string(), "PT30S");
+    /// assert_eq!(stats.max.to_string(), "PT2M");
+    /// ```
+    pub fn nominal<'a>(durations: impl IntoIterator<Item = &'a Duration>) -> Option<Self> {
+        let length = |duration: &Duration| {
+            duration
+                .to_seconds_f64(CalendarPolicy::Average)
+                .unwrap_or_default()
+        };
+
+        let mut durations = durations.into_iter();
+        let first = *durations.next()?;
+        let mut stats = DurationStats {
+            count: 1,
+            sum: first,
+            mean: first,
+            min: first,
+            max: first,
+        };
+
+        for duration in durations {
+            stats.count += 1;
+            stats.sum = stats.sum.saturating_add(duration);
+            if length(duration) < length(&stats.min) {
+                stats.min = *duration;
+            }
+            if length(duration) > length(&stats.max) {
+                stats.max = *duration;
+            }
+        }
+
+        stats.mean = stats.sum.saturating_mul(1. / stats.count as f32);
+        Some(stats)
+    }
+}
+
+#[cfg(feature = "time_03")]
+impl DurationStats<time::Duration> {
+    /// Compute the exact statistics of the spans the durations cover when
+    /// each of them is applied at `anchor`.
+    ///
+    /// The mean is rounded toward zero to the nanosecond. This method will
+    /// return `None` if `durations` is empty.
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, DurationStats};
+    /// use time::macros::datetime;
+    ///
+    /// let durations: Vec<Duration> = ["P1M", "P30D"]
+    ///     .iter()
+    ///     .map(|s| s.parse().unwrap())
+    ///     .collect();
+    ///
+    /// let stats = DurationStats::at(&durations, datetime!(2023-02-01 00:00:00 UTC)).unwrap();
+    /// assert_eq!(stats.min, time::Duration::days(28));
+    /// assert_eq!(stats.mean, time::Duration::days(29));
+    /// ```
+    pub fn at<'a>(
+        durations: impl IntoIterator<Item = &'a Duration>,
+        anchor: OffsetDateTime,
+    ) -> Option<Self> {
+        let mut spans = durations
+            .into_iter()
+            .map(|duration| (anchor + *duration) - anchor);
+
+        let first = spans.next()?;
+        let mut stats = DurationStats {
+            count: 1,
+            sum: first,
+            mean: first,
+            min: first,
+            max: first,
+        };
+
+        for span in spans {
+            stats.count += 1;
+            stats.sum = stats.sum.saturating_add(span);
+            stats.min = stats.min.min(span);
+            stats.max = stats.max.max(span);
+        }
+
+        stats.mean = stats.sum / stats.count as u32;
+        Some(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nominal_stats() {
+        let durations: Vec<Duration> = ["P1M", "P2M", "P29D"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        let stats = DurationStats::nominal(&durations).unwrap();
+        assert_eq!(stats.sum, "P3M29D".parse().unwrap());
+        assert_eq!(stats.mean, Duration::new(0., 1., 29. / 3., 0., 0., 0.));
+        assert_eq!(stats.min, durations[2]);
+        assert_eq!(stats.max, durations[1]);
+
+        assert_eq!(DurationStats::nominal(&[]), None);
+    }
+
+    #[cfg(feature = "time_03")]
+    #[test]
+    fn anchored_stats() {
+        use time::macros::datetime;
+
+        let durations: Vec<Duration> = ["P1M", "P29D", "PT1H"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let anchor = datetime!(2024-02-01 00:00:00 UTC);
+
+        let stats = DurationStats::at(&durations, anchor).unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.sum, time::Duration::hours(29 * 48 + 1));
+        assert_eq!(stats.min, time::Duration::HOUR);
+        assert_eq!(stats.max, time::Duration::days(29));
+    }
+}
@@ -0,0 +1,353 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::{Duration, ParseDurationError};
+
+/// Error returned by [`DurationTemplate`].
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    /// A `{` without its `}`
+    UnclosedPlaceholder { position: usize },
+    /// A `}` without its `{`, or a `{` inside a placeholder
+    UnexpectedBrace { position: usize },
+    /// A placeholder name is empty or not made of ASCII letters, digits
+    /// and `_`
+    InvalidName { position: usize },
+    /// A placeholder is followed by another one or by a number, so its
+    /// value can't be extracted
+    AmbiguousPlaceholder { position: usize },
+    /// The pattern isn't a duration once its placeholders are replaced
+    InvalidPattern(ParseDurationError),
+    /// No value was given for a placeholder
+    MissingVariable(String),
+    /// A value is negative, NaN or infinite
+    InvalidValue { name: String, value: f32 },
+    /// The rendered or extracted duration is invalid
+    InvalidDuration(ParseDurationError),
+    /// The input doesn't match the pattern at this byte offset
+    Mismatch { position: usize },
+    /// A placeholder appearing twice has different values in the input
+    Conflict(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UnclosedPlaceholder { position } => {
+                write!(f, "unclosed placeholder at {}", position)
+            }
+            TemplateError::UnexpectedBrace { position } => {
+                write!(f, "unexpected brace at {}", position)
+            }
+            TemplateError::InvalidName { position } => {
+                write!(f, "invalid placeholder name at {}", position)
+            }
+            TemplateError::AmbiguousPlaceholder { position } => {
+                write!(f, "ambiguous placeholder at {}", position)
+            }
+            TemplateError::InvalidPattern(err) => write!(f, "invalid pattern: {}", err),
+            TemplateError::MissingVariable(name) => write!(f, "missing variable `{}`", name),
+            TemplateError::InvalidValue { name, value } => {
+                write!(f, "invalid value {} for `{}`", value, name)
+            }
+            TemplateError::InvalidDuration(err) => write!(f, "{}", err),
+            TemplateError::Mismatch { position } => {
+                write!(f, "input doesn't match the pattern at {}", position)
+            }
+            TemplateError::Conflict(name) => {
+                write!(f, "conflicting values for `{}`", name)
+            }
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// A duration pattern with named placeholders, such as `P{days}DT{hours}H`.
+///
+/// Placeholders stand for the number of a component. The pattern is
+/// checked when parsed, by replacing every placeholder with `1`.
+///
+/// ```rust
+/// use iso8601_duration::{Duration, DurationTemplate};
+///
+/// let retention: DurationTemplate = "P{days}DT{hours}H".parse().unwrap();
+///
+/// let daily = retention.render(&[("days", 1.), ("hours", 0.)]).unwrap();
+/// assert_eq!(daily, Duration::new(0., 0., 1., 0., 0., 0.));
+///
+/// let vars = retention.extract("P7DT12H").unwrap();
+/// assert_eq!(vars, vec![("days".to_string(), 7.), ("hours".to_string(), 12.)]);
+///
+/// assert!("P{days}X".parse::<DurationTemplate>().is_err());
+/// assert!(retention.extract("P7D").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationTemplate {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+impl DurationTemplate {
+    /// Parse a pattern, with placeholders written `{name}`
+    pub fn parse(pattern: &str) -> Result<DurationTemplate, TemplateError> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices();
+
+        while let Some((position, c)) = chars.next() {
+            match c {
+                '{' => {
+                    let start = position + 1;
+                    let end = loop {
+                        match chars.next() {
+                            Some((end, '}')) => break end,
+                            Some((end, '{')) => {
+                                return Err(TemplateError::UnexpectedBrace { position: end })
+                            }
+                            Some(_) => {}
+                            None => return Err(TemplateError::UnclosedPlaceholder { position }),
+                        }
+                    };
+
+                    let name = &pattern[start..end];
+                    if name.is_empty()
+                        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    {
+                        return Err(TemplateError::InvalidName { position });
+                    }
+
+                    match segments.last() {
+                        Some(Segment::Placeholder(_)) if literal.is_empty() => {
+                            return Err(TemplateError::AmbiguousPlaceholder { position });
+                        }
+                        Some(Segment::Placeholder(_)) if literal.starts_with(is_number_char) => {
+                            return Err(TemplateError::AmbiguousPlaceholder {
+                                position: position - literal.len(),
+                            });
+                        }
+                        _ => {}
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Placeholder(name.to_string()));
+                }
+                '}' => return Err(TemplateError::UnexpectedBrace { position }),
+                c => literal.push(c),
+            }
+        }
+
+        if let Some(Segment::Placeholder(_)) = segments.last() {
+            if literal.starts_with(is_number_char) {
+                return Err(TemplateError::AmbiguousPlaceholder {
+                    position: pattern.len() - literal.len(),
+                });
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        let template = DurationTemplate {
+            pattern: pattern.to_string(),
+            segments,
+        };
+        Duration::parse(&template.substitute(|_| Some(1.))?)
+            .map_err(TemplateError::InvalidPattern)?;
+
+        Ok(template)
+    }
+
+    /// Return the placeholder names, in the order they first appear
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for segment in &self.segments {
+            if let Segment::Placeholder(name) = segment {
+                if !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Replace the placeholders with `vars` and parse the result
+    ///
+    /// Every placeholder must have a value, extra variables are ignored.
+    pub fn render(&self, vars: &[(&str, f32)]) -> Result<Duration, TemplateError> {
+        let input = self.substitute(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| *value)
+        })?;
+
+        Duration::parse(&input).map_err(TemplateError::InvalidDuration)
+    }
+
+    /// Match `input` against the pattern and return the value of each
+    /// placeholder, in the order they first appear
+    pub fn extract(&self, input: &str) -> Result<Vec<(String, f32)>, TemplateError> {
+        let mut vars: Vec<(String, f32)> = Vec::new();
+        let mut position = 0;
+
+        for segment in &self.segments {
+            let rest = &input[position..];
+            match segment {
+                Segment::Literal(literal) => {
+                    if !rest.starts_with(literal.as_str()) {
+                        return Err(TemplateError::Mismatch { position });
+                    }
+                    position += literal.len();
+                }
+                Segment::Placeholder(name) => {
+                    let len = rest.find(|c| !is_number_char(c)).unwrap_or(rest.len());
+                    let value = rest[..len]
+                        .replace(',', ".")
+                        .parse::<f32>()
+                        .map_err(|_| TemplateError::Mismatch { position })?;
+
+                    match vars.iter().find(|(var, _)| var == name) {
+                        Some((_, previous)) if *previous != value => {
+                            return Err(TemplateError::Conflict(name.clone()));
+                        }
+                        Some(_) => {}
+                        None => vars.push((name.clone(), value)),
+                    }
+                    position += len;
+                }
+            }
+        }
+
+        if position != input.len() {
+            return Err(TemplateError::Mismatch { position });
+        }
+        Duration::parse(input).map_err(TemplateError::InvalidDuration)?;
+
+        Ok(vars)
+    }
+
+    fn substitute(&self, value: impl Fn(&str) -> Option<f32>) -> Result<String, TemplateError> {
+        let mut output = String::with_capacity(self.pattern.len());
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => output.push_str(literal),
+                Segment::Placeholder(name) => {
+                    let value =
+                        value(name).ok_or_else(|| TemplateError::MissingVariable(name.clone()))?;
+                    if !value.is_finite() || value < 0. {
+                        return Err(TemplateError::InvalidValue {
+                            name: name.clone(),
+                            value,
+                        });
+                    }
+                    output.push_str(&value.to_string());
+                }
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+fn is_number_char(c: char) -> bool {
+    c.is_ascii_digit() || c == '.' || c == ','
+}
+
+impl FromStr for DurationTemplate {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DurationTemplate::parse(s)
+    }
+}
+
+impl fmt::Display for DurationTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template() {
+        assert_eq!(
+            DurationTemplate::parse("P{days"),
+            Err(TemplateError::UnclosedPlaceholder { position: 1 })
+        );
+        assert_eq!(
+            DurationTemplate::parse("P{d{ays}D"),
+            Err(TemplateError::UnexpectedBrace { position: 3 })
+        );
+        assert_eq!(
+            DurationTemplate::parse("P1D}"),
+            Err(TemplateError::UnexpectedBrace { position: 3 })
+        );
+        assert_eq!(
+            DurationTemplate::parse("P{}D"),
+            Err(TemplateError::InvalidName { position: 1 })
+        );
+        assert_eq!(
+            DurationTemplate::parse("P{a}{b}D"),
+            Err(TemplateError::AmbiguousPlaceholder { position: 4 })
+        );
+        assert_eq!(
+            DurationTemplate::parse("P{a}1D"),
+            Err(TemplateError::AmbiguousPlaceholder { position: 4 })
+        );
+        assert!(matches!(
+            DurationTemplate::parse("P{a}X"),
+            Err(TemplateError::InvalidPattern(_))
+        ));
+
+        let template = DurationTemplate::parse("P{n}W").unwrap();
+        assert_eq!(template.names(), vec!["n"]);
+        assert_eq!(template.to_string(), "P{n}W");
+        assert_eq!(
+            template.render(&[("n", 2.)]),
+            Ok(Duration::new(0., 0., 14., 0., 0., 0.))
+        );
+        assert_eq!(
+            template.render(&[]),
+            Err(TemplateError::MissingVariable("n".to_string()))
+        );
+        assert_eq!(
+            template.render(&[("n", -1.)]),
+            Err(TemplateError::InvalidValue {
+                name: "n".to_string(),
+                value: -1.
+            })
+        );
+        assert_eq!(template.extract("P2,5W"), Ok(vec![("n".to_string(), 2.5)]));
+        assert_eq!(
+            template.extract("P2D"),
+            Err(TemplateError::Mismatch { position: 2 })
+        );
+        assert_eq!(
+            template.extract("P2WT"),
+            Err(TemplateError::Mismatch { position: 3 })
+        );
+
+        let template = DurationTemplate::parse("P{n}DT{n}H").unwrap();
+        assert_eq!(template.names(), vec!["n"]);
+        assert_eq!(
+            template.render(&[("n", 3.)]),
+            Ok(Duration::new(0., 0., 3., 3., 0., 0.))
+        );
+        assert_eq!(template.extract("P3DT3H"), Ok(vec![("n".to_string(), 3.)]));
+        assert_eq!(
+            template.extract("P3DT4H"),
+            Err(TemplateError::Conflict("n".to_string()))
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Generators and invariant checks for property tests of code built on
+//! `Duration`.
+//!
+//! Requires the `test-support` feature. The generator is deterministic
+//! and doesn't depend on any property testing framework, so it can be
+//! driven by a plain loop or wrapped in a strategy:
+//!
+//! ```rust
+//! use iso8601_duration::test_support::{check_format_parse, check_sanitize_idempotent, Generator};
+//!
+//! for duration in Generator::new(42).take(1000) {
+//!     check_format_parse(&duration).unwrap();
+//!     check_sanitize_idempotent(&duration).unwrap();
+//! }
+//! ```
+
+use std::{error::Error, fmt};
+
+#[cfg(feature = "time_03")]
+use time::OffsetDateTime;
+
+use crate::{Duration, NonFinitePolicy};
+
+/// An endless, deterministic source of durations.
+///
+/// Each component is zero half of the time, otherwise a multiple of a
+/// quarter below 1000, and one duration in four is negated. Values stay
+/// small enough to be added to any datetime of the last millennia.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// Create a generator, the same `seed` always giving the same durations
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            // xorshift gets stuck on zero
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn component(&mut self) -> f32 {
+        let value = self.next_u64();
+        if value.is_multiple_of(2) {
+            0.
+        } else {
+            (value >> 1) as f32 % 4000. / 4.
+        }
+    }
+
+    /// Return the next duration
+    pub fn duration(&mut self) -> Duration {
+        let duration = Duration::new(
+            self.component(),
+            self.component(),
+            self.component(),
+            self.component(),
+            self.component(),
+            self.component(),
+        );
+
+        if self.next_u64().is_multiple_of(4) {
+            -duration
+        } else {
+            duration
+        }
+    }
+}
+
+impl Iterator for Generator {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        Some(self.duration())
+    }
+}
+
+/// Error returned when a duration breaks an invariant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvariantViolation {
+    /// Name of the invariant
+    pub invariant: &'static str,
+    /// The duration breaking it
+    pub duration: Duration,
+    /// What was found instead of the expected value
+    pub detail: String,
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} doesn't hold for {:?}: {}",
+            self.invariant, self.duration, self.detail
+        )
+    }
+}
+
+impl Error for InvariantViolation {}
+
+fn violation(invariant: &'static str, duration: &Duration, detail: String) -> InvariantViolation {
+    InvariantViolation {
+        invariant,
+        duration: *duration,
+        detail,
+    }
+}
+
+/// Check that formatting `duration` and parsing it back gives the same
+/// duration.
+///
+/// Durations with non-finite components can't be formatted, they pass
+/// trivially.
+pub fn check_format_parse(duration: &Duration) -> Result<(), InvariantViolation> {
+    if duration
+        .components()
+        .iter()
+        .any(|(_, value)| !value.is_finite())
+    {
+        return Ok(());
+    }
+
+    let formatted = duration.to_string();
+    match Duration::parse(&formatted) {
+        Ok(parsed) if parsed == *duration => Ok(()),
+        // `-0.0` is formatted as zero
+        Ok(parsed) if parsed.components() == duration.components() => Ok(()),
+        Ok(parsed) => Err(violation(
+            "parse(format(d)) == d",
+            duration,
+            format!(
+                "{:?} formatted as {:?} parses to {:?}",
+                duration, formatted, parsed
+            ),
+        )),
+        Err(err) => Err(violation(
+            "parse(format(d)) == d",
+            duration,
+            format!("{:?} doesn't parse: {}", formatted, err),
+        )),
+    }
+}
+
+/// Check that sanitizing a sanitized duration changes nothing, with
+/// both policies.
+pub fn check_sanitize_idempotent(duration: &Duration) -> Result<(), InvariantViolation> {
+    for policy in [NonFinitePolicy::Reject, NonFinitePolicy::Clamp] {
+        let mut once = *duration;
+        if once.sanitize(policy).is_err() {
+            continue;
+        }
+
+        let mut twice = once;
+        match twice.sanitize(policy) {
+            Ok(report) if report.is_clean() && twice == once => {}
+            other => {
+                return Err(violation(
+                    "sanitize(sanitize(d)) == sanitize(d)",
+                    duration,
+                    format!(
+                        "{:?} sanitized again with {:?} gives {:?}",
+                        once, policy, other
+                    ),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that subtracting `duration` after adding it to `anchor` gives
+/// `anchor` back.
+///
+/// Adding months clamps the day to the end of shorter months, which
+/// subtraction can't undo, so anchors after the 28th of their month pass
+/// trivially.
+#[cfg(feature = "time_03")]
+pub fn check_add_sub_inverse(
+    duration: &Duration,
+    anchor: OffsetDateTime,
+) -> Result<(), InvariantViolation> {
+    if anchor.day() > 28 {
+        return Ok(());
+    }
+
+    let added = anchor + *duration;
+    let back = added - *duration;
+    if back == anchor {
+        Ok(())
+    } else {
+        Err(violation(
+            "(a + d) - d == a",
+            duration,
+            format!("{} + d is {}, minus d is {}", anchor, added, back),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_durations_hold() {
+        for duration in Generator::new(0).take(2000) {
+            check_format_parse(&duration).unwrap();
+            check_sanitize_idempotent(&duration).unwrap();
+        }
+
+        assert_eq!(
+            Generator::new(7).take(10).collect::<Vec<_>>(),
+            Generator::new(7).take(10).collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "time_03")]
+    #[test]
+    fn generated_durations_invert() {
+        use time::macros::datetime;
+
+        let anchor = datetime!(2023-06-15 10:30:00 UTC);
+        for duration in Generator::new(1).take(2000) {
+            check_add_sub_inverse(&duration, anchor).unwrap();
+        }
+    }
+
+    #[test]
+    fn report_violation() {
+        let err = violation("d == d", &Duration::ZERO, "nope".to_string());
+        assert_eq!(
+            err.to_string(),
+            "d == d doesn't hold for Duration { year: 0.0, month: 0.0, day: 0.0, \
+             hour: 0.0, minute: 0.0, second: 0.0 }: nope"
+        );
+    }
+}
@@ -1,66 +1,478 @@
-use std::{convert::TryFrom, ops::Add};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt,
+    ops::{Add, Sub},
+};
 
-use crate::Duration;
+use crate::{
+    apply::{shift_months, whole_parts},
+    ApplyDuration, ApplyError, Duration, EomPolicy,
+};
 
-use time::{Date, OffsetDateTime, PrimitiveDateTime};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, UtcDateTime};
 
+impl ApplyDuration for OffsetDateTime {
+    /// Add `duration`, resolving days past the end of a month with `policy`
+    ///
+    /// ```rust
+    /// use iso8601_duration::{ApplyDuration, Duration, EomPolicy};
+    /// use time::macros::datetime;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// let start = datetime!(2023-01-31 10:00:00 UTC);
+    /// assert_eq!(
+    ///     start.apply(&month, EomPolicy::Overflow),
+    ///     Ok(datetime!(2023-03-03 10:00:00 UTC))
+    /// );
+    /// assert_eq!(start.apply(&month, EomPolicy::Clamp), Ok(start + month));
+    /// ```
+    fn apply(&self, duration: &Duration, policy: EomPolicy) -> Result<Self, ApplyError> {
+        let (months, days, nanos) = whole_parts(duration)?;
+
+        let (year, month, day) = self.date().to_calendar_date();
+        let (year, month, day, extra) =
+            shift_months((year, month as u32, day as u32), months, policy)?;
+
+        let date = time::Month::try_from(month as u8)
+            .and_then(|month| Date::from_calendar_date(year, month, day as u8))
+            .map_err(|_| ApplyError::OutOfRange)?;
+        let days = (days + extra as i64)
+            .checked_mul(86400)
+            .map(time::Duration::seconds)
+            .ok_or(ApplyError::OutOfRange)?;
+        let date = date.checked_add(days).ok_or(ApplyError::OutOfRange)?;
+
+        let seconds =
+            i64::try_from(nanos.div_euclid(1_000_000_000)).map_err(|_| ApplyError::OutOfRange)?;
+        let time = time::Duration::new(seconds, nanos.rem_euclid(1_000_000_000) as i32);
+
+        self.replace_date(date)
+            .checked_add(time)
+            .ok_or(ApplyError::OutOfRange)
+    }
+}
+
+/// Add a duration, clamping the day to the end of shorter months.
+///
+/// Results past the supported range saturate to `Date::MIN` or
+/// `Date::MAX`, use [`Duration::checked_add_to`] to detect overflows.
 impl Add<Duration> for OffsetDateTime {
     type Output = Self;
 
     fn add(self, rhs: Duration) -> Self::Output {
-        // Date component arithmetic
+        add_time(add_date(self, &rhs), &rhs)
+    }
+}
 
-        let (year, month, mut day) = self.date().to_calendar_date();
-        let month_u8 = month as u8;
+/// Subtract a duration, undoing `Add` step by step.
+///
+/// The time components are removed first, then `day`, then `month` and
+/// `year`, so that `(datetime + duration) - duration == datetime`. This
+/// only fails when adding clamped the day to the end of a shorter month:
+/// `2023-01-31 + P1M` is `2023-02-28`, and going back a month from there
+/// gives `2023-01-28`. Use [`Duration::sub_strict`] to detect that case.
+impl Sub<Duration> for OffsetDateTime {
+    type Output = Self;
 
-        // Add years and months
-        // We do this manually to handle month-end clamping correctly.
-        // Month is 1-based, so convert to 0-based for calculation
-        let month_0_based = month_u8 as u32 - 1;
-        let total_months_0_based = month_0_based + rhs.month as u32;
+    fn sub(self, rhs: Duration) -> Self::Output {
+        sub_date(sub_time(self, &rhs), &rhs)
+    }
+}
 
-        let new_year = year + rhs.year as i32 + (total_months_0_based / 12) as i32;
-        let new_month_u8 = (total_months_0_based % 12 + 1) as u8;
+/// Add the `year`, `month` and `day` components, clamping the day to the
+/// end of shorter months as for `OffsetDateTime`.
+///
+/// The time components are ignored, use [`Duration::checked_add_to_date`]
+/// to reject them.
+impl Add<Duration> for Date {
+    type Output = Self;
 
-        let new_month = match time::Month::try_from(new_month_u8) {
-            Ok(m) => m,
-            // This should not happen with the modulo arithmetic above, but as a safeguard:
-            Err(_) => return self,
-        };
+    fn add(self, rhs: Duration) -> Self::Output {
+        add_date(self.midnight().assume_utc(), &rhs).date()
+    }
+}
+
+/// Subtract the `year`, `month` and `day` components, undoing `Add`.
+///
+/// The time components are ignored.
+impl Sub<Duration> for Date {
+    type Output = Self;
 
-        // Clamp day to the valid range for the new month and year.
-        let max_day_in_month = new_month.length(new_year);
-        if day > max_day_in_month {
-            day = max_day_in_month;
+    fn sub(self, rhs: Duration) -> Self::Output {
+        sub_date(self.midnight().assume_utc(), &rhs).date()
+    }
+}
+
+/// Add a duration as for `OffsetDateTime` in UTC, which has no daylight
+/// saving time.
+impl Add<Duration> for PrimitiveDateTime {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        let result = self.assume_utc() + rhs;
+        PrimitiveDateTime::new(result.date(), result.time())
+    }
+}
+
+/// Subtract a duration as for `OffsetDateTime` in UTC.
+impl Sub<Duration> for PrimitiveDateTime {
+    type Output = Self;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        let result = self.assume_utc() - rhs;
+        PrimitiveDateTime::new(result.date(), result.time())
+    }
+}
+
+/// Add a duration as for `OffsetDateTime`.
+impl Add<Duration> for UtcDateTime {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        (OffsetDateTime::from(self) + rhs).into()
+    }
+}
+
+/// Subtract a duration as for `OffsetDateTime`.
+impl Sub<Duration> for UtcDateTime {
+    type Output = Self;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        (OffsetDateTime::from(self) - rhs).into()
+    }
+}
+
+/// Error returned by [`Duration::sub_strict`] when the subtraction can't
+/// be undone by adding the duration back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonInvertibleError {
+    /// The result of the lossy subtraction
+    pub result: OffsetDateTime,
+}
+
+impl fmt::Display for NonInvertibleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("subtraction can't be undone by adding the duration back")
+    }
+}
+
+impl Error for NonInvertibleError {}
+
+impl Duration {
+    /// Subtract the duration from `datetime`, failing unless exactly one
+    /// datetime gives `datetime` back once the duration is added.
+    ///
+    /// Because of month-end clamping, `2023-01-28` to `2023-01-31` all
+    /// give `2023-02-28` with `P1M` added, and no datetime gives
+    /// `2023-03-31` with `P1M` added, since `2023-02-28 + P1M` is
+    /// `2023-03-28`. Both cases are errors.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// assert_eq!(
+    ///     month.sub_strict(datetime!(2023-04-15 00:00:00 UTC)),
+    ///     Ok(datetime!(2023-03-15 00:00:00 UTC))
+    /// );
+    /// assert!(month.sub_strict(datetime!(2023-02-28 00:00:00 UTC)).is_err());
+    /// assert!(month.sub_strict(datetime!(2023-03-31 00:00:00 UTC)).is_err());
+    /// ```
+    pub fn sub_strict(
+        &self,
+        datetime: OffsetDateTime,
+    ) -> Result<OffsetDateTime, NonInvertibleError> {
+        let before_days = sub_time(datetime, self);
+        let before_month =
+            before_days.replace_date(before_days.date().saturating_sub(whole_days(self.day)));
+        let result = sub_year_month(before_month, self);
+
+        let (year, month, day) = before_month.date().to_calendar_date();
+        let (result_year, result_month, result_day) = result.date().to_calendar_date();
+
+        // a shorter target month clamps several days of the original month
+        // onto its last day, and leaves no original day for the days past it
+        let clamped = result_day != day;
+        let ambiguous = day == month.length(year) && day < result_month.length(result_year);
+
+        if clamped || ambiguous || result + *self != datetime {
+            Err(NonInvertibleError { result })
+        } else {
+            Ok(result)
         }
+    }
 
-        let date_with_ym_added = match Date::from_calendar_date(new_year, new_month, day) {
-            Ok(d) => d,
-            // This should not happen due to the clamping logic, but as a safeguard:
-            Err(_) => return self,
-        };
+    /// Add the duration to `datetime`, returning `None` instead of
+    /// saturating when a component isn't finite or the result is out of
+    /// the supported range.
+    ///
+    /// `+` saturates to `Date::MIN` or `Date::MAX`, which is fine for
+    /// "forever" sentinels but hides overflows.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::datetime;
+    ///
+    /// let start = datetime!(2023-01-31 10:00:00 UTC);
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// assert_eq!(month.checked_add_to(start), Some(start + month));
+    /// assert_eq!(Duration::years(100_000).checked_add_to(start), None);
+    /// ```
+    pub fn checked_add_to(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        let datetime = checked_shift_year_month(
+            datetime,
+            checked_whole(self.year)?,
+            checked_whole(self.month)?,
+        )?;
+        let date = datetime.date().checked_add(checked_whole_days(self.day)?)?;
 
-        // Add days. `saturating_add` with `time::Duration::days` handles calendar days.
-        let final_date = date_with_ym_added.saturating_add(time::Duration::days(rhs.day as i64));
+        datetime
+            .replace_date(date)
+            .checked_add(checked_time_duration(self)?)
+    }
+
+    /// Add the duration to `date`, returning `None` if it has a time
+    /// component, if a component isn't finite, or if the result is out of
+    /// the supported range.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    /// use time::macros::date;
+    ///
+    /// let month: Duration = "P1M".parse().unwrap();
+    /// assert_eq!(month.checked_add_to_date(date!(2023-01-31)), Some(date!(2023-02-28)));
+    /// assert_eq!(date!(2023-01-31) + month, date!(2023-02-28));
+    ///
+    /// let hours: Duration = "P1DT12H".parse().unwrap();
+    /// assert_eq!(hours.checked_add_to_date(date!(2023-01-31)), None);
+    /// assert_eq!(date!(2023-01-31) + hours, date!(2023-02-01));
+    /// ```
+    pub fn checked_add_to_date(&self, date: Date) -> Option<Date> {
+        if self.hour != 0. || self.minute != 0. || self.second != 0. {
+            return None;
+        }
+
+        self.checked_add_to(date.midnight().assume_utc())
+            .map(|datetime| datetime.date())
+    }
+
+    /// Subtract the duration from `datetime` like `-` does, returning
+    /// `None` instead of saturating when a component isn't finite or the
+    /// result is out of the supported range.
+    pub fn checked_sub_from(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        let datetime = datetime.checked_sub(checked_time_duration(self)?)?;
+        let date = datetime.date().checked_sub(checked_whole_days(self.day)?)?;
+
+        checked_shift_year_month(
+            datetime.replace_date(date),
+            checked_whole(self.year)?.checked_neg()?,
+            checked_whole(self.month)?.checked_neg()?,
+        )
+    }
+
+    /// Apply only the `year`, `month` and `day` components to `datetime`.
+    ///
+    /// `datetime + duration` is the same as
+    /// `duration.apply_time_part(duration.apply_date_part(datetime))`,
+    /// splitting it lets other adjustments run in between.
+    pub fn apply_date_part(&self, datetime: OffsetDateTime) -> OffsetDateTime {
+        add_date(datetime, self)
+    }
+
+    /// Apply only the `hour`, `minute` and `second` components to `datetime`.
+    pub fn apply_time_part(&self, datetime: OffsetDateTime) -> OffsetDateTime {
+        add_time(datetime, self)
+    }
+}
+
+/// Apply the `year`, `month` and `day` components of `rhs` to `datetime`.
+pub(crate) fn add_date(datetime: OffsetDateTime, rhs: &Duration) -> OffsetDateTime {
+    let datetime = add_year_month(datetime, rhs);
 
-        // Time component arithmetic
-        let time_duration = time::Duration::hours(rhs.hour as i64)
+    // Add days. `saturating_add` with `time::Duration::days` handles calendar days.
+    let final_date = datetime.date().saturating_add(whole_days(rhs.day));
+
+    datetime.replace_date(final_date)
+}
+
+/// Apply the `year` and `month` components of `rhs` to `datetime`.
+pub(crate) fn add_year_month(datetime: OffsetDateTime, rhs: &Duration) -> OffsetDateTime {
+    shift_year_month(
+        datetime,
+        i64::from(rhs.year as i32),
+        i64::from(rhs.month as i32),
+    )
+}
+
+/// Remove the `year` and `month` components of `rhs` from `datetime`.
+///
+/// The components are negated as `i64`, since `-i32::MIN` overflows.
+fn sub_year_month(datetime: OffsetDateTime, rhs: &Duration) -> OffsetDateTime {
+    shift_year_month(
+        datetime,
+        -i64::from(rhs.year as i32),
+        -i64::from(rhs.month as i32),
+    )
+}
+
+/// Move `datetime` by whole `years` and `months`, clamping the day to the
+/// end of the resulting month.
+///
+/// Results past the supported range saturate to `Date::MIN` or `Date::MAX`.
+fn shift_year_month(datetime: OffsetDateTime, years: i64, months: i64) -> OffsetDateTime {
+    checked_shift_year_month(datetime, years, months).unwrap_or_else(|| {
+        if years.saturating_mul(12).saturating_add(months) > 0 {
+            datetime.replace_date(Date::MAX)
+        } else {
+            datetime.replace_date(Date::MIN)
+        }
+    })
+}
+
+/// Move `datetime` by whole `years` and `months`, clamping the day to the
+/// end of the resulting month, or return `None` past the supported range.
+fn checked_shift_year_month(
+    datetime: OffsetDateTime,
+    years: i64,
+    months: i64,
+) -> Option<OffsetDateTime> {
+    let (year, month, day) = datetime.date().to_calendar_date();
+
+    // Month is 1-based, so convert to 0-based for calculation
+    let total_months_0_based = (month as i64 - 1).checked_add(months)?;
+    let new_year = (year as i64)
+        .checked_add(years)?
+        .checked_add(total_months_0_based.div_euclid(12))?;
+    let new_year = i32::try_from(new_year).ok()?;
+    let new_month = time::Month::try_from((total_months_0_based.rem_euclid(12) + 1) as u8).ok()?;
+
+    // Clamp day to the valid range for the new month and year.
+    let day = day.min(new_month.length(new_year));
+
+    Date::from_calendar_date(new_year, new_month, day)
+        .ok()
+        .map(|date| datetime.replace_date(date))
+}
+
+/// Return `days` whole days as `time::Duration`, bounded so that it
+/// can't overflow but still saturates any date.
+pub(crate) fn whole_days(days: f32) -> time::Duration {
+    const MAX_DAYS: i64 = 1_000_000_000;
+
+    time::Duration::days((days as i64).clamp(-MAX_DAYS, MAX_DAYS))
+}
+
+/// Remove the `year`, `month` and `day` components of `rhs` from `datetime`.
+pub(crate) fn sub_date(datetime: OffsetDateTime, rhs: &Duration) -> OffsetDateTime {
+    let date = datetime.date().saturating_sub(whole_days(rhs.day));
+
+    sub_year_month(datetime.replace_date(date), rhs)
+}
+
+/// Remove the `hour`, `minute` and `second` components of `rhs` from `datetime`.
+pub(crate) fn sub_time(datetime: OffsetDateTime, rhs: &Duration) -> OffsetDateTime {
+    datetime.saturating_sub(time_duration(rhs))
+}
+
+/// Apply the `hour`, `minute` and `second` components of `rhs` to `datetime`.
+pub(crate) fn add_time(datetime: OffsetDateTime, rhs: &Duration) -> OffsetDateTime {
+    datetime.saturating_add(time_duration(rhs))
+}
+
+// keep well below `i64::MAX` seconds so that the sum can't overflow
+const MAX_SECONDS: f64 = 1e17;
+
+/// Return the `hour`, `minute` and `second` components of `rhs` as `time::Duration`.
+///
+/// Spans too large to be represented saturate to `time::Duration::MIN` or
+/// `time::Duration::MAX`.
+pub(crate) fn time_duration(rhs: &Duration) -> time::Duration {
+    checked_time_duration(rhs).unwrap_or_else(|| {
+        let seconds = time_seconds(rhs);
+        if seconds.is_nan() {
+            time::Duration::ZERO
+        } else if seconds > 0. {
+            time::Duration::MAX
+        } else {
+            time::Duration::MIN
+        }
+    })
+}
+
+/// Return the `hour`, `minute` and `second` components of `rhs` as
+/// `time::Duration`, or `None` if they aren't finite or are too large.
+fn checked_time_duration(rhs: &Duration) -> Option<time::Duration> {
+    if !(-MAX_SECONDS..=MAX_SECONDS).contains(&time_seconds(rhs)) {
+        return None;
+    }
+
+    // Time component arithmetic
+    Some(
+        time::Duration::hours(rhs.hour as i64)
             + time::Duration::minutes(rhs.minute as i64)
-            + time::Duration::seconds_f32(rhs.second);
+            + time::Duration::seconds_f32(rhs.second),
+    )
+}
 
-        // Reconstruct the datetime and add the time duration
-        let primitive_dt = PrimitiveDateTime::new(final_date, self.time());
-        let offset_dt = primitive_dt.assume_offset(self.offset());
+fn time_seconds(rhs: &Duration) -> f64 {
+    rhs.hour.trunc() as f64 * 3600. + rhs.minute.trunc() as f64 * 60. + rhs.second as f64
+}
 
-        offset_dt.saturating_add(time_duration)
+/// Return the whole part of `value`, or `None` if it isn't finite.
+fn checked_whole(value: f32) -> Option<i64> {
+    if value.is_finite() {
+        Some(value as i64)
+    } else {
+        None
     }
 }
 
+/// Return `days` whole days as `time::Duration`, or `None` if they aren't
+/// finite or overflow.
+fn checked_whole_days(days: f32) -> Option<time::Duration> {
+    checked_whole(days)?
+        .checked_mul(86400)
+        .map(time::Duration::seconds)
+}
+
 #[cfg(all(test, feature = "time_03"))]
 mod tests {
     use super::*;
     use time::macros::datetime;
 
+    #[test]
+    fn add_to_other_datetime_types() {
+        use time::macros::{date, utc_datetime};
+
+        let duration: Duration = "P1M2DT3H".parse().unwrap();
+        let start = datetime!(2024-01-31 22:00 UTC);
+        let end = start + duration;
+
+        assert_eq!(date!(2024 - 01 - 31) + duration, date!(2024 - 03 - 02));
+        assert_eq!(date!(2024 - 03 - 02) - duration, date!(2024 - 01 - 29));
+
+        let primitive = PrimitiveDateTime::new(start.date(), start.time());
+        assert_eq!(
+            primitive + duration,
+            PrimitiveDateTime::new(end.date(), end.time())
+        );
+        assert_eq!(
+            primitive + duration - duration,
+            primitive - Duration::days(2)
+        );
+
+        let utc = utc_datetime!(2024-01-31 22:00);
+        assert_eq!(utc + duration, utc_datetime!(2024-03-03 01:00));
+        assert_eq!(OffsetDateTime::from(utc - duration), start - duration);
+
+        assert_eq!(
+            Duration::years(100_000).checked_add_to_date(date!(2024 - 01 - 31)),
+            None
+        );
+    }
+
     #[test]
     fn add_one_month_to_end_of_january() {
         let start = datetime!(2023-01-31 10:00:00 UTC);
@@ -152,4 +564,150 @@ mod tests {
         let end = start + duration;
         assert_eq!(end, datetime!(2024-01-15 10:00:00 UTC));
     }
+
+    #[test]
+    fn apply_date_and_time_parts() {
+        let start = datetime!(2023-01-31 10:30:00 UTC);
+        let duration: Duration = "P1M1DT14H".parse().unwrap();
+
+        let date_applied = duration.apply_date_part(start);
+        assert_eq!(date_applied, datetime!(2023-03-01 10:30:00 UTC));
+        assert_eq!(
+            duration.apply_time_part(date_applied),
+            datetime!(2023-03-02 00:30:00 UTC)
+        );
+        assert_eq!(duration.apply_time_part(date_applied), start + duration);
+    }
+
+    #[test]
+    fn apply_with_eom_policy() {
+        let start = datetime!(2024-01-31 10:00:00 UTC);
+        let duration: Duration = "P1MT1H".parse().unwrap();
+
+        assert_eq!(
+            start.apply(&duration, EomPolicy::Clamp),
+            Ok(datetime!(2024-02-29 11:00:00 UTC))
+        );
+        assert_eq!(
+            start.apply(&duration, EomPolicy::Overflow),
+            Ok(datetime!(2024-03-02 11:00:00 UTC))
+        );
+        assert_eq!(
+            start.apply(&duration, EomPolicy::Reject),
+            Err(ApplyError::DayOutOfMonth)
+        );
+        assert_eq!(
+            start.apply(&"P20000Y".parse().unwrap(), EomPolicy::Clamp),
+            Err(ApplyError::OutOfRange)
+        );
+        assert_eq!(
+            start.apply(
+                &Duration::new(0., 0., f32::NAN, 0., 0., 0.),
+                EomPolicy::Clamp
+            ),
+            Err(ApplyError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn add_extreme_durations_saturates() {
+        let start = datetime!(2023-01-31 10:00:00 UTC);
+
+        assert_eq!((start + Duration::MAX).date(), Date::MAX);
+        assert_eq!((start + Duration::MIN).date(), Date::MIN);
+        assert_eq!((start - Duration::MAX).date(), Date::MIN);
+        assert_eq!((start - Duration::MIN).date(), Date::MAX);
+        assert_eq!(
+            (start - Duration::new(-1e10, 0., 0., 0., 0., 0.)).date(),
+            Date::MAX
+        );
+        assert_eq!(
+            (start - Duration::new(0., -1e10, 0., 0., 0., 0.)).date(),
+            Date::MAX
+        );
+        assert_eq!(
+            (start + "P20000Y".parse::<Duration>().unwrap()).date(),
+            Date::MAX
+        );
+        assert_eq!(
+            (start + "PT1000000000000000000000S".parse::<Duration>().unwrap()).date(),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let start = datetime!(2023-01-31 10:00:00 UTC);
+        let duration: Duration = "P1Y1M1DT1H1M1S".parse().unwrap();
+
+        assert_eq!(duration.checked_add_to(start), Some(start + duration));
+        assert_eq!(duration.checked_sub_from(start), Some(start - duration));
+
+        assert_eq!(Duration::MAX.checked_add_to(start), None);
+        assert_eq!(Duration::MAX.checked_sub_from(start), None);
+        assert_eq!(Duration::MIN.checked_sub_from(start), None);
+        assert!(Duration::MIN.sub_strict(start).is_err());
+        assert_eq!(
+            Duration::new(-1e10, 0., 0., 0., 0., 0.).checked_sub_from(start),
+            None
+        );
+        assert_eq!(
+            "PT1000000000000000000000S"
+                .parse::<Duration>()
+                .unwrap()
+                .checked_add_to(start),
+            None
+        );
+        assert_eq!(
+            Duration::new(0., 0., f32::NAN, 0., 0., 0.).checked_add_to(start),
+            None
+        );
+    }
+
+    #[test]
+    fn sub_one_month_from_end_of_march() {
+        let start = datetime!(2023-03-31 10:00:00 UTC);
+        let duration: Duration = "P1M".parse().unwrap();
+        assert_eq!(start - duration, datetime!(2023-02-28 10:00:00 UTC));
+    }
+
+    #[test]
+    fn sub_mixed_duration() {
+        let start = datetime!(2024-02-16 11:31:01 UTC);
+        let duration: Duration = "P1Y1M1DT1H1M1S".parse().unwrap();
+        assert_eq!(start - duration, datetime!(2023-01-15 10:30:00 UTC));
+    }
+
+    #[test]
+    fn add_then_sub_round_trips() {
+        let durations = [
+            "P1Y",
+            "P1M",
+            "P13M",
+            "P1D",
+            "P1Y2M3DT4H5M6S",
+            "PT36H",
+            "P2M29D",
+            "PT1.5S",
+        ];
+        let mut start = datetime!(2023-01-01 07:45:00 UTC);
+
+        // every day over a leap year boundary
+        for _ in 0..800 {
+            for duration in durations.iter() {
+                let duration: Duration = duration.parse().unwrap();
+                let end = start + duration;
+                let clamped = add_year_month(start, &duration).day() != start.day();
+
+                // clamping at the end of a month is the only lossy case
+                assert_eq!(end - duration == start, !clamped);
+
+                let preimages = (-3..=3)
+                    .filter(|&days| start + time::Duration::days(days) + duration == end)
+                    .count();
+                assert_eq!(duration.sub_strict(end).is_ok(), preimages == 1);
+            }
+            start += time::Duration::days(1);
+        }
+    }
 }
@@ -0,0 +1,231 @@
+use time::OffsetDateTime;
+
+use crate::time_03::{add_date, time_duration};
+use crate::Duration;
+
+/// Unix timestamps of the instants right after each positive leap second
+/// inserted into UTC so far.
+const LEAP_SECONDS: [i64; 27] = [
+    78796800,   // 1972-06-30
+    94694400,   // 1972-12-31
+    126230400,  // 1973-12-31
+    157766400,  // 1974-12-31
+    189302400,  // 1975-12-31
+    220924800,  // 1976-12-31
+    252460800,  // 1977-12-31
+    283996800,  // 1978-12-31
+    315532800,  // 1979-12-31
+    362793600,  // 1981-06-30
+    394329600,  // 1982-06-30
+    425865600,  // 1983-06-30
+    489024000,  // 1985-06-30
+    567993600,  // 1987-12-31
+    631152000,  // 1989-12-31
+    662688000,  // 1990-12-31
+    709948800,  // 1992-06-30
+    741484800,  // 1993-06-30
+    773020800,  // 1994-06-30
+    820454400,  // 1995-12-31
+    867715200,  // 1997-06-30
+    915148800,  // 1998-12-31
+    1136073600, // 2005-12-31
+    1230768000, // 2008-12-31
+    1341100800, // 2012-06-30
+    1435708800, // 2015-06-30
+    1483228800, // 2016-12-31
+];
+
+/// The time scale the time components of a duration are measured on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeScale {
+    /// Unix time, where every minute has exactly 60 seconds.
+    ///
+    /// This is what the `Add` implementations use.
+    #[default]
+    Unix,
+    /// UTC with leap seconds: the time components are SI seconds
+    /// actually elapsed, so crossing a leap second moves the resulting
+    /// wall-clock time one second earlier.
+    Utc,
+    /// International Atomic Time, the datetime is read as a TAI label.
+    ///
+    /// TAI has no leap seconds, so this gives the same result as `Unix`.
+    Tai,
+}
+
+/// Return the number of leap seconds inserted in `(from, to]`.
+fn leap_seconds_between(from: i64, to: i64) -> i64 {
+    LEAP_SECONDS
+        .iter()
+        .filter(|&&leap| from < leap && leap <= to)
+        .count() as i64
+}
+
+impl Duration {
+    /// Add the duration to `datetime`, measuring the time components on
+    /// the given time scale.
+    ///
+    /// The date components are applied as usual. Leap seconds themselves
+    /// (`23:59:60`) can't be represented and resolve to the following second.
+    pub fn add_with_time_scale(
+        &self,
+        datetime: OffsetDateTime,
+        scale: TimeScale,
+    ) -> OffsetDateTime {
+        let datetime = add_date(datetime, self);
+        let elapsed = time_duration(self);
+
+        match scale {
+            TimeScale::Unix | TimeScale::Tai => datetime.saturating_add(elapsed),
+            TimeScale::Utc => {
+                let from = datetime.unix_timestamp();
+                // SI seconds actually elapsed between `datetime` and `to`,
+                // negative when `to` is earlier
+                let elapsed_until = |to: OffsetDateTime| {
+                    let to_timestamp = to.unix_timestamp();
+                    let leap_seconds = if to_timestamp < from {
+                        -leap_seconds_between(to_timestamp, from)
+                    } else {
+                        leap_seconds_between(from, to_timestamp)
+                    };
+                    (to - datetime) + time::Duration::seconds(leap_seconds)
+                };
+
+                let mut to = datetime.saturating_add(elapsed);
+                if elapsed.is_negative() {
+                    // going back, the Unix result misses the leap seconds
+                    // crossed: move forward by them, then back off the
+                    // second a leap second may have added, stopping at the
+                    // earliest supported datetime
+                    to = to.saturating_add(elapsed.saturating_sub(elapsed_until(to)));
+                    while let Some(earlier) = to.checked_sub(time::Duration::SECOND) {
+                        if elapsed_until(earlier) < elapsed {
+                            break;
+                        }
+                        to = earlier;
+                    }
+                    return to;
+                }
+
+                // walk back from the Unix result until no leap second is
+                // counted twice, stopping before undershooting
+                loop {
+                    let excess = elapsed_until(to) - elapsed;
+                    if !excess.is_positive() {
+                        return to;
+                    }
+                    match to.checked_sub(excess) {
+                        Some(candidate) if elapsed_until(candidate) >= elapsed => to = candidate,
+                        _ => return to,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::{macros::datetime, Date};
+
+    #[test]
+    fn add_across_leap_second() {
+        let start = datetime!(2016-12-31 23:00:00 UTC);
+        let duration: Duration = "PT2H".parse().unwrap();
+
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Unix),
+            datetime!(2017-01-01 01:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Tai),
+            datetime!(2017-01-01 01:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Utc),
+            datetime!(2017-01-01 00:59:59 UTC)
+        );
+    }
+
+    #[test]
+    fn add_onto_leap_second() {
+        let start = datetime!(2016-12-31 23:59:59 UTC);
+        let duration: Duration = "PT1S".parse().unwrap();
+
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Utc),
+            datetime!(2017-01-01 00:00:00 UTC)
+        );
+
+        let duration: Duration = "PT2S".parse().unwrap();
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Utc),
+            datetime!(2017-01-01 00:00:00 UTC)
+        );
+    }
+
+    #[test]
+    fn subtract_across_leap_second() {
+        let start = datetime!(2017-01-01 01:00:00 UTC);
+        let duration: Duration = "-PT2H".parse().unwrap();
+
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Unix),
+            datetime!(2016-12-31 23:00:00 UTC)
+        );
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Utc),
+            datetime!(2016-12-31 23:00:01 UTC)
+        );
+
+        // undoes the addition
+        let start = datetime!(2017-01-01 00:59:59 UTC);
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Utc),
+            datetime!(2016-12-31 23:00:00 UTC)
+        );
+
+        let start = datetime!(2017-01-01 00:00:00 UTC);
+        let duration: Duration = "-PT2S".parse().unwrap();
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Utc),
+            datetime!(2016-12-31 23:59:59 UTC)
+        );
+    }
+
+    #[test]
+    fn add_extreme_durations_saturates() {
+        let start = datetime!(2016-12-31 23:00:00 UTC);
+
+        assert_eq!(
+            Duration::MIN
+                .add_with_time_scale(start, TimeScale::Utc)
+                .date(),
+            Date::MIN
+        );
+        assert_eq!(
+            Duration::new(0., 0., 0., 0., 0., -1e15)
+                .add_with_time_scale(start, TimeScale::Utc)
+                .date(),
+            Date::MIN
+        );
+        assert_eq!(
+            Duration::MAX
+                .add_with_time_scale(start, TimeScale::Utc)
+                .date(),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn add_without_leap_second() {
+        let start = datetime!(2020-06-30 23:00:00 UTC);
+        let duration: Duration = "P1DT2H".parse().unwrap();
+
+        assert_eq!(
+            duration.add_with_time_scale(start, TimeScale::Utc),
+            start + duration
+        );
+    }
+}
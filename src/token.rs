@@ -0,0 +1,135 @@
+use std::ops::Range;
+
+use crate::Unit;
+
+/// The kind of a [`DurationToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// The leading `P`
+    Prefix,
+    /// The `T` starting the time components
+    TimeSeparator,
+    /// A number, with an optional `.` or `,` fraction
+    Number,
+    /// A component designator, `M` being a month or a minute depending on
+    /// whether it follows the time separator
+    Designator(Unit),
+    /// The `W` designator
+    Week,
+    /// A character which isn't part of the duration syntax
+    Unknown,
+}
+
+/// A token yielded by [`tokenize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationToken<'a> {
+    pub kind: TokenKind,
+    /// Byte range of the token in the input
+    pub span: Range<usize>,
+    pub text: &'a str,
+}
+
+/// Iterator returned by [`tokenize`].
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    input: &'a str,
+    position: usize,
+    time: bool,
+}
+
+/// Split `input` into duration tokens.
+///
+/// The lexer never fails: it doesn't check the order of the tokens, and
+/// characters it doesn't know are yielded as [`TokenKind::Unknown`]. This
+/// is meant for syntax highlighting and partial validation, use
+/// [`Duration::parse`](crate::Duration::parse) to validate a duration.
+///
+/// ```rust
+/// use iso8601_duration::{tokenize, TokenKind, Unit};
+///
+/// let kinds: Vec<_> = tokenize("P1MT1.5M").map(|token| token.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         TokenKind::Prefix,
+///         TokenKind::Number,
+///         TokenKind::Designator(Unit::Month),
+///         TokenKind::TimeSeparator,
+///         TokenKind::Number,
+///         TokenKind::Designator(Unit::Minute),
+///     ]
+/// );
+/// ```
+pub fn tokenize(input: &str) -> Tokens<'_> {
+    Tokens {
+        input,
+        position: 0,
+        time: false,
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = DurationToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.position..];
+        let c = rest.chars().next()?;
+
+        let (kind, len) = match c {
+            '0'..='9' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                    .unwrap_or(rest.len());
+                (TokenKind::Number, len)
+            }
+            'P' if self.position == 0 => (TokenKind::Prefix, 1),
+            'T' => {
+                self.time = true;
+                (TokenKind::TimeSeparator, 1)
+            }
+            'Y' => (TokenKind::Designator(Unit::Year), 1),
+            #[cfg(feature = "extensions")]
+            'Q' => (TokenKind::Designator(Unit::Quarter), 1),
+            'M' if self.time => (TokenKind::Designator(Unit::Minute), 1),
+            'M' => (TokenKind::Designator(Unit::Month), 1),
+            'W' => (TokenKind::Week, 1),
+            'D' => (TokenKind::Designator(Unit::Day), 1),
+            'H' => (TokenKind::Designator(Unit::Hour), 1),
+            'S' => (TokenKind::Designator(Unit::Second), 1),
+            _ => (TokenKind::Unknown, c.len_utf8()),
+        };
+
+        let span = self.position..self.position + len;
+        self.position = span.end;
+
+        Some(DurationToken {
+            kind,
+            text: &self.input[span.clone()],
+            span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans() {
+        let tokens: Vec<_> = tokenize("P1,5Wé").collect();
+
+        assert_eq!(tokens.len(), 4);
+        assert_eq!(tokens[1].span, 1..4);
+        assert_eq!(tokens[1].text, "1,5");
+        assert_eq!(tokens[2].kind, TokenKind::Week);
+        assert_eq!(tokens[3].kind, TokenKind::Unknown);
+        assert_eq!(tokens[3].span, 5..7);
+    }
+
+    #[test]
+    fn prefix_only_at_start() {
+        let kinds: Vec<_> = tokenize("PP").map(|token| token.kind).collect();
+
+        assert_eq!(kinds, [TokenKind::Prefix, TokenKind::Unknown]);
+    }
+}
@@ -0,0 +1,90 @@
+use tracing::field::{display, DisplayValue};
+
+use crate::Duration;
+
+impl Duration {
+    /// Return the duration as a `tracing` field value, recorded as its
+    /// ISO8601 string.
+    ///
+    /// This is the same as the `%` sigil, the string is written straight
+    /// to the subscriber's visitor without any intermediate allocation.
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let retry_interval: Duration = "PT30S".parse().unwrap();
+    /// tracing::info!(retry_interval = retry_interval.as_value(), "scheduling retry");
+    /// ```
+    pub fn as_value(&self) -> DisplayValue<&Duration> {
+        display(self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::{
+        fmt,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use super::*;
+
+    /// Collect the fields of each event as `name=value` pairs.
+    #[derive(Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            let pair = format!("{}={:?}", field.name(), value);
+            self.0.lock().unwrap().push(pair);
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut Recorder(self.0.clone()));
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn record_field() {
+        let recorder = Recorder::default();
+        let fields = recorder.0.clone();
+        let retry_interval: Duration = "PT1M30S".parse().unwrap();
+
+        tracing::subscriber::with_default(recorder, || {
+            tracing::info!(retry_interval = retry_interval.as_value(), "retry");
+            tracing::info!(%retry_interval, "retry");
+        });
+
+        let fields = fields.lock().unwrap();
+        assert_eq!(
+            fields
+                .iter()
+                .filter(|pair| pair.as_str() == "retry_interval=PT1M30S")
+                .count(),
+            2
+        );
+    }
+}
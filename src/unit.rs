@@ -0,0 +1,46 @@
+/// A component of a [`Duration`](crate::Duration).
+///
+/// The `extensions` feature adds a `Quarter` variant, so matches on `Unit`
+/// outside this crate need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    Year,
+    /// Three months, not part of ISO8601.
+    #[cfg(feature = "extensions")]
+    Quarter,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl Unit {
+    /// Return the designator used for this unit in the duration format
+    ///
+    /// Note that `Month` and `Minute` share the `M` designator.
+    pub fn designator(self) -> char {
+        match self {
+            Unit::Year => 'Y',
+            #[cfg(feature = "extensions")]
+            Unit::Quarter => 'Q',
+            Unit::Month => 'M',
+            Unit::Day => 'D',
+            Unit::Hour => 'H',
+            Unit::Minute => 'M',
+            Unit::Second => 'S',
+        }
+    }
+
+    /// Return the number of months in this unit
+    #[cfg(feature = "extensions")]
+    pub(crate) fn months(self) -> f32 {
+        match self {
+            Unit::Year => 12.,
+            Unit::Quarter => 3.,
+            Unit::Month => 1.,
+            _ => 0.,
+        }
+    }
+}
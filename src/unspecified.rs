@@ -0,0 +1,241 @@
+use std::{fmt, str::FromStr};
+
+use nom::{
+    branch::alt,
+    bytes::complete::{is_a, tag},
+    combinator::{map, opt, value},
+    sequence::{preceded, terminated, tuple},
+    Finish,
+};
+
+use crate::{
+    duration::{complete, expect, no_component, value_with_designator, ParseResult},
+    Duration, ErrorReason, ParseDurationError, Unit,
+};
+
+/// A duration whose components may be unspecified, as allowed by the
+/// extended representations of ISO 8601-2.
+///
+/// An unspecified component is written with `X` in place of its value,
+/// e.g. `P1YXM` is one year and an unknown number of months. It is kept
+/// as `None`, apart from absent components, which are zero.
+///
+/// Only whole components can be unspecified: `P1XY` is rejected.
+///
+/// ```rust
+/// use iso8601_duration::{PartialDuration, Unit};
+///
+/// let duration: PartialDuration = "P1YXMT2H".parse().unwrap();
+/// assert_eq!(duration.year, Some(1.));
+/// assert_eq!(duration.month, None);
+/// assert_eq!(duration.day, Some(0.));
+/// assert_eq!(duration.unspecified(), [Unit::Month]);
+/// assert_eq!(duration.to_duration(), None);
+/// assert_eq!(duration.to_string(), "P1YXMT2H");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PartialDuration {
+    pub year: Option<f32>,
+    pub month: Option<f32>,
+    pub day: Option<f32>,
+    pub hour: Option<f32>,
+    pub minute: Option<f32>,
+    pub second: Option<f32>,
+}
+
+impl PartialDuration {
+    /// Parse given string into a partial duration, accepting `X` as the
+    /// value of any component.
+    pub fn parse(input: &str) -> Result<PartialDuration, ParseDurationError> {
+        complete(preceded(
+            expect(ErrorReason::MissingPrefix, tag("P")),
+            alt((parse_week_format, parse_basic_format)),
+        ))(input)
+        .finish()
+        .map(|(_, duration)| duration)
+        .map_err(|err| ParseDurationError::new(input, err))
+    }
+
+    /// Return each component along with its unit
+    fn components(&self) -> [(Unit, Option<f32>); 6] {
+        [
+            (Unit::Year, self.year),
+            (Unit::Month, self.month),
+            (Unit::Day, self.day),
+            (Unit::Hour, self.hour),
+            (Unit::Minute, self.minute),
+            (Unit::Second, self.second),
+        ]
+    }
+
+    /// Return the units of the unspecified components, largest first
+    pub fn unspecified(&self) -> Vec<Unit> {
+        self.components()
+            .iter()
+            .filter(|(_, value)| value.is_none())
+            .map(|(unit, _)| *unit)
+            .collect()
+    }
+
+    /// Convert to a `Duration`.
+    ///
+    /// This method will return `None` if any component is unspecified.
+    pub fn to_duration(&self) -> Option<Duration> {
+        Some(Duration::new(
+            self.year?,
+            self.month?,
+            self.day?,
+            self.hour?,
+            self.minute?,
+            self.second?,
+        ))
+    }
+}
+
+impl From<Duration> for PartialDuration {
+    fn from(duration: Duration) -> Self {
+        PartialDuration {
+            year: Some(duration.year),
+            month: Some(duration.month),
+            day: Some(duration.day),
+            hour: Some(duration.hour),
+            minute: Some(duration.minute),
+            second: Some(duration.second),
+        }
+    }
+}
+
+impl FromStr for PartialDuration {
+    type Err = ParseDurationError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PartialDuration::parse(s)
+    }
+}
+
+impl fmt::Display for PartialDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_component(
+            f: &mut fmt::Formatter<'_>,
+            value: Option<f32>,
+            designator: char,
+        ) -> fmt::Result {
+            match value {
+                None => write!(f, "X{}", designator),
+                Some(value) if value > 0.0 => write!(f, "{}{}", value, designator),
+                Some(_) => Ok(()),
+            }
+        }
+
+        f.write_str("P")?;
+        write_component(f, self.year, 'Y')?;
+        write_component(f, self.month, 'M')?;
+        write_component(f, self.day, 'D')?;
+        if [self.hour, self.minute, self.second]
+            .iter()
+            .any(|value| value.is_none_or(|value| value > 0.0))
+        {
+            f.write_str("T")?;
+        }
+        write_component(f, self.hour, 'H')?;
+        write_component(f, self.minute, 'M')?;
+        write_component(f, self.second, 'S')
+    }
+}
+
+fn value_or_unspecified(designator: &str) -> impl Fn(&str) -> ParseResult<'_, Option<f32>> + '_ {
+    move |input| {
+        alt((
+            map(value_with_designator(designator), Some),
+            value(None, terminated(is_a("X"), tag(designator))),
+        ))(input)
+    }
+}
+
+fn parse_basic_format(input: &str) -> ParseResult<'_, PartialDuration> {
+    let (input, (year, month, day)) = tuple((
+        opt(value_or_unspecified("Y")),
+        opt(value_or_unspecified("M")),
+        opt(value_or_unspecified("D")),
+    ))(input)?;
+
+    let (input, time) = opt(preceded(
+        tag("T"),
+        tuple((
+            opt(value_or_unspecified("H")),
+            opt(value_or_unspecified("M")),
+            opt(value_or_unspecified("S")),
+        )),
+    ))(input)?;
+
+    let (hour, minute, second) = time.unwrap_or_default();
+
+    if year.is_none()
+        && month.is_none()
+        && day.is_none()
+        && hour.is_none()
+        && minute.is_none()
+        && second.is_none()
+    {
+        no_component(input)
+    } else {
+        Ok((
+            input,
+            PartialDuration {
+                year: year.unwrap_or(Some(0.)),
+                month: month.unwrap_or(Some(0.)),
+                day: day.unwrap_or(Some(0.)),
+                hour: hour.unwrap_or(Some(0.)),
+                minute: minute.unwrap_or(Some(0.)),
+                second: second.unwrap_or(Some(0.)),
+            },
+        ))
+    }
+}
+
+fn parse_week_format(input: &str) -> ParseResult<'_, PartialDuration> {
+    let (input, week) = value_or_unspecified("W")(input)?;
+
+    Ok((
+        input,
+        PartialDuration {
+            day: week.map(|week| week * 7.),
+            ..Duration::ZERO.into()
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_unspecified() {
+        let duration = PartialDuration::parse("PXYT1HXS").unwrap();
+        assert_eq!(duration.unspecified(), [Unit::Year, Unit::Second]);
+        assert_eq!(duration.hour, Some(1.));
+        assert_eq!(duration.to_string(), "PXYT1HXS");
+
+        let week = PartialDuration::parse("PXW").unwrap();
+        assert_eq!(week.unspecified(), [Unit::Day]);
+        assert_eq!(week.to_string(), "PXD");
+
+        assert!(PartialDuration::parse("P1XY").is_err());
+        assert!(PartialDuration::parse("PT").is_err());
+        assert!(PartialDuration::parse("PX").is_err());
+    }
+
+    #[test]
+    fn fully_specified() {
+        let duration = PartialDuration::parse("P1Y2M3DT4H5M6S").unwrap();
+
+        assert!(duration.unspecified().is_empty());
+        assert_eq!(
+            duration.to_duration(),
+            Some("P1Y2M3DT4H5M6S".parse().unwrap())
+        );
+        assert_eq!(
+            PartialDuration::from(duration.to_duration().unwrap()),
+            duration
+        );
+    }
+}
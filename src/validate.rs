@@ -0,0 +1,252 @@
+use std::{error::Error, fmt};
+
+use crate::{CalendarPolicy, Duration, ParseDurationError, Unit};
+
+/// Check that `value` is a valid ISO8601 duration, returning it parsed.
+///
+/// This and [`DurationRange`] don't depend on any validation framework,
+/// and are meant to be wrapped in its custom validators. With the
+/// `validator` feature, both errors convert to `validator::ValidationError`,
+/// see `ValidateIso8601Duration`.
+pub fn validate_iso8601_duration(value: &str) -> Result<Duration, ParseDurationError> {
+    value.parse()
+}
+
+/// An inclusive range of durations, each bound being optional.
+///
+/// Durations are compared by their nominal length in seconds, with
+/// years and months counted with their average Gregorian length (see
+/// [`CalendarPolicy::Average`]).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DurationRange {
+    pub min: Option<Duration>,
+    pub max: Option<Duration>,
+}
+
+/// Error returned when a duration is out of a [`DurationRange`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RangeError {
+    /// The duration is shorter than the minimum.
+    TooShort { min: Duration },
+    /// The duration is longer than the maximum.
+    TooLong { max: Duration },
+    /// A component is NaN or infinite, so the duration has no length.
+    NonFinite,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::TooShort { min } => write!(f, "duration must be at least {}", min),
+            RangeError::TooLong { max } => write!(f, "duration must be at most {}", max),
+            RangeError::NonFinite => f.write_str("duration has a non-finite component"),
+        }
+    }
+}
+
+impl Error for RangeError {}
+
+pub(crate) fn nominal_seconds(duration: &Duration) -> f64 {
+    duration
+        .to_seconds_f64(CalendarPolicy::Average)
+        .unwrap_or_default()
+}
+
+impl DurationRange {
+    /// Create a range between `min` and `max`, both inclusive
+    pub const fn new(min: Option<Duration>, max: Option<Duration>) -> Self {
+        DurationRange { min, max }
+    }
+
+    /// Return `true` if `duration` is within the range
+    pub fn contains(&self, duration: &Duration) -> bool {
+        self.validate(duration).is_ok()
+    }
+
+    /// Check that `duration` is within the range
+    ///
+    /// ```rust
+    /// use iso8601_duration::{Duration, DurationRange, RangeError};
+    ///
+    /// let range = DurationRange::new(None, Some("P30D".parse().unwrap()));
+    /// assert_eq!(range.validate(&"P1W".parse().unwrap()), Ok(()));
+    /// assert_eq!(
+    ///     range.validate(&"P1Y".parse().unwrap()),
+    ///     Err(RangeError::TooLong { max: Duration::new(0., 0., 30., 0., 0., 0.) })
+    /// );
+    /// ```
+    pub fn validate(&self, duration: &Duration) -> Result<(), RangeError> {
+        let seconds = nominal_seconds(duration);
+        // NaN compares false against both bounds
+        if !seconds.is_finite() {
+            return Err(RangeError::NonFinite);
+        }
+
+        if let Some(min) = self.min {
+            if seconds < nominal_seconds(&min) {
+                return Err(RangeError::TooShort { min });
+            }
+        }
+        if let Some(max) = self.max {
+            if seconds > nominal_seconds(&max) {
+                return Err(RangeError::TooLong { max });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Exclusive upper bounds on each component of a duration, each bound
+/// being optional.
+///
+/// This is meant for APIs requiring normalized input, where `PT90S`
+/// should be written `PT1M30S`, see [`ComponentLimits::CANONICAL`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ComponentLimits {
+    pub year: Option<f32>,
+    pub month: Option<f32>,
+    pub day: Option<f32>,
+    pub hour: Option<f32>,
+    pub minute: Option<f32>,
+    pub second: Option<f32>,
+}
+
+/// Error returned when a component reaches its bound in [`ComponentLimits`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComponentLimitError {
+    pub unit: Unit,
+    pub value: f32,
+    pub limit: f32,
+}
+
+impl fmt::Display for ComponentLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{} is out of range, it must be less than {}{}",
+            self.value,
+            self.unit.designator(),
+            self.limit,
+            self.unit.designator()
+        )
+    }
+}
+
+impl Error for ComponentLimitError {}
+
+impl ComponentLimits {
+    /// Limits of a normalized duration: less than 12 months, 24 hours,
+    /// 60 minutes and 60 seconds. Days aren't limited, since months don't
+    /// have a fixed number of days.
+    pub const CANONICAL: ComponentLimits = ComponentLimits {
+        year: None,
+        month: Some(12.),
+        day: None,
+        hour: Some(24.),
+        minute: Some(60.),
+        second: Some(60.),
+    };
+
+    /// Check that every component of `duration` is below its bound
+    ///
+    /// ```rust
+    /// use iso8601_duration::{ComponentLimits, Unit};
+    ///
+    /// let limits = ComponentLimits::CANONICAL;
+    /// assert!(limits.validate(&"P1Y11MT23H59M59.9S".parse().unwrap()).is_ok());
+    ///
+    /// let err = limits.validate(&"PT90S".parse().unwrap()).unwrap_err();
+    /// assert_eq!(err.unit, Unit::Second);
+    /// assert_eq!(err.to_string(), "90S is out of range, it must be less than 60S");
+    /// ```
+    pub fn validate(&self, duration: &Duration) -> Result<(), ComponentLimitError> {
+        let limits = [
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        ];
+
+        for ((unit, value), limit) in duration.components().iter().zip(&limits) {
+            if let Some(limit) = limit {
+                if value.is_nan() || *value >= *limit {
+                    return Err(ComponentLimitError {
+                        unit: *unit,
+                        value: *value,
+                        limit: *limit,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range() {
+        let range = DurationRange::new(Some("PT1S".parse().unwrap()), Some("P1M".parse().unwrap()));
+
+        assert!(range.contains(&"PT1S".parse().unwrap()));
+        assert!(range.contains(&"P30D".parse().unwrap()));
+        assert!(!range.contains(&"P31D".parse().unwrap()));
+        assert_eq!(
+            range.validate(&"PT0.5S".parse().unwrap()),
+            Err(RangeError::TooShort {
+                min: Duration::new(0., 0., 0., 0., 0., 1.)
+            })
+        );
+        assert!(DurationRange::default().contains(&"P100Y".parse().unwrap()));
+
+        for duration in [
+            Duration::new(0., 0., 0., 0., 0., f32::NAN),
+            Duration::new(0., 0., 1., 0., 0., f32::INFINITY),
+            Duration::new(0., 0., 0., 0., 0., f32::NEG_INFINITY),
+        ] {
+            assert_eq!(
+                range.validate(&duration),
+                Err(RangeError::NonFinite),
+                "{:?}",
+                duration
+            );
+            assert!(!DurationRange::default().contains(&duration));
+        }
+    }
+
+    #[test]
+    fn component_limits() {
+        let limits = ComponentLimits {
+            day: Some(7.),
+            ..ComponentLimits::default()
+        };
+
+        assert!(limits.validate(&"P6DT100H".parse().unwrap()).is_ok());
+        assert_eq!(
+            limits.validate(&"P1W".parse().unwrap()),
+            Err(ComponentLimitError {
+                unit: Unit::Day,
+                value: 7.,
+                limit: 7.
+            })
+        );
+        assert!(ComponentLimits::CANONICAL
+            .validate(&Duration::new(0., 0., 0., 0., 0., f32::NAN))
+            .is_err());
+        assert!(ComponentLimits::CANONICAL
+            .validate(&"P12M".parse().unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn validate_string() {
+        assert!(validate_iso8601_duration("PT5M").is_ok());
+        assert!(validate_iso8601_duration("5 minutes").is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use std::borrow::Cow;
+
+use validator::{ValidateRange, ValidationError};
+
+use crate::{validate::nominal_seconds, Duration, ParseDurationError, RangeError};
+
+/// Check that a field is a valid ISO8601 duration, in the style of the
+/// `validator` crate's own `ValidateEmail` or `ValidateUrl`.
+///
+/// Use it from a custom validator, or convert the [`ParseDurationError`]
+/// of [`validate_iso8601_duration`](crate::validate_iso8601_duration)
+/// to get a [`ValidationError`] describing the failure:
+///
+/// ```rust
+/// use iso8601_duration::{validate_iso8601_duration, DurationRange, ValidateIso8601Duration};
+/// use validator::ValidationError;
+///
+/// fn validate_timeout(value: &str) -> Result<(), ValidationError> {
+///     let duration = validate_iso8601_duration(value)?;
+///     let range = DurationRange::new("PT1S".parse().ok(), "PT1H".parse().ok());
+///     Ok(range.validate(&duration)?)
+/// }
+///
+/// assert!("PT30S".validate_iso8601_duration());
+/// assert!(validate_timeout("PT30S").is_ok());
+/// assert_eq!(validate_timeout("30s").unwrap_err().code, "iso8601_duration");
+/// assert_eq!(validate_timeout("P1D").unwrap_err().code, "duration_range");
+/// ```
+pub trait ValidateIso8601Duration {
+    /// Return `true` if the value is a valid ISO8601 duration
+    fn validate_iso8601_duration(&self) -> bool;
+}
+
+impl ValidateIso8601Duration for str {
+    fn validate_iso8601_duration(&self) -> bool {
+        Duration::parse(self).is_ok()
+    }
+}
+
+impl ValidateIso8601Duration for String {
+    fn validate_iso8601_duration(&self) -> bool {
+        self.as_str().validate_iso8601_duration()
+    }
+}
+
+impl ValidateIso8601Duration for Cow<'_, str> {
+    fn validate_iso8601_duration(&self) -> bool {
+        self.as_ref().validate_iso8601_duration()
+    }
+}
+
+impl<T: ValidateIso8601Duration + ?Sized> ValidateIso8601Duration for &T {
+    fn validate_iso8601_duration(&self) -> bool {
+        (**self).validate_iso8601_duration()
+    }
+}
+
+/// A missing value is valid, like for the `validator` crate's own checks.
+impl<T: ValidateIso8601Duration> ValidateIso8601Duration for Option<T> {
+    fn validate_iso8601_duration(&self) -> bool {
+        self.as_ref()
+            .is_none_or(ValidateIso8601Duration::validate_iso8601_duration)
+    }
+}
+
+/// Compares durations by their nominal length, like
+/// [`DurationRange`](crate::DurationRange), so that
+/// `#[validate(range(min = ..., max = ...))]` works on `Duration` fields.
+///
+/// A duration with a NaN or infinite component is out of any range.
+impl ValidateRange<Duration> for Duration {
+    fn validate_range(
+        &self,
+        min: Option<Duration>,
+        max: Option<Duration>,
+        exclusive_min: Option<Duration>,
+        exclusive_max: Option<Duration>,
+    ) -> bool {
+        let seconds = nominal_seconds(self);
+
+        seconds.is_finite()
+            && min.is_none_or(|min| seconds >= nominal_seconds(&min))
+            && max.is_none_or(|max| seconds <= nominal_seconds(&max))
+            && exclusive_min.is_none_or(|min| seconds > nominal_seconds(&min))
+            && exclusive_max.is_none_or(|max| seconds < nominal_seconds(&max))
+    }
+
+    fn greater_than(&self, max: Duration) -> Option<bool> {
+        let (seconds, max) = (nominal_seconds(self), nominal_seconds(&max));
+        seconds.partial_cmp(&max).map(|ordering| ordering.is_gt())
+    }
+
+    fn less_than(&self, min: Duration) -> Option<bool> {
+        let (seconds, min) = (nominal_seconds(self), nominal_seconds(&min));
+        seconds.partial_cmp(&min).map(|ordering| ordering.is_lt())
+    }
+}
+
+/// A missing value is valid, like for the `validator` crate's own checks.
+impl ValidateRange<Duration> for Option<Duration> {
+    fn validate_range(
+        &self,
+        min: Option<Duration>,
+        max: Option<Duration>,
+        exclusive_min: Option<Duration>,
+        exclusive_max: Option<Duration>,
+    ) -> bool {
+        self.is_none_or(|duration| duration.validate_range(min, max, exclusive_min, exclusive_max))
+    }
+
+    fn greater_than(&self, max: Duration) -> Option<bool> {
+        self.and_then(|duration| duration.greater_than(max))
+    }
+
+    fn less_than(&self, min: Duration) -> Option<bool> {
+        self.and_then(|duration| duration.less_than(min))
+    }
+}
+
+/// The code is `iso8601_duration`, with the English message and the
+/// `position` and `input` of the error as parameters.
+impl From<ParseDurationError> for ValidationError {
+    fn from(error: ParseDurationError) -> Self {
+        let mut validation =
+            ValidationError::new("iso8601_duration").with_message(Cow::Owned(error.to_string()));
+        validation.add_param(Cow::Borrowed("position"), &error.position);
+        validation.add_param(Cow::Borrowed("input"), &error.input);
+        validation
+    }
+}
+
+/// The code is `duration_range`, with the English message and the bound
+/// as an ISO8601 string in the `min` or `max` parameter.
+impl From<RangeError> for ValidationError {
+    fn from(error: RangeError) -> Self {
+        let mut validation =
+            ValidationError::new("duration_range").with_message(Cow::Owned(error.to_string()));
+        match error {
+            RangeError::TooShort { min } => {
+                validation.add_param(Cow::Borrowed("min"), &min.to_string())
+            }
+            RangeError::TooLong { max } => {
+                validation.add_param(Cow::Borrowed("max"), &max.to_string())
+            }
+            RangeError::NonFinite => {}
+        }
+        validation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_strings() {
+        assert!("P1DT12H".validate_iso8601_duration());
+        assert!(!"1 day".validate_iso8601_duration());
+        assert!(!String::from("P").validate_iso8601_duration());
+        assert!(Cow::Borrowed("PT1S").validate_iso8601_duration());
+        assert!(None::<String>.validate_iso8601_duration());
+        assert!(!Some("PT").validate_iso8601_duration());
+    }
+
+    #[test]
+    fn validate_range() {
+        let min: Duration = "PT1S".parse().unwrap();
+        let max: Duration = "P1M".parse().unwrap();
+        let in_range = |duration: &str| {
+            let duration: Duration = duration.parse().unwrap();
+            duration.validate_range(Some(min), Some(max), None, None)
+        };
+
+        assert!(in_range("PT1S"));
+        assert!(in_range("P30D"));
+        assert!(!in_range("PT0.5S"));
+        assert!(!in_range("P31D"));
+        assert!(!min.validate_range(None, None, Some(min), None));
+        assert!(!Duration::new(0., 0., 0., 0., 0., f32::NAN).validate_range(
+            None,
+            Some(max),
+            None,
+            None
+        ));
+        assert!(None::<Duration>.validate_range(Some(min), None, None, None));
+
+        assert_eq!(max.greater_than(min), Some(true));
+        assert_eq!(max.less_than(min), Some(false));
+    }
+
+    #[test]
+    fn derive_validate() {
+        use validator::Validate;
+
+        #[derive(Validate)]
+        struct Settings {
+            #[validate(range(min = Duration::seconds(1), max = Duration::hours(1)))]
+            timeout: Duration,
+            #[validate(range(exclusive_min = Duration::ZERO))]
+            retry: Option<Duration>,
+        }
+
+        let settings = |timeout: &str, retry: Option<&str>| Settings {
+            timeout: timeout.parse().unwrap(),
+            retry: retry.map(|retry| retry.parse().unwrap()),
+        };
+
+        assert!(settings("PT30S", None).validate().is_ok());
+        assert!(settings("PT30S", Some("PT1S")).validate().is_ok());
+        assert!(settings("PT2H", None).validate().is_err());
+        assert!(settings("PT30S", Some("PT0S")).validate().is_err());
+    }
+
+    #[test]
+    fn validation_errors() {
+        let error = ValidationError::from(Duration::parse("PT1H2D").unwrap_err());
+        assert_eq!(error.code, "iso8601_duration");
+        assert_eq!(
+            error.message.as_deref(),
+            Some("component 'D' at position 4 is out of order")
+        );
+        assert_eq!(error.params["position"], 4);
+        assert_eq!(error.params["input"], "PT1H2D");
+
+        let max: Duration = "PT1H".parse().unwrap();
+        let error = ValidationError::from(RangeError::TooLong { max });
+        assert_eq!(error.code, "duration_range");
+        assert_eq!(error.params["max"], "PT1H");
+    }
+}
@@ -0,0 +1,250 @@
+use std::{fmt, ops::Deref, str::FromStr};
+
+use serde::{
+    de::{Error, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Duration, DurationRange, ParseDurationError, RangeError};
+
+/// A `Duration` taken from a path segment or a query parameter.
+///
+/// Web frameworks such as axum and actix-web deserialize their `Path` and
+/// `Query` extractors with serde, so `Path<DurationParam>` or a query
+/// struct with a `DurationParam` field work out of the box. Unlike
+/// `Duration`, rejections explain what's wrong with the input, and
+/// [`ErrorBody`] can be used to build a structured 400 response.
+///
+/// With the `axum` feature, `DurationParam` is also an extractor for a
+/// single path parameter, which rejects invalid durations with a 400 and
+/// their `ErrorBody` as JSON.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationParam(pub Duration);
+
+impl Deref for DurationParam {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+impl From<DurationParam> for Duration {
+    fn from(param: DurationParam) -> Self {
+        param.0
+    }
+}
+
+impl FromStr for DurationParam {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Duration::parse(s).map(DurationParam)
+    }
+}
+
+struct DurationParamVisitor;
+
+impl<'de> Visitor<'de> for DurationParamVisitor {
+    type Value = DurationParam;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an iso8601 duration format")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        v.parse().map_err(|err: ParseDurationError| {
+            E::custom(format_args!(
+                "invalid ISO8601 duration {:?}: {} ({})",
+                err.input,
+                err,
+                err.code()
+            ))
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DurationParamVisitor)
+    }
+}
+
+impl Serialize for DurationParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// A `Duration` whose nominal length is between `MIN` and `MAX` seconds,
+/// both inclusive.
+///
+/// The bounds are enforced when deserializing, so request payloads can't
+/// carry durations such as `P9999Y` past validation. Years and months are
+/// counted with their average Gregorian length, see [`DurationRange`].
+///
+/// ```rust
+/// use iso8601_duration::Bounded;
+///
+/// // between one second and one day
+/// type Ttl = Bounded<1, 86400>;
+///
+/// let ttl: Ttl = serde_json::from_str(r#""PT1H""#).unwrap();
+/// assert_eq!(ttl.num_seconds(), Some(3600.));
+///
+/// let err = serde_json::from_str::<Ttl>(r#""P9999Y""#).unwrap_err();
+/// assert_eq!(err.to_string(), "duration must be at most PT24H");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounded<const MIN: u64, const MAX: u64>(Duration);
+
+impl<const MIN: u64, const MAX: u64> Bounded<MIN, MAX> {
+    /// Return the range `Bounded` durations must be in
+    pub fn range() -> DurationRange {
+        DurationRange::new(
+            Some(Duration::from_secs(MIN)),
+            Some(Duration::from_secs(MAX)),
+        )
+    }
+
+    /// Wrap `duration`, checking that it's within the bounds
+    ///
+    /// Durations with a NaN or infinite component are rejected, since
+    /// they have no length to check.
+    pub fn new(duration: Duration) -> Result<Self, RangeError> {
+        Self::range().validate(&duration)?;
+        Ok(Bounded(duration))
+    }
+
+    /// Return the wrapped duration
+    pub fn into_inner(self) -> Duration {
+        self.0
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> Deref for Bounded<MIN, MAX> {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> From<Bounded<MIN, MAX>> for Duration {
+    fn from(bounded: Bounded<MIN, MAX>) -> Self {
+        bounded.0
+    }
+}
+
+impl<'de, const MIN: u64, const MAX: u64> Deserialize<'de> for Bounded<MIN, MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Bounded::new(Duration::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> Serialize for Bounded<MIN, MAX> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// A serializable description of a parse error, meant to be returned as
+/// the body of a 400 response.
+///
+/// It serializes as `{"code": ..., "message": ..., "input": ..., "position": ...}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorBody<'a> {
+    error: &'a ParseDurationError,
+}
+
+impl ParseDurationError {
+    /// Return a serializable body describing the error
+    ///
+    /// ```rust
+    /// use iso8601_duration::Duration;
+    ///
+    /// let error = Duration::parse("5M").unwrap_err();
+    /// assert_eq!(
+    ///     serde_json::to_string(&error.to_error_body()).unwrap(),
+    ///     r#"{"code":"E001_MISSING_P","message":"duration must start with 'P'","input":"5M","position":0}"#
+    /// );
+    /// ```
+    pub fn to_error_body(&self) -> ErrorBody<'_> {
+        ErrorBody { error: self }
+    }
+}
+
+impl Serialize for ErrorBody<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut body = serializer.serialize_struct("ErrorBody", 4)?;
+        body.serialize_field("code", self.error.code().as_str())?;
+        body.serialize_field("message", &self.error.to_string())?;
+        body.serialize_field("input", &self.error.input)?;
+        body.serialize_field("position", &self.error.position)?;
+        body.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_param() {
+        let param: DurationParam = serde_json::from_str(r#""PT5M""#).unwrap();
+        assert_eq!(*param, Duration::new(0., 0., 0., 0., 5., 0.));
+
+        let err = serde_json::from_str::<DurationParam>(r#""PT5X""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid ISO8601 duration \"PT5X\": unknown designator 'X' in the component at position 2 \
+             (E003_INVALID_COMPONENT) at line 1 column 6"
+        );
+    }
+
+    #[test]
+    fn bounded() {
+        type Timeout = Bounded<5, 60>;
+
+        let timeout: Timeout = serde_json::from_str(r#""PT1M""#).unwrap();
+        assert_eq!(timeout.into_inner(), Duration::new(0., 0., 0., 0., 1., 0.));
+        assert_eq!(serde_json::to_string(&timeout).unwrap(), r#""PT1M""#);
+
+        assert!(serde_json::from_str::<Timeout>(r#""PT1S""#).is_err());
+        assert!(serde_json::from_str::<Timeout>(r#""PT61S""#).is_err());
+
+        type Ttl = Bounded<1, 86400>;
+        for input in [r#""P9999YTNaNS""#, r#""PTnanS""#, r#""P1DT-infS""#] {
+            assert!(serde_json::from_str::<Ttl>(input).is_err(), "{}", input);
+        }
+        assert_eq!(
+            Ttl::new(Duration::new(9999., 0., 0., 0., 0., f32::NAN)),
+            Err(RangeError::NonFinite)
+        );
+        assert_eq!(
+            Timeout::new("PT4S".parse().unwrap()),
+            Err(RangeError::TooShort {
+                min: Duration::new(0., 0., 0., 0., 0., 5.)
+            })
+        );
+    }
+}